@@ -1,2 +1,8 @@
+pub mod attachment_constants;
+pub mod credit_card_constants;
+pub mod currency_constants;
 pub mod environment_constants;
+pub mod exchange_rate_constants;
 pub mod time_constants;
+pub mod transaction_constants;
+pub mod wallet_constants;
@@ -0,0 +1,4 @@
+pub const MAX_ATTACHMENT_SIZE: usize = 10 * 1024 * 1024;
+pub const MAX_ATTACHMENT_FILE_NAME_LENGTH: usize = 255;
+// how long a deleted attachment keeps its stored file so it can still be restored
+pub const ATTACHMENT_RETENTION_DAYS: i64 = 30;
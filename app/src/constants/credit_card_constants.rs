@@ -0,0 +1,8 @@
+use rust_decimal::Decimal;
+
+// 5% of the statement balance, rounded up to the minor unit of the card currency
+pub const MINIMUM_PAYMENT_RATE: Decimal = Decimal::from_parts(5, 0, 0, false, 2);
+pub const DEFAULT_STATEMENT_COUNT: u32 = 12;
+pub const MAX_STATEMENT_COUNT: u32 = 60;
+pub const DEFAULT_REMINDER_DAYS: i64 = 7;
+pub const MAX_REMINDER_DAYS: i64 = 60;
@@ -3,4 +3,12 @@ pub const DB_URI: &str = "DB_URI";
 pub const JWT_SECRET: &str = "JWT_SECRET";
 pub const PORT: &str = "PORT";
 pub const REFRESH_TOKEN_EXPIRATION: &str = "REFRESH_TOKEN_EXPIRATION";
+pub const S3_ACCESS_KEY: &str = "S3_ACCESS_KEY";
+pub const S3_BUCKET: &str = "S3_BUCKET";
+pub const S3_ENDPOINT: &str = "S3_ENDPOINT";
+pub const S3_REGION: &str = "S3_REGION";
+pub const S3_SECRET_KEY: &str = "S3_SECRET_KEY";
+pub const SCHEDULED_TRANSACTIONS_INTERVAL: &str = "SCHEDULED_TRANSACTIONS_INTERVAL";
+pub const STORAGE_BACKEND: &str = "STORAGE_BACKEND";
+pub const STORAGE_LOCAL_PATH: &str = "STORAGE_LOCAL_PATH";
 pub const TIMEOUT_DURATION: &str = "TIMEOUT_DURATION";
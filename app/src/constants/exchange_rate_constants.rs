@@ -0,0 +1,7 @@
+// ECB rate files quote everything against the euro, so it doubles as the cross rate currency
+pub const ECB_BASE_CURRENCY: &str = "EUR";
+pub const EXCHANGE_RATE_SCALE: u32 = 12;
+pub const EXCHANGE_RATE_IMPORT_BATCH_SIZE: usize = 1000;
+pub const MAX_EXCHANGE_RATE_FILE_SIZE: usize = 32 * 1024 * 1024;
+pub const DEFAULT_EXCHANGE_RATE_PAGE_SIZE: u64 = 50;
+pub const MAX_EXCHANGE_RATE_PAGE_SIZE: u64 = 500;
@@ -6,3 +6,10 @@ lazy_static! {
     pub static ref DATE_FORMATTER: Vec<BorrowedFormatItem<'static>> =
         format_description::parse("[year]-[month]-[day]").expect("Invalid format description");
 }
+
+// the daily ECB csv writes its dates like 17 October 2025
+lazy_static! {
+    pub static ref LONG_DATE_FORMATTER: Vec<BorrowedFormatItem<'static>> =
+        format_description::parse("[day padding:none] [month repr:long] [year]")
+            .expect("Invalid format description");
+}
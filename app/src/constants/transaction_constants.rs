@@ -0,0 +1,9 @@
+pub const MAX_BULK_TRANSACTION_OPERATIONS: usize = 500;
+pub const MAX_BULK_TRANSACTION_IDS: usize = 1000;
+pub const DEFAULT_TRANSACTION_SEARCH_PAGE_SIZE: u64 = 20;
+pub const MAX_TRANSACTION_SEARCH_PAGE_SIZE: u64 = 100;
+pub const MAX_TRANSACTION_SEARCH_QUERY_LENGTH: usize = 200;
+pub const DEFAULT_SCHEDULED_TRANSACTIONS_INTERVAL: u64 = 60;
+pub const COORDINATE_SCALE: u32 = 6;
+pub const MAX_LOCATION_RADIUS: f64 = 100_000.0;
+pub const METERS_PER_DEGREE: f64 = 111_320.0;
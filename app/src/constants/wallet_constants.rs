@@ -0,0 +1 @@
+pub const MAX_BALANCE_HISTORY_POINTS: i64 = 1000;
@@ -1,8 +1,19 @@
 pub mod auth_controller;
+pub mod balance_checks_controller;
 pub mod budget_configs_controller;
 pub mod budgets_controller;
 pub mod categories_controller;
+pub mod credit_card_statements_controller;
+pub mod exchange_rates_controller;
 pub mod global_controller;
+pub mod installment_plans_controller;
+pub mod net_worth_controller;
+pub mod payees_controller;
+pub mod reconciliations_controller;
+pub mod tags_controller;
+pub mod transaction_attachments_controller;
+pub mod transaction_rules_controller;
 pub mod transactions_controller;
 pub mod users_controller;
+pub mod wallet_members_controller;
 pub mod wallets_controller;
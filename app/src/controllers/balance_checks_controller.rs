@@ -0,0 +1,68 @@
+use crate::AppState;
+use crate::dto::response::balance_checks_dto::GetBalanceCheckResponse;
+use crate::dto::response::global::success_response::SuccessResponse;
+use crate::enums::roles::Roles;
+use crate::errors::AppError;
+use crate::extractors::user::User;
+use crate::services::balance_checks_service;
+use axum::extract::State;
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+#[utoipa::path(
+    path = "/api/v1/balance-checks",
+    get,
+    tag = "balance-checks",
+    operation_id = "balance_checks_check",
+    responses(
+        (status = 200, body = SuccessResponse<GetBalanceCheckResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn check(
+    State(state): State<Arc<AppState>>,
+    User(_, roles): User,
+) -> Result<(StatusCode, SuccessResponse<GetBalanceCheckResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::Admin])?;
+
+    let report = balance_checks_service::check(&state.db, false).await?;
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully checked balances",
+            GetBalanceCheckResponse::from(report),
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/balance-checks/repair",
+    post,
+    tag = "balance-checks",
+    operation_id = "balance_checks_repair",
+    responses(
+        (status = 200, body = SuccessResponse<GetBalanceCheckResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn repair(
+    State(state): State<Arc<AppState>>,
+    User(_, roles): User,
+) -> Result<(StatusCode, SuccessResponse<GetBalanceCheckResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::Admin])?;
+
+    let report = balance_checks_service::check(&state.db, true).await?;
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully repaired balances",
+            GetBalanceCheckResponse::from(report),
+        ),
+    ))
+}
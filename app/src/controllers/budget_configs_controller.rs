@@ -161,3 +161,83 @@ pub async fn delete_by_id(
 
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[utoipa::path(
+    path = "/api/v1/budget-configs/trash",
+    get,
+    tag = "budget-configs",
+    operation_id = "budget-configs_find_all_deleted",
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetBudgetConfigResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_all_deleted(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+) -> Result<(StatusCode, SuccessResponse<Vec<GetBudgetConfigResponse>>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let found_budget_configs =
+        budget_configs_service::find_all_deleted(&state.db, &found_user).await?;
+    let response: Vec<GetBudgetConfigResponse> = found_budget_configs
+        .into_iter()
+        .map(|budget_config| GetBudgetConfigResponse {
+            id: budget_config.id,
+            name: budget_config.name,
+            limit: budget_config.limit.to_string(),
+            description: budget_config.description,
+            repetition_type: budget_config.repetition_type.to_value().to_string(),
+        })
+        .collect();
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new("Successfully found all deleted budget configs", response),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/budget-configs/{id}/restore",
+    post,
+    tag = "budget-configs",
+    operation_id = "budget-configs_restore_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<GetBudgetConfigResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn restore_by_id(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    User(found_user, roles): User,
+) -> Result<(StatusCode, SuccessResponse<GetBudgetConfigResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let restored_budget_config =
+        budget_configs_service::restore_by_id(&state.db, &found_user, id).await?;
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully restored a budget config",
+            GetBudgetConfigResponse {
+                id: restored_budget_config.id,
+                name: restored_budget_config.name,
+                limit: restored_budget_config.limit.to_string(),
+                description: restored_budget_config.description,
+                repetition_type: restored_budget_config
+                    .repetition_type
+                    .to_value()
+                    .to_string(),
+            },
+        ),
+    ))
+}
@@ -99,6 +99,41 @@ pub async fn find_all(
     ))
 }
 
+#[utoipa::path(
+    path = "/api/v1/wallets/{id}/budgets",
+    get,
+    tag = "budgets",
+    operation_id = "budgets_find_all_by_wallet_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetBudgetResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_all_by_wallet_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+) -> Result<(StatusCode, SuccessResponse<Vec<GetBudgetResponse>>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let found_budgets = budgets_service::find_all_by_wallet_id(&state.db, &found_user, id).await?;
+
+    let response = found_budgets
+        .into_iter()
+        .map(GetBudgetResponse::from)
+        .collect();
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new("Successfully find all budgets", response),
+    ))
+}
+
 #[utoipa::path(
     path = "/api/v1/budgets/{id}",
     get,
@@ -194,3 +229,64 @@ pub async fn delete_by_id(
     budgets_service::delete_by_id(&state.db, &found_user, id).await?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[utoipa::path(
+    path = "/api/v1/budgets/trash",
+    get,
+    tag = "budgets",
+    operation_id = "budgets_find_all_deleted",
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetBudgetResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_all_deleted(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+) -> Result<(StatusCode, SuccessResponse<Vec<GetBudgetResponse>>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let found_budgets = budgets_service::find_all_deleted(&state.db, &found_user).await?;
+    let response = found_budgets
+        .into_iter()
+        .map(GetBudgetResponse::from)
+        .collect();
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new("Successfully found all deleted budgets", response),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/budgets/{id}/restore",
+    post,
+    tag = "budgets",
+    operation_id = "budgets_restore_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<GetBudgetResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn restore_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+) -> Result<(StatusCode, SuccessResponse<GetBudgetResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let restored_budget = budgets_service::restore_by_id(&state.db, &found_user, id).await?;
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully restored a budget",
+            GetBudgetResponse::from(restored_budget),
+        ),
+    ))
+}
@@ -175,3 +175,135 @@ pub async fn delete_by_id(
     categories_service::delete_by_id(&state.db, &found_user, id).await?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[utoipa::path(
+    path = "/api/v1/wallets/{id}/categories",
+    get,
+    tag = "categories",
+    operation_id = "categories_find_all_by_wallet_id",
+    params(
+        ("id" = i32, Path),
+        ("page" = Option<String>, Query),
+        ("page_size" = Option<String>, Query),
+        ("name" = Option<String>, Query),
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetCategoryResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_all_by_wallet_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+    Query(params): Query<GetAllCategoriesParams>,
+) -> Result<(StatusCode, SuccessResponse<Vec<GetCategoryResponse>>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let validated_query_params = params.validate()?;
+
+    let (found_categories, total_found_categories) = categories_service::find_all_by_wallet_id(
+        &state.db,
+        &found_user,
+        id,
+        validated_query_params.to_owned(),
+    )
+    .await?;
+
+    let found_categories = found_categories
+        .iter()
+        .map(|category| GetCategoryResponse {
+            id: category.id,
+            name: String::from(&category.name),
+        })
+        .collect();
+
+    let (Some(paginated), Some(page_information)) =
+        (validated_query_params.paginated, total_found_categories)
+    else {
+        return Ok((
+            StatusCode::OK,
+            SuccessResponse::new("Successfully get all categories", found_categories),
+        ));
+    };
+
+    let meta = Meta {
+        total_items: page_information.number_of_items,
+        page: paginated.page,
+        page_size: paginated.page_size,
+        last_page: page_information.number_of_pages,
+    };
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new("Successfully get all categories", found_categories).with_meta(meta),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/categories/trash",
+    get,
+    tag = "categories",
+    operation_id = "categories_find_all_deleted",
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetCategoryResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_all_deleted(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+) -> Result<(StatusCode, SuccessResponse<Vec<GetCategoryResponse>>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let found_categories = categories_service::find_all_deleted(&state.db, &found_user).await?;
+    let response = found_categories
+        .into_iter()
+        .map(|category| GetCategoryResponse {
+            id: category.id,
+            name: category.name,
+        })
+        .collect();
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new("Successfully found all deleted categories", response),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/categories/{id}/restore",
+    post,
+    tag = "categories",
+    operation_id = "categories_restore_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<GetCategoryResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn restore_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+) -> Result<(StatusCode, SuccessResponse<GetCategoryResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let restored_category = categories_service::restore_by_id(&state.db, &found_user, id).await?;
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully restored a category",
+            GetCategoryResponse {
+                id: restored_category.id,
+                name: restored_category.name,
+            },
+        ),
+    ))
+}
@@ -0,0 +1,98 @@
+use crate::AppState;
+use crate::dto::request::credit_card_statements_dto::{
+    FindAllCreditCardStatementsParams, FindUpcomingCreditCardStatementsParams,
+};
+use crate::dto::response::credit_card_statements_dto::GetCreditCardStatementResponse;
+use crate::dto::response::global::success_response::SuccessResponse;
+use crate::enums::roles::Roles;
+use crate::errors::AppError;
+use crate::extractors::user::User;
+use crate::services::credit_card_statements_service;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+#[utoipa::path(
+    path = "/api/v1/wallets/{id}/statements",
+    get,
+    tag = "credit_card_statements",
+    operation_id = "credit_card_statements_find_all",
+    params(
+        ("id" = i32, Path),
+        ("count" = Option<u32>, Query, description = "between 1 and 60 including the open statement, defaults to 12"),
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetCreditCardStatementResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_all(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+    Query(params): Query<FindAllCreditCardStatementsParams>,
+) -> Result<
+    (
+        StatusCode,
+        SuccessResponse<Vec<GetCreditCardStatementResponse>>,
+    ),
+    AppError,
+> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let count = params.validate()?;
+    let found_statements =
+        credit_card_statements_service::find_all(&state.db, &found_user, id, count)
+            .await?
+            .into_iter()
+            .map(GetCreditCardStatementResponse::from)
+            .collect();
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new("Successfully found all statements", found_statements),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/wallets/statements/upcoming",
+    get,
+    tag = "credit_card_statements",
+    operation_id = "credit_card_statements_find_upcoming",
+    params(
+        ("days" = Option<i64>, Query, description = "due within this many days, between 0 and 60, defaults to 7"),
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetCreditCardStatementResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_upcoming(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Query(params): Query<FindUpcomingCreditCardStatementsParams>,
+) -> Result<
+    (
+        StatusCode,
+        SuccessResponse<Vec<GetCreditCardStatementResponse>>,
+    ),
+    AppError,
+> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let days = params.validate()?;
+    let found_statements =
+        credit_card_statements_service::find_upcoming(&state.db, &found_user, days)
+            .await?
+            .into_iter()
+            .map(GetCreditCardStatementResponse::from)
+            .collect();
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully found upcoming statement payments",
+            found_statements,
+        ),
+    ))
+}
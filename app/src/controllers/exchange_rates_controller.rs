@@ -0,0 +1,250 @@
+use crate::AppState;
+use crate::constants::exchange_rate_constants::MAX_EXCHANGE_RATE_FILE_SIZE;
+use crate::dto::request::exchange_rates_dto::{
+    ConvertCurrencyParams, CreateExchangeRateRequest, FindAllExchangeRatesParams,
+    ImportExchangeRatesParams, ImportExchangeRatesRequest,
+};
+use crate::dto::response::exchange_rates_dto::{
+    ConvertCurrencyResponse, CreateExchangeRateResponse, GetExchangeRateResponse,
+    ImportExchangeRatesResponse,
+};
+use crate::dto::response::global::success_response::{Meta, SuccessResponse};
+use crate::enums::exchange_rate_file_format::ExchangeRateFileFormat;
+use crate::enums::roles::Roles;
+use crate::errors::AppError;
+use crate::extractors::json::ValidatedJson;
+use crate::extractors::user::User;
+use crate::services::{exchange_rate_imports_service, exchange_rates_service};
+use axum::extract::{Multipart, Path, Query, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+use time::OffsetDateTime;
+
+#[utoipa::path(
+    path = "/api/v1/exchange-rates",
+    post,
+    tag = "exchange-rates",
+    operation_id = "exchange_rates_create",
+    request_body(
+        content = CreateExchangeRateRequest,
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 201, body = SuccessResponse<CreateExchangeRateResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    ValidatedJson(request): ValidatedJson<CreateExchangeRateRequest>,
+) -> Result<(StatusCode, SuccessResponse<CreateExchangeRateResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let new_exchange_rate = exchange_rates_service::create(&state.db, &found_user, request).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        SuccessResponse::new(
+            "Successfully create an exchange rate",
+            CreateExchangeRateResponse::from(new_exchange_rate),
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/exchange-rates",
+    get,
+    tag = "exchange-rates",
+    operation_id = "exchange_rates_find_all",
+    params(
+        ("base_currency" = Option<String>, Query),
+        ("quote_currency" = Option<String>, Query),
+        ("start_date" = Option<String>, Query),
+        ("end_date" = Option<String>, Query),
+        ("page" = Option<u64>, Query, description = "defaults to 1"),
+        ("page_size" = Option<u64>, Query, description = "between 1 and 500, defaults to 50"),
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetExchangeRateResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_all(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Query(params): Query<FindAllExchangeRatesParams>,
+) -> Result<(StatusCode, SuccessResponse<Vec<GetExchangeRateResponse>>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let validated_params = params.validate()?;
+    let page = validated_params.page;
+    let page_size = validated_params.page_size;
+
+    let (found_exchange_rates, page_information) =
+        exchange_rates_service::find_all(&state.db, &found_user, validated_params).await?;
+    let response = found_exchange_rates
+        .into_iter()
+        .map(GetExchangeRateResponse::from)
+        .collect();
+
+    let meta = Meta {
+        total_items: page_information.number_of_items,
+        page,
+        page_size,
+        last_page: page_information.number_of_pages,
+    };
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new("Successfully found all exchange rates", response).with_meta(meta),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/exchange-rates/import",
+    post,
+    tag = "exchange-rates",
+    operation_id = "exchange_rates_import",
+    params(
+        ("format" = Option<String>, Query, description = "xml or csv, taken from the content type of the file when left out"),
+    ),
+    request_body(
+        content = ImportExchangeRatesRequest,
+        content_type = "multipart/form-data"
+    ),
+    responses(
+        (status = 201, body = SuccessResponse<ImportExchangeRatesResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn import(
+    State(state): State<Arc<AppState>>,
+    User(_, roles): User,
+    Query(params): Query<ImportExchangeRatesParams>,
+    mut multipart: Multipart,
+) -> Result<(StatusCode, SuccessResponse<ImportExchangeRatesResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::Admin])?;
+
+    while let Some(mut field) = multipart.next_field().await? {
+        if field.name() != Some("file") {
+            continue;
+        }
+
+        let format = match params.format {
+            Some(ref format) => ExchangeRateFileFormat::from_string(format)?,
+            None => {
+                ExchangeRateFileFormat::from_content_type(field.content_type().unwrap_or_default())?
+            }
+        };
+        let mut content = Vec::new();
+        while let Some(chunk) = field.chunk().await? {
+            if content.len() + chunk.len() > MAX_EXCHANGE_RATE_FILE_SIZE {
+                return Err(AppError::PayloadTooLarge(format!(
+                    "rate file cannot be larger than {MAX_EXCHANGE_RATE_FILE_SIZE} bytes"
+                )));
+            }
+            content.extend_from_slice(&chunk);
+        }
+
+        let response = exchange_rate_imports_service::import(&state.db, format, &content).await?;
+
+        return Ok((
+            StatusCode::CREATED,
+            SuccessResponse::new("Successfully imported exchange rates", response),
+        ));
+    }
+
+    Err(AppError::ParseBody(String::from(
+        "multipart field `file` is required",
+    )))
+}
+
+#[utoipa::path(
+    path = "/api/v1/exchange-rates/convert",
+    get,
+    tag = "exchange-rates",
+    operation_id = "exchange_rates_convert",
+    params(
+        ("amount" = String, Query),
+        ("from" = String, Query),
+        ("to" = Option<String>, Query, description = "defaults to the reporting currency of the user"),
+        ("date" = Option<String>, Query, description = "the latest rate on or before this date is used, defaults to today"),
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<ConvertCurrencyResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn convert(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Query(params): Query<ConvertCurrencyParams>,
+) -> Result<(StatusCode, SuccessResponse<ConvertCurrencyResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let validated_params = params.validate()?;
+    let to = validated_params
+        .to
+        .unwrap_or_else(|| found_user.reporting_currency.clone());
+
+    let (converted_amount, rate, rate_date) = exchange_rates_service::convert(
+        &state.db,
+        &found_user,
+        validated_params.amount,
+        &validated_params.from,
+        &to,
+        validated_params
+            .date
+            .unwrap_or_else(|| OffsetDateTime::now_utc().date()),
+    )
+    .await?;
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully converted an amount",
+            ConvertCurrencyResponse {
+                amount: validated_params.amount.to_string(),
+                from: validated_params.from,
+                to,
+                converted_amount: converted_amount.to_string(),
+                rate: rate.normalize().to_string(),
+                rate_date,
+            },
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/exchange-rates/{id}",
+    delete,
+    tag = "exchange-rates",
+    operation_id = "exchange_rates_delete_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 204)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+) -> Result<StatusCode, AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    exchange_rates_service::delete_by_id(&state.db, &found_user, id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
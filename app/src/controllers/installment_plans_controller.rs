@@ -0,0 +1,266 @@
+use crate::AppState;
+use crate::dto::request::installment_plans_dto::{
+    CreateInstallmentPlanRequest, PayOffInstallmentPlanRequest,
+};
+use crate::dto::response::global::success_response::SuccessResponse;
+use crate::dto::response::installment_plans_dto::{
+    CreateInstallmentPlanResponse, GetInstallmentPlanResponse,
+};
+use crate::enums::roles::Roles;
+use crate::errors::AppError;
+use crate::extractors::json::ValidatedJson;
+use crate::extractors::request_id::RequestId;
+use crate::extractors::user::User;
+use crate::services::installment_plans_service;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+#[utoipa::path(
+    path = "/api/v1/installment-plans",
+    post,
+    tag = "installment-plans",
+    operation_id = "installment_plans_create",
+    request_body(
+        content = CreateInstallmentPlanRequest,
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 201, body = SuccessResponse<CreateInstallmentPlanResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    RequestId(request_id): RequestId,
+    ValidatedJson(request): ValidatedJson<CreateInstallmentPlanRequest>,
+) -> Result<(StatusCode, SuccessResponse<CreateInstallmentPlanResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let new_installment_plan =
+        installment_plans_service::create(&state.db, &found_user, request_id.as_deref(), request)
+            .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        SuccessResponse::new(
+            "Successfully create an installment plan",
+            CreateInstallmentPlanResponse::from(new_installment_plan),
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/installment-plans",
+    get,
+    tag = "installment-plans",
+    operation_id = "installment_plans_find_all",
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetInstallmentPlanResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_all(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+) -> Result<(StatusCode, SuccessResponse<Vec<GetInstallmentPlanResponse>>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let found_installment_plans = installment_plans_service::find_all(&state.db, &found_user)
+        .await?
+        .into_iter()
+        .map(GetInstallmentPlanResponse::from)
+        .collect();
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully found all installment plans",
+            found_installment_plans,
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/installment-plans/{id}",
+    get,
+    tag = "installment-plans",
+    operation_id = "installment_plans_get_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<GetInstallmentPlanResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+) -> Result<(StatusCode, SuccessResponse<GetInstallmentPlanResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let found_installment_plan =
+        installment_plans_service::get_progress(&state.db, &found_user, id).await?;
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully found an installment plan",
+            GetInstallmentPlanResponse::from(found_installment_plan),
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/installment-plans/{id}/payoff",
+    post,
+    tag = "installment-plans",
+    operation_id = "installment_plans_pay_off_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    request_body(
+        content = PayOffInstallmentPlanRequest,
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<GetInstallmentPlanResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn pay_off_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    RequestId(request_id): RequestId,
+    Path(id): Path<i32>,
+    ValidatedJson(request): ValidatedJson<PayOffInstallmentPlanRequest>,
+) -> Result<(StatusCode, SuccessResponse<GetInstallmentPlanResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let paid_off_installment_plan = installment_plans_service::pay_off_by_id(
+        &state.db,
+        &found_user,
+        request_id.as_deref(),
+        id,
+        request,
+    )
+    .await?;
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully paid off an installment plan",
+            GetInstallmentPlanResponse::from(paid_off_installment_plan),
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/installment-plans/{id}",
+    delete,
+    tag = "installment-plans",
+    operation_id = "installment_plans_delete_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 204)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    RequestId(request_id): RequestId,
+    Path(id): Path<i32>,
+) -> Result<StatusCode, AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    installment_plans_service::delete_by_id(&state.db, &found_user, request_id.as_deref(), id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    path = "/api/v1/installment-plans/trash",
+    get,
+    tag = "installment-plans",
+    operation_id = "installment_plans_find_all_deleted",
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetInstallmentPlanResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_all_deleted(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+) -> Result<(StatusCode, SuccessResponse<Vec<GetInstallmentPlanResponse>>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let found_installment_plans =
+        installment_plans_service::find_all_deleted(&state.db, &found_user)
+            .await?
+            .into_iter()
+            .map(GetInstallmentPlanResponse::from)
+            .collect();
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully found all deleted installment plans",
+            found_installment_plans,
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/installment-plans/{id}/restore",
+    post,
+    tag = "installment-plans",
+    operation_id = "installment_plans_restore_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<GetInstallmentPlanResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn restore_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    RequestId(request_id): RequestId,
+    Path(id): Path<i32>,
+) -> Result<(StatusCode, SuccessResponse<GetInstallmentPlanResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let restored_installment_plan =
+        installment_plans_service::restore_by_id(&state.db, &found_user, request_id.as_deref(), id)
+            .await?;
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully restored an installment plan",
+            GetInstallmentPlanResponse::from(restored_installment_plan),
+        ),
+    ))
+}
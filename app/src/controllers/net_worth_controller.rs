@@ -0,0 +1,76 @@
+use crate::AppState;
+use crate::dto::request::net_worth_dto::FindNetWorthHistoryParams;
+use crate::dto::response::global::success_response::SuccessResponse;
+use crate::dto::response::net_worth_dto::{GetNetWorthHistoryResponse, GetNetWorthResponse};
+use crate::enums::roles::Roles;
+use crate::errors::AppError;
+use crate::extractors::user::User;
+use crate::services::net_worth_service;
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+#[utoipa::path(
+    path = "/api/v1/net-worth",
+    get,
+    tag = "net-worth",
+    operation_id = "net_worth_get_current",
+    responses(
+        (status = 200, body = SuccessResponse<GetNetWorthResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_current(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+) -> Result<(StatusCode, SuccessResponse<GetNetWorthResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let (as_of, wallet_type_totals) =
+        net_worth_service::get_current(&state.db, &found_user).await?;
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully get the net worth",
+            GetNetWorthResponse::from((found_user.reporting_currency, as_of, wallet_type_totals)),
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/net-worth/history",
+    get,
+    tag = "net-worth",
+    operation_id = "net_worth_find_history",
+    params(
+        ("start_date" = Option<String>, Query, description = "defaults to 12 months before end_date"),
+        ("end_date" = Option<String>, Query, description = "defaults to today"),
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<GetNetWorthHistoryResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_history(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Query(params): Query<FindNetWorthHistoryParams>,
+) -> Result<(StatusCode, SuccessResponse<GetNetWorthHistoryResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let params = params.validate()?;
+    let points = net_worth_service::find_history(&state.db, &found_user, &params).await?;
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully found the net worth history",
+            GetNetWorthHistoryResponse::from((found_user.reporting_currency, points)),
+        ),
+    ))
+}
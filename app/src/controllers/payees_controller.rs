@@ -0,0 +1,306 @@
+use crate::AppState;
+use crate::dto::request::payees_dto::{
+    CreatePayeeRequest, GetPayeeTotalsParams, UpdatePayeeRequest,
+};
+use crate::dto::response::global::success_response::SuccessResponse;
+use crate::dto::response::payees_dto::{
+    ApplyPayeesResponse, CreatePayeeResponse, GetPayeeResponse, GetPayeeTotalResponse,
+    UpdatePayeeResponse,
+};
+use crate::enums::roles::Roles;
+use crate::errors::AppError;
+use crate::extractors::json::ValidatedJson;
+use crate::extractors::request_id::RequestId;
+use crate::extractors::user::User;
+use crate::services::payees_service;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+#[utoipa::path(
+    path = "/api/v1/payees",
+    post,
+    tag = "payees",
+    operation_id = "payees_create",
+    request_body(
+        content = CreatePayeeRequest,
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 201, body = SuccessResponse<CreatePayeeResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    ValidatedJson(request): ValidatedJson<CreatePayeeRequest>,
+) -> Result<(StatusCode, SuccessResponse<CreatePayeeResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let new_payee = payees_service::create(&state.db, &found_user, request).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        SuccessResponse::new(
+            "Successfully create a payee",
+            CreatePayeeResponse::from(new_payee),
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/payees",
+    get,
+    tag = "payees",
+    operation_id = "payees_find_all",
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetPayeeResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_all(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+) -> Result<(StatusCode, SuccessResponse<Vec<GetPayeeResponse>>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let found_payees = payees_service::find_all(&state.db, &found_user).await?;
+    let response = found_payees
+        .into_iter()
+        .map(GetPayeeResponse::from)
+        .collect();
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new("Successfully found all payees", response),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/payees/totals",
+    get,
+    tag = "payees",
+    operation_id = "payees_find_totals",
+    params(
+        ("start_date" = Option<String>, Query),
+        ("end_date" = Option<String>, Query),
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetPayeeTotalResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_totals(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Query(params): Query<GetPayeeTotalsParams>,
+) -> Result<(StatusCode, SuccessResponse<Vec<GetPayeeTotalResponse>>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let validated_params = params.validate()?;
+
+    let response = payees_service::find_totals(&state.db, &found_user, validated_params).await?;
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new("Successfully found payee totals", response),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/payees/apply",
+    post,
+    tag = "payees",
+    operation_id = "payees_apply_all",
+    responses(
+        (status = 200, body = SuccessResponse<ApplyPayeesResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn apply_all(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    RequestId(request_id): RequestId,
+) -> Result<(StatusCode, SuccessResponse<ApplyPayeesResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let matched_transactions =
+        payees_service::apply_all(&state.db, &found_user, request_id.as_deref()).await?;
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully matched payees to transactions",
+            ApplyPayeesResponse {
+                matched_transactions,
+            },
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/payees/{id}",
+    get,
+    tag = "payees",
+    operation_id = "payees_get_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<GetPayeeResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+) -> Result<(StatusCode, SuccessResponse<GetPayeeResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let found_payee = payees_service::get_with_aliases_by_id(&state.db, &found_user, id).await?;
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully found a payee",
+            GetPayeeResponse::from(found_payee),
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/payees/{id}",
+    put,
+    tag = "payees",
+    operation_id = "payees_update_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    request_body(
+        content = UpdatePayeeRequest,
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<UpdatePayeeResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+    ValidatedJson(request): ValidatedJson<UpdatePayeeRequest>,
+) -> Result<(StatusCode, SuccessResponse<UpdatePayeeResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let updated_payee = payees_service::update_by_id(&state.db, &found_user, id, request).await?;
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully updated a payee",
+            UpdatePayeeResponse::from(updated_payee),
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/payees/{id}",
+    delete,
+    tag = "payees",
+    operation_id = "payees_delete_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 204)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+) -> Result<StatusCode, AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    payees_service::delete_by_id(&state.db, &found_user, id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    path = "/api/v1/payees/trash",
+    get,
+    tag = "payees",
+    operation_id = "payees_find_all_deleted",
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetPayeeResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_all_deleted(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+) -> Result<(StatusCode, SuccessResponse<Vec<GetPayeeResponse>>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let found_payees = payees_service::find_all_deleted(&state.db, &found_user).await?;
+    let response = found_payees
+        .into_iter()
+        .map(GetPayeeResponse::from)
+        .collect();
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new("Successfully found all deleted payees", response),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/payees/{id}/restore",
+    post,
+    tag = "payees",
+    operation_id = "payees_restore_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<GetPayeeResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn restore_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+) -> Result<(StatusCode, SuccessResponse<GetPayeeResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let restored_payee = payees_service::restore_by_id(&state.db, &found_user, id).await?;
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully restored a payee",
+            GetPayeeResponse::from(restored_payee),
+        ),
+    ))
+}
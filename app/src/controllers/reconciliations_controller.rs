@@ -0,0 +1,269 @@
+use crate::AppState;
+use crate::dto::request::reconciliations_dto::CreateReconciliationRequest;
+use crate::dto::response::global::success_response::SuccessResponse;
+use crate::dto::response::reconciliations_dto::{
+    CreateReconciliationResponse, GetReconciliationResponse, GetReconciliationSummaryResponse,
+};
+use crate::enums::roles::Roles;
+use crate::errors::AppError;
+use crate::extractors::json::ValidatedJson;
+use crate::extractors::request_id::RequestId;
+use crate::extractors::user::User;
+use crate::services::reconciliations_service;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+#[utoipa::path(
+    path = "/api/v1/wallets/{id}/reconciliations",
+    post,
+    tag = "reconciliations",
+    operation_id = "reconciliations_create",
+    params(
+        ("id" = i32, Path)
+    ),
+    request_body(
+        content = CreateReconciliationRequest,
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 201, body = SuccessResponse<CreateReconciliationResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+    ValidatedJson(request): ValidatedJson<CreateReconciliationRequest>,
+) -> Result<(StatusCode, SuccessResponse<CreateReconciliationResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let new_reconciliation =
+        reconciliations_service::create(&state.db, &found_user, id, request).await?;
+    Ok((
+        StatusCode::CREATED,
+        SuccessResponse::new(
+            "Successfully started a reconciliation",
+            CreateReconciliationResponse::from(new_reconciliation),
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/wallets/{id}/reconciliations",
+    get,
+    tag = "reconciliations",
+    operation_id = "reconciliations_find_all",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetReconciliationResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_all(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+) -> Result<(StatusCode, SuccessResponse<Vec<GetReconciliationResponse>>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let found_reconciliations = reconciliations_service::find_all(&state.db, &found_user, id)
+        .await?
+        .into_iter()
+        .map(GetReconciliationResponse::from)
+        .collect();
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully found all reconciliations",
+            found_reconciliations,
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/wallets/{id}/reconciliations/{reconciliation_id}",
+    get,
+    tag = "reconciliations",
+    operation_id = "reconciliations_get_by_id",
+    params(
+        ("id" = i32, Path),
+        ("reconciliation_id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<GetReconciliationSummaryResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path((id, reconciliation_id)): Path<(i32, i32)>,
+) -> Result<
+    (
+        StatusCode,
+        SuccessResponse<GetReconciliationSummaryResponse>,
+    ),
+    AppError,
+> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let found_summary =
+        reconciliations_service::get_summary_by_id(&state.db, &found_user, id, reconciliation_id)
+            .await?;
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully found a reconciliation",
+            GetReconciliationSummaryResponse::from(found_summary),
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/wallets/{id}/reconciliations/{reconciliation_id}/complete",
+    post,
+    tag = "reconciliations",
+    operation_id = "reconciliations_complete_by_id",
+    params(
+        ("id" = i32, Path),
+        ("reconciliation_id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<GetReconciliationSummaryResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn complete_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    RequestId(request_id): RequestId,
+    Path((id, reconciliation_id)): Path<(i32, i32)>,
+) -> Result<
+    (
+        StatusCode,
+        SuccessResponse<GetReconciliationSummaryResponse>,
+    ),
+    AppError,
+> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let completed_summary = reconciliations_service::complete_by_id(
+        &state.db,
+        &found_user,
+        request_id.as_deref(),
+        id,
+        reconciliation_id,
+    )
+    .await?;
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully completed a reconciliation",
+            GetReconciliationSummaryResponse::from(completed_summary),
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/wallets/{id}/reconciliations/{reconciliation_id}",
+    delete,
+    tag = "reconciliations",
+    operation_id = "reconciliations_delete_by_id",
+    params(
+        ("id" = i32, Path),
+        ("reconciliation_id" = i32, Path)
+    ),
+    responses(
+        (status = 204)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path((id, reconciliation_id)): Path<(i32, i32)>,
+) -> Result<StatusCode, AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    reconciliations_service::delete_by_id(&state.db, &found_user, id, reconciliation_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    path = "/api/v1/wallets/{id}/reconciliations/trash",
+    get,
+    tag = "reconciliations",
+    operation_id = "reconciliations_find_all_deleted",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetReconciliationResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_all_deleted(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+) -> Result<(StatusCode, SuccessResponse<Vec<GetReconciliationResponse>>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let found_reconciliations =
+        reconciliations_service::find_all_deleted(&state.db, &found_user, id)
+            .await?
+            .into_iter()
+            .map(GetReconciliationResponse::from)
+            .collect();
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully found all deleted reconciliations",
+            found_reconciliations,
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/wallets/{id}/reconciliations/{reconciliation_id}/restore",
+    post,
+    tag = "reconciliations",
+    operation_id = "reconciliations_restore_by_id",
+    params(
+        ("id" = i32, Path),
+        ("reconciliation_id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<GetReconciliationResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn restore_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path((id, reconciliation_id)): Path<(i32, i32)>,
+) -> Result<(StatusCode, SuccessResponse<GetReconciliationResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let restored_reconciliation =
+        reconciliations_service::restore_by_id(&state.db, &found_user, id, reconciliation_id)
+            .await?;
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully restored a reconciliation",
+            GetReconciliationResponse::from(restored_reconciliation),
+        ),
+    ))
+}
@@ -0,0 +1,260 @@
+use crate::AppState;
+use crate::dto::request::tags_dto::{CreateTagRequest, GetTagTotalsParams, UpdateTagRequest};
+use crate::dto::response::global::success_response::SuccessResponse;
+use crate::dto::response::tags_dto::{
+    CreateTagResponse, GetTagResponse, GetTagTotalResponse, UpdateTagResponse,
+};
+use crate::enums::roles::Roles;
+use crate::errors::AppError;
+use crate::extractors::json::ValidatedJson;
+use crate::extractors::user::User;
+use crate::services::tags_service;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+#[utoipa::path(
+    path = "/api/v1/tags",
+    post,
+    tag = "tags",
+    operation_id = "tags_create",
+    request_body(
+        content = CreateTagRequest,
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 201, body = SuccessResponse<CreateTagResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    ValidatedJson(request): ValidatedJson<CreateTagRequest>,
+) -> Result<(StatusCode, SuccessResponse<CreateTagResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let new_tag = tags_service::create(&state.db, &found_user, request).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        SuccessResponse::new(
+            "Successfully create a tag",
+            CreateTagResponse::from(new_tag),
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/tags",
+    get,
+    tag = "tags",
+    operation_id = "tags_find_all",
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetTagResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_all(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+) -> Result<(StatusCode, SuccessResponse<Vec<GetTagResponse>>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let found_tags = tags_service::find_all(&state.db, &found_user).await?;
+    let response = found_tags.into_iter().map(GetTagResponse::from).collect();
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new("Successfully found all tags", response),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/tags/totals",
+    get,
+    tag = "tags",
+    operation_id = "tags_find_totals",
+    params(
+        ("start_date" = Option<String>, Query),
+        ("end_date" = Option<String>, Query),
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetTagTotalResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_totals(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Query(params): Query<GetTagTotalsParams>,
+) -> Result<(StatusCode, SuccessResponse<Vec<GetTagTotalResponse>>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let validated_params = params.validate()?;
+
+    let response = tags_service::find_totals(&state.db, &found_user, validated_params).await?;
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new("Successfully found tag totals", response),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/tags/{id}",
+    get,
+    tag = "tags",
+    operation_id = "tags_get_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<GetTagResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+) -> Result<(StatusCode, SuccessResponse<GetTagResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let found_tag = tags_service::get_by_id(&state.db, &found_user, id).await?;
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new("Successfully found a tag", GetTagResponse::from(found_tag)),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/tags/{id}",
+    put,
+    tag = "tags",
+    operation_id = "tags_update_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    request_body(
+        content = UpdateTagRequest,
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<UpdateTagResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+    ValidatedJson(request): ValidatedJson<UpdateTagRequest>,
+) -> Result<(StatusCode, SuccessResponse<UpdateTagResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let updated_tag = tags_service::update_by_id(&state.db, &found_user, id, request).await?;
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully updated a tag",
+            UpdateTagResponse::from(updated_tag),
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/tags/{id}",
+    delete,
+    tag = "tags",
+    operation_id = "tags_delete_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 204)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+) -> Result<StatusCode, AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    tags_service::delete_by_id(&state.db, &found_user, id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    path = "/api/v1/tags/trash",
+    get,
+    tag = "tags",
+    operation_id = "tags_find_all_deleted",
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetTagResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_all_deleted(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+) -> Result<(StatusCode, SuccessResponse<Vec<GetTagResponse>>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let found_tags = tags_service::find_all_deleted(&state.db, &found_user).await?;
+    let response = found_tags.into_iter().map(GetTagResponse::from).collect();
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new("Successfully found all deleted tags", response),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/tags/{id}/restore",
+    post,
+    tag = "tags",
+    operation_id = "tags_restore_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<GetTagResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn restore_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+) -> Result<(StatusCode, SuccessResponse<GetTagResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let restored_tag = tags_service::restore_by_id(&state.db, &found_user, id).await?;
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully restored a tag",
+            GetTagResponse::from(restored_tag),
+        ),
+    ))
+}
@@ -0,0 +1,297 @@
+use crate::AppState;
+use crate::constants::attachment_constants::MAX_ATTACHMENT_SIZE;
+use crate::dto::request::transaction_attachments_dto::UploadTransactionAttachmentRequest;
+use crate::dto::response::global::success_response::SuccessResponse;
+use crate::dto::response::transaction_attachments_dto::GetTransactionAttachmentResponse;
+use crate::enums::roles::Roles;
+use crate::errors::AppError;
+use crate::extractors::user::User;
+use crate::services::transaction_attachments_service;
+use axum::body::Body;
+use axum::extract::{Multipart, Path, State};
+use axum::http::StatusCode;
+use axum::http::header::{CONTENT_DISPOSITION, CONTENT_TYPE, HeaderName};
+use std::sync::Arc;
+
+#[utoipa::path(
+    path = "/api/v1/transactions/{id}/attachments",
+    post,
+    tag = "transaction-attachments",
+    operation_id = "transaction_attachments_create",
+    params(
+        ("id" = i32, Path)
+    ),
+    request_body(
+        content = UploadTransactionAttachmentRequest,
+        content_type = "multipart/form-data"
+    ),
+    responses(
+        (status = 201, body = SuccessResponse<GetTransactionAttachmentResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+    mut multipart: Multipart,
+) -> Result<
+    (
+        StatusCode,
+        SuccessResponse<GetTransactionAttachmentResponse>,
+    ),
+    AppError,
+> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    while let Some(mut field) = multipart.next_field().await? {
+        if field.name() != Some("file") {
+            continue;
+        }
+
+        let file_name = field.file_name().map(String::from);
+        let mut content = Vec::new();
+        while let Some(chunk) = field.chunk().await? {
+            if content.len() + chunk.len() > MAX_ATTACHMENT_SIZE {
+                return Err(AppError::PayloadTooLarge(format!(
+                    "attachment cannot be larger than {MAX_ATTACHMENT_SIZE} bytes"
+                )));
+            }
+            content.extend_from_slice(&chunk);
+        }
+
+        let new_transaction_attachment = transaction_attachments_service::create(
+            &state.db,
+            state.storage.as_ref(),
+            &found_user,
+            id,
+            file_name.as_deref(),
+            &content,
+        )
+        .await?;
+
+        return Ok((
+            StatusCode::CREATED,
+            SuccessResponse::new(
+                "Successfully uploaded a transaction attachment",
+                GetTransactionAttachmentResponse::from(new_transaction_attachment),
+            ),
+        ));
+    }
+
+    Err(AppError::ParseBody(String::from(
+        "multipart field `file` is required",
+    )))
+}
+
+#[utoipa::path(
+    path = "/api/v1/transactions/{id}/attachments",
+    get,
+    tag = "transaction-attachments",
+    operation_id = "transaction_attachments_find_all",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetTransactionAttachmentResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_all(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+) -> Result<
+    (
+        StatusCode,
+        SuccessResponse<Vec<GetTransactionAttachmentResponse>>,
+    ),
+    AppError,
+> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let found_transaction_attachments =
+        transaction_attachments_service::find_all(&state.db, &found_user, id).await?;
+    let response = found_transaction_attachments
+        .into_iter()
+        .map(GetTransactionAttachmentResponse::from)
+        .collect();
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new("Successfully found all transaction attachments", response),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/transactions/{id}/attachments/{attachment_id}",
+    get,
+    tag = "transaction-attachments",
+    operation_id = "transaction_attachments_download_by_id",
+    params(
+        ("id" = i32, Path),
+        ("attachment_id" = i32, Path)
+    ),
+    responses(
+        (status = 200, content_type = "application/octet-stream")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn download_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path((id, attachment_id)): Path<(i32, i32)>,
+) -> Result<(StatusCode, [(HeaderName, String); 2], Body), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let (found_transaction_attachment, content) = transaction_attachments_service::download_by_id(
+        &state.db,
+        state.storage.as_ref(),
+        &found_user,
+        id,
+        attachment_id,
+    )
+    .await?;
+    let file_name: String = found_transaction_attachment
+        .file_name
+        .chars()
+        .map(
+            |character| {
+                if character.is_ascii() { character } else { '_' }
+            },
+        )
+        .collect();
+
+    Ok((
+        StatusCode::OK,
+        [
+            (CONTENT_TYPE, found_transaction_attachment.content_type),
+            (
+                CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{file_name}\""),
+            ),
+        ],
+        Body::from(content),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/transactions/{id}/attachments/{attachment_id}",
+    delete,
+    tag = "transaction-attachments",
+    operation_id = "transaction_attachments_delete_by_id",
+    params(
+        ("id" = i32, Path),
+        ("attachment_id" = i32, Path)
+    ),
+    responses(
+        (status = 204)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path((id, attachment_id)): Path<(i32, i32)>,
+) -> Result<StatusCode, AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    transaction_attachments_service::delete_by_id(&state.db, &found_user, id, attachment_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    path = "/api/v1/transactions/{id}/attachments/trash",
+    get,
+    tag = "transaction-attachments",
+    operation_id = "transaction_attachments_find_all_deleted",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetTransactionAttachmentResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_all_deleted(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+) -> Result<
+    (
+        StatusCode,
+        SuccessResponse<Vec<GetTransactionAttachmentResponse>>,
+    ),
+    AppError,
+> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let found_transaction_attachments =
+        transaction_attachments_service::find_all_deleted(&state.db, &found_user, id).await?;
+    let response = found_transaction_attachments
+        .into_iter()
+        .map(GetTransactionAttachmentResponse::from)
+        .collect();
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully found all deleted transaction attachments",
+            response,
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/transactions/{id}/attachments/{attachment_id}/restore",
+    post,
+    tag = "transaction-attachments",
+    operation_id = "transaction_attachments_restore_by_id",
+    params(
+        ("id" = i32, Path),
+        ("attachment_id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<GetTransactionAttachmentResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn restore_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path((id, attachment_id)): Path<(i32, i32)>,
+) -> Result<
+    (
+        StatusCode,
+        SuccessResponse<GetTransactionAttachmentResponse>,
+    ),
+    AppError,
+> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let restored_transaction_attachment =
+        transaction_attachments_service::restore_by_id(&state.db, &found_user, id, attachment_id)
+            .await?;
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully restored a transaction attachment",
+            GetTransactionAttachmentResponse::from(restored_transaction_attachment),
+        ),
+    ))
+}
@@ -0,0 +1,393 @@
+use crate::AppState;
+use crate::dto::request::transaction_rules_dto::{
+    CreateTransactionRuleRequest, UpdateTransactionRuleRequest,
+};
+use crate::dto::request::transactions_dto::FindAllTransactionsParams;
+use crate::dto::response::global::success_response::SuccessResponse;
+use crate::dto::response::transaction_rules_dto::{
+    ApplyTransactionRulesResponse, CreateTransactionRuleResponse, GetTransactionRuleResponse,
+    UpdateTransactionRuleResponse,
+};
+use crate::dto::response::transactions_dto::GetTransactionResponse;
+use crate::enums::roles::Roles;
+use crate::errors::AppError;
+use crate::extractors::json::ValidatedJson;
+use crate::extractors::request_id::RequestId;
+use crate::extractors::user::User;
+use crate::services::transaction_rules_service;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+#[utoipa::path(
+    path = "/api/v1/transaction-rules",
+    post,
+    tag = "transaction-rules",
+    operation_id = "transaction_rules_create",
+    request_body(
+        content = CreateTransactionRuleRequest,
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 201, body = SuccessResponse<CreateTransactionRuleResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    ValidatedJson(request): ValidatedJson<CreateTransactionRuleRequest>,
+) -> Result<(StatusCode, SuccessResponse<CreateTransactionRuleResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let new_transaction_rule =
+        transaction_rules_service::create(&state.db, &found_user, request).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        SuccessResponse::new(
+            "Successfully create a transaction rule",
+            CreateTransactionRuleResponse::from(new_transaction_rule),
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/transaction-rules",
+    get,
+    tag = "transaction-rules",
+    operation_id = "transaction_rules_find_all",
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetTransactionRuleResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_all(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+) -> Result<(StatusCode, SuccessResponse<Vec<GetTransactionRuleResponse>>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let found_transaction_rules =
+        transaction_rules_service::find_all(&state.db, &found_user).await?;
+    let mut found_tag_ids = transaction_rules_service::find_tag_ids_by_transaction_rule_ids(
+        &state.db,
+        found_transaction_rules
+            .iter()
+            .map(|transaction_rule| transaction_rule.id)
+            .collect(),
+    )
+    .await?;
+    let response = found_transaction_rules
+        .into_iter()
+        .map(|transaction_rule| {
+            let tag_ids = found_tag_ids
+                .remove(&transaction_rule.id)
+                .unwrap_or_default();
+            GetTransactionRuleResponse::from(transaction_rule).with_tag_ids(tag_ids)
+        })
+        .collect();
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new("Successfully found all transaction rules", response),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/transaction-rules/{id}",
+    get,
+    tag = "transaction-rules",
+    operation_id = "transaction_rules_get_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<GetTransactionRuleResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+) -> Result<(StatusCode, SuccessResponse<GetTransactionRuleResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let found_transaction_rule =
+        transaction_rules_service::get_by_id(&state.db, &found_user, id).await?;
+    let tag_ids =
+        transaction_rules_service::find_tag_ids_by_transaction_rule_ids(&state.db, vec![id])
+            .await?
+            .remove(&id)
+            .unwrap_or_default();
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully found a transaction rule",
+            GetTransactionRuleResponse::from(found_transaction_rule).with_tag_ids(tag_ids),
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/transaction-rules/{id}",
+    put,
+    tag = "transaction-rules",
+    operation_id = "transaction_rules_update_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    request_body(
+        content = UpdateTransactionRuleRequest,
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<UpdateTransactionRuleResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+    ValidatedJson(request): ValidatedJson<UpdateTransactionRuleRequest>,
+) -> Result<(StatusCode, SuccessResponse<UpdateTransactionRuleResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let updated_transaction_rule =
+        transaction_rules_service::update_by_id(&state.db, &found_user, id, request).await?;
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully updated a transaction rule",
+            UpdateTransactionRuleResponse::from(updated_transaction_rule),
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/transaction-rules/{id}",
+    delete,
+    tag = "transaction-rules",
+    operation_id = "transaction_rules_delete_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 204)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+) -> Result<StatusCode, AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    transaction_rules_service::delete_by_id(&state.db, &found_user, id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    path = "/api/v1/transaction-rules/{id}/test",
+    get,
+    tag = "transaction-rules",
+    operation_id = "transaction_rules_test_by_id",
+    params(
+        ("id" = i32, Path),
+        ("start_date" = Option<String>, Query),
+        ("end_date" = Option<String>, Query),
+        ("category_id" = Option<i32>, Query),
+        ("wallet_id" = Option<i32>, Query),
+        ("budget_id" = Option<i32>, Query),
+        ("payee_id" = Option<i32>, Query),
+        ("flow_direction" = Option<String>, Query),
+        ("status" = Option<String>, Query),
+        ("min_amount" = Option<String>, Query),
+        ("max_amount" = Option<String>, Query),
+        ("tag_ids" = Option<String>, Query, description = "comma separated tag ids"),
+        ("tag_match" = Option<String>, Query, description = "any or all, defaults to any"),
+        ("scheduled" = Option<bool>, Query, description = "only scheduled or only posted transactions"),
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetTransactionResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn test_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+    Query(params): Query<FindAllTransactionsParams>,
+) -> Result<(StatusCode, SuccessResponse<Vec<GetTransactionResponse>>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let validated_params = params.validate()?;
+
+    let matched_transactions =
+        transaction_rules_service::test_by_id(&state.db, &found_user, id, validated_params).await?;
+    let response = matched_transactions
+        .into_iter()
+        .map(GetTransactionResponse::from)
+        .collect();
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new("Successfully tested a transaction rule", response),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/transaction-rules/apply",
+    post,
+    tag = "transaction-rules",
+    operation_id = "transaction_rules_apply_all",
+    params(
+        ("start_date" = Option<String>, Query),
+        ("end_date" = Option<String>, Query),
+        ("category_id" = Option<i32>, Query),
+        ("wallet_id" = Option<i32>, Query),
+        ("budget_id" = Option<i32>, Query),
+        ("payee_id" = Option<i32>, Query),
+        ("flow_direction" = Option<String>, Query),
+        ("status" = Option<String>, Query),
+        ("min_amount" = Option<String>, Query),
+        ("max_amount" = Option<String>, Query),
+        ("tag_ids" = Option<String>, Query, description = "comma separated tag ids"),
+        ("tag_match" = Option<String>, Query, description = "any or all, defaults to any"),
+        ("scheduled" = Option<bool>, Query, description = "only scheduled or only posted transactions"),
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<ApplyTransactionRulesResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn apply_all(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    RequestId(request_id): RequestId,
+    Query(params): Query<FindAllTransactionsParams>,
+) -> Result<(StatusCode, SuccessResponse<ApplyTransactionRulesResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let validated_params = params.validate()?;
+
+    let (matched_transactions, updated_transactions) = transaction_rules_service::apply_all(
+        &state.db,
+        &found_user,
+        request_id.as_deref(),
+        validated_params,
+    )
+    .await?;
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully applied transaction rules",
+            ApplyTransactionRulesResponse {
+                matched_transactions,
+                updated_transactions,
+            },
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/transaction-rules/trash",
+    get,
+    tag = "transaction-rules",
+    operation_id = "transaction_rules_find_all_deleted",
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetTransactionRuleResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_all_deleted(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+) -> Result<(StatusCode, SuccessResponse<Vec<GetTransactionRuleResponse>>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let found_transaction_rules =
+        transaction_rules_service::find_all_deleted(&state.db, &found_user).await?;
+    let mut found_tag_ids = transaction_rules_service::find_tag_ids_by_transaction_rule_ids(
+        &state.db,
+        found_transaction_rules
+            .iter()
+            .map(|transaction_rule| transaction_rule.id)
+            .collect(),
+    )
+    .await?;
+    let response = found_transaction_rules
+        .into_iter()
+        .map(|transaction_rule| {
+            let tag_ids = found_tag_ids
+                .remove(&transaction_rule.id)
+                .unwrap_or_default();
+            GetTransactionRuleResponse::from(transaction_rule).with_tag_ids(tag_ids)
+        })
+        .collect();
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new("Successfully found all deleted transaction rules", response),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/transaction-rules/{id}/restore",
+    post,
+    tag = "transaction-rules",
+    operation_id = "transaction_rules_restore_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<GetTransactionRuleResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn restore_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+) -> Result<(StatusCode, SuccessResponse<GetTransactionRuleResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let restored_transaction_rule =
+        transaction_rules_service::restore_by_id(&state.db, &found_user, id).await?;
+    let tag_ids = transaction_rules_service::find_tag_ids_by_transaction_rule_ids(
+        &state.db,
+        vec![restored_transaction_rule.id],
+    )
+    .await?
+    .remove(&restored_transaction_rule.id)
+    .unwrap_or_default();
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully restored a transaction rule",
+            GetTransactionRuleResponse::from(restored_transaction_rule).with_tag_ids(tag_ids),
+        ),
+    ))
+}
@@ -1,16 +1,34 @@
 use crate::AppState;
-use crate::dto::request::transactions_dto::{CreateTransactionRequest, UpdateTransactionRequest};
-use crate::dto::response::global::success_response::SuccessResponse;
+use crate::dto::request::transactions_dto::{
+    BulkTransactionsRequest, CreateTransactionRefundRequest, CreateTransactionRequest,
+    CreateTransactionTransferRequest, ExportTransactionsParams, FindAllTransactionsParams,
+    FindDuplicateTransactionsParams, FindTransactionsByLocationParams,
+    MergeDuplicateTransactionsRequest, SearchTransactionsParams, UpdateTransactionRequest,
+    UpdateTransactionStatusRequest,
+};
+use crate::dto::response::global::success_response::{Meta, SuccessResponse};
+use crate::dto::response::transaction_revisions_dto::GetTransactionRevisionResponse;
 use crate::dto::response::transactions_dto::{
-    CreateTransactionResponse, GetTransactionResponse, UpdateTransactionResponse,
+    BulkTransactionsResponse, CreateTransactionResponse, CreateTransactionTransferResponse,
+    GetDuplicateTransactionResponse, GetPlaceTotalResponse, GetTransactionRefundsResponse,
+    GetTransactionResponse, SearchTransactionResponse, UpdateTransactionResponse,
 };
+use crate::entities::sea_orm_active_enums::WalletMemberRole;
+use crate::enums::export_format::ExportFormat;
 use crate::enums::roles::Roles;
 use crate::errors::AppError;
 use crate::extractors::json::ValidatedJson;
+use crate::extractors::request_id::RequestId;
 use crate::extractors::user::User;
-use crate::services::transactions_service;
-use axum::extract::{Path, State};
-use axum::http::StatusCode;
+use crate::services::{
+    transaction_bulk_operations_service, transaction_duplicates_service,
+    transaction_exports_service, transaction_refunds_service, transaction_revisions_service,
+    transaction_tags_service, transaction_transfers_service, transactions_service,
+};
+use axum::body::Body;
+use axum::extract::{Path, Query, State};
+use axum::http::header::{CONTENT_DISPOSITION, CONTENT_TYPE};
+use axum::http::{HeaderName, StatusCode};
 use std::sync::Arc;
 
 #[utoipa::path(
@@ -32,11 +50,14 @@ use std::sync::Arc;
 pub async fn create(
     State(state): State<Arc<AppState>>,
     User(found_user, roles): User,
+    RequestId(request_id): RequestId,
     ValidatedJson(request): ValidatedJson<CreateTransactionRequest>,
 ) -> Result<(StatusCode, SuccessResponse<CreateTransactionResponse>), AppError> {
     User::has_any_role(roles, vec![Roles::User])?;
 
-    let new_transaction = transactions_service::create(&state.db, &found_user, request).await?;
+    let new_transaction =
+        transactions_service::create(&state.db, &found_user, request_id.as_deref(), request)
+            .await?;
 
     Ok((
         StatusCode::CREATED,
@@ -52,6 +73,21 @@ pub async fn create(
     get,
     tag = "transactions",
     operation_id = "transactions_find_all",
+    params(
+        ("start_date" = Option<String>, Query),
+        ("end_date" = Option<String>, Query),
+        ("category_id" = Option<i32>, Query),
+        ("wallet_id" = Option<i32>, Query),
+        ("budget_id" = Option<i32>, Query),
+        ("payee_id" = Option<i32>, Query),
+        ("flow_direction" = Option<String>, Query),
+        ("status" = Option<String>, Query),
+        ("min_amount" = Option<String>, Query),
+        ("max_amount" = Option<String>, Query),
+        ("tag_ids" = Option<String>, Query, description = "comma separated tag ids"),
+        ("tag_match" = Option<String>, Query, description = "any or all, defaults to any"),
+        ("scheduled" = Option<bool>, Query, description = "only scheduled or only posted transactions"),
+    ),
     responses(
         (status = 200, body = SuccessResponse<Vec<GetTransactionResponse>>)
     ),
@@ -62,19 +98,27 @@ pub async fn create(
 pub async fn find_all(
     State(state): State<Arc<AppState>>,
     User(found_user, roles): User,
+    Query(params): Query<FindAllTransactionsParams>,
 ) -> Result<(StatusCode, SuccessResponse<Vec<GetTransactionResponse>>), AppError> {
     User::has_any_role(roles, vec![Roles::User])?;
+    let validated_params = params.validate()?;
 
-    // TODO: add filter by date
-    // TODO: add filter by category
-    // TODO: add filter by amount
-    // TODO: add filter by flow_direction
-    // TODO: add filter by wallet
-
-    let found_transactions = transactions_service::find_all(&state.db, &found_user).await?;
+    let found_transactions =
+        transactions_service::find_all(&state.db, &found_user, validated_params).await?;
+    let mut found_tag_ids = transaction_tags_service::find_tag_ids_by_transaction_ids(
+        &state.db,
+        found_transactions
+            .iter()
+            .map(|transaction| transaction.id)
+            .collect(),
+    )
+    .await?;
     let response = found_transactions
         .into_iter()
-        .map(GetTransactionResponse::from)
+        .map(|transaction| {
+            let tag_ids = found_tag_ids.remove(&transaction.id).unwrap_or_default();
+            GetTransactionResponse::from(transaction).with_tag_ids(tag_ids)
+        })
         .collect();
 
     Ok((
@@ -83,6 +127,473 @@ pub async fn find_all(
     ))
 }
 
+#[utoipa::path(
+    path = "/api/v1/transactions/search",
+    get,
+    tag = "transactions",
+    operation_id = "transactions_search",
+    params(
+        ("q" = String, Query, description = "web search syntax, matched word for word without stemming against description, category and payee names"),
+        ("page" = Option<u64>, Query, description = "defaults to 1"),
+        ("page_size" = Option<u64>, Query, description = "between 1 and 100, defaults to 20"),
+        ("start_date" = Option<String>, Query),
+        ("end_date" = Option<String>, Query),
+        ("category_id" = Option<i32>, Query),
+        ("wallet_id" = Option<i32>, Query),
+        ("budget_id" = Option<i32>, Query),
+        ("payee_id" = Option<i32>, Query),
+        ("flow_direction" = Option<String>, Query),
+        ("status" = Option<String>, Query),
+        ("min_amount" = Option<String>, Query),
+        ("max_amount" = Option<String>, Query),
+        ("tag_ids" = Option<String>, Query, description = "comma separated tag ids"),
+        ("tag_match" = Option<String>, Query, description = "any or all, defaults to any"),
+        ("scheduled" = Option<bool>, Query, description = "only scheduled or only posted transactions"),
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<Vec<SearchTransactionResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn search(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Query(params): Query<FindAllTransactionsParams>,
+    Query(search_params): Query<SearchTransactionsParams>,
+) -> Result<(StatusCode, SuccessResponse<Vec<SearchTransactionResponse>>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let validated_params = params.validate()?;
+    let validated_search_params = search_params.validate()?;
+
+    let (found_results, page_information) = transactions_service::search(
+        &state.db,
+        &found_user,
+        validated_params,
+        validated_search_params.clone(),
+    )
+    .await?;
+    let mut found_tag_ids = transaction_tags_service::find_tag_ids_by_transaction_ids(
+        &state.db,
+        found_results
+            .iter()
+            .map(|(transaction, ..)| transaction.id)
+            .collect(),
+    )
+    .await?;
+    let response = found_results
+        .into_iter()
+        .map(|found_result| {
+            let tag_ids = found_tag_ids.remove(&found_result.0.id).unwrap_or_default();
+            SearchTransactionResponse::from(found_result).with_tag_ids(tag_ids)
+        })
+        .collect();
+
+    let meta = Meta {
+        total_items: page_information.number_of_items,
+        page: validated_search_params.page,
+        page_size: validated_search_params.page_size,
+        last_page: page_information.number_of_pages,
+    };
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new("Successfully searched transactions", response).with_meta(meta),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/transactions/nearby",
+    get,
+    tag = "transactions",
+    operation_id = "transactions_find_all_nearby",
+    params(
+        ("latitude" = Option<f64>, Query, description = "center of the radius search, requires longitude and radius"),
+        ("longitude" = Option<f64>, Query, description = "center of the radius search, requires latitude and radius"),
+        ("radius" = Option<f64>, Query, description = "meters, at most 100000"),
+        ("min_latitude" = Option<f64>, Query, description = "bounding box, used instead of a radius"),
+        ("min_longitude" = Option<f64>, Query, description = "bounding box, greater than max_longitude when crossing the antimeridian"),
+        ("max_latitude" = Option<f64>, Query),
+        ("max_longitude" = Option<f64>, Query),
+        ("start_date" = Option<String>, Query),
+        ("end_date" = Option<String>, Query),
+        ("category_id" = Option<i32>, Query),
+        ("wallet_id" = Option<i32>, Query),
+        ("budget_id" = Option<i32>, Query),
+        ("payee_id" = Option<i32>, Query),
+        ("flow_direction" = Option<String>, Query),
+        ("status" = Option<String>, Query),
+        ("min_amount" = Option<String>, Query),
+        ("max_amount" = Option<String>, Query),
+        ("tag_ids" = Option<String>, Query, description = "comma separated tag ids"),
+        ("tag_match" = Option<String>, Query, description = "any or all, defaults to any"),
+        ("scheduled" = Option<bool>, Query, description = "only scheduled or only posted transactions"),
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetTransactionResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_all_nearby(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Query(params): Query<FindAllTransactionsParams>,
+    Query(location_params): Query<FindTransactionsByLocationParams>,
+) -> Result<(StatusCode, SuccessResponse<Vec<GetTransactionResponse>>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let validated_params = params.validate()?;
+    let location_area = location_params.validate()?.ok_or_else(|| {
+        AppError::ParseQuery(String::from(
+            "Either latitude, longitude and radius or a bounding box is required",
+        ))
+    })?;
+
+    let found_transactions = transactions_service::find_all_nearby(
+        &state.db,
+        &found_user,
+        validated_params,
+        location_area,
+    )
+    .await?;
+    let mut found_tag_ids = transaction_tags_service::find_tag_ids_by_transaction_ids(
+        &state.db,
+        found_transactions
+            .iter()
+            .map(|transaction| transaction.id)
+            .collect(),
+    )
+    .await?;
+    let response = found_transactions
+        .into_iter()
+        .map(|transaction| {
+            let tag_ids = found_tag_ids.remove(&transaction.id).unwrap_or_default();
+            GetTransactionResponse::from(transaction).with_tag_ids(tag_ids)
+        })
+        .collect();
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new("Successfully found nearby transactions", response),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/transactions/places",
+    get,
+    tag = "transactions",
+    operation_id = "transactions_find_place_totals",
+    params(
+        ("latitude" = Option<f64>, Query, description = "center of the radius search, requires longitude and radius"),
+        ("longitude" = Option<f64>, Query, description = "center of the radius search, requires latitude and radius"),
+        ("radius" = Option<f64>, Query, description = "meters, at most 100000"),
+        ("min_latitude" = Option<f64>, Query, description = "bounding box, used instead of a radius, filters places when given"),
+        ("min_longitude" = Option<f64>, Query, description = "bounding box, greater than max_longitude when crossing the antimeridian"),
+        ("max_latitude" = Option<f64>, Query),
+        ("max_longitude" = Option<f64>, Query),
+        ("start_date" = Option<String>, Query),
+        ("end_date" = Option<String>, Query),
+        ("category_id" = Option<i32>, Query),
+        ("wallet_id" = Option<i32>, Query),
+        ("budget_id" = Option<i32>, Query),
+        ("payee_id" = Option<i32>, Query),
+        ("flow_direction" = Option<String>, Query),
+        ("status" = Option<String>, Query),
+        ("min_amount" = Option<String>, Query),
+        ("max_amount" = Option<String>, Query),
+        ("tag_ids" = Option<String>, Query, description = "comma separated tag ids"),
+        ("tag_match" = Option<String>, Query, description = "any or all, defaults to any"),
+        ("scheduled" = Option<bool>, Query, description = "only scheduled or only posted transactions"),
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetPlaceTotalResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_place_totals(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Query(params): Query<FindAllTransactionsParams>,
+    Query(location_params): Query<FindTransactionsByLocationParams>,
+) -> Result<(StatusCode, SuccessResponse<Vec<GetPlaceTotalResponse>>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let validated_params = params.validate()?;
+    let location_area = location_params.validate()?;
+
+    let found_totals = transactions_service::find_place_totals(
+        &state.db,
+        &found_user,
+        validated_params,
+        location_area,
+    )
+    .await?;
+    let response = found_totals
+        .into_iter()
+        .map(GetPlaceTotalResponse::from)
+        .collect();
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new("Successfully found spending by place", response),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/transactions/export",
+    get,
+    tag = "transactions",
+    operation_id = "transactions_export",
+    params(
+        ("format" = Option<String>, Query, description = "csv, jsonl or xlsx, defaults to csv"),
+        ("start_date" = Option<String>, Query),
+        ("end_date" = Option<String>, Query),
+        ("category_id" = Option<i32>, Query),
+        ("wallet_id" = Option<i32>, Query),
+        ("budget_id" = Option<i32>, Query),
+        ("payee_id" = Option<i32>, Query),
+        ("flow_direction" = Option<String>, Query),
+        ("status" = Option<String>, Query),
+        ("min_amount" = Option<String>, Query),
+        ("max_amount" = Option<String>, Query),
+        ("tag_ids" = Option<String>, Query, description = "comma separated tag ids"),
+        ("tag_match" = Option<String>, Query, description = "any or all, defaults to any"),
+        ("scheduled" = Option<bool>, Query, description = "only scheduled or only posted transactions"),
+    ),
+    responses(
+        (status = 200, content_type = "application/octet-stream")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn export(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Query(params): Query<FindAllTransactionsParams>,
+    Query(export_params): Query<ExportTransactionsParams>,
+) -> Result<(StatusCode, [(HeaderName, String); 2], Body), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let validated_params = params.validate()?;
+    let format = match export_params.format {
+        Some(format) => ExportFormat::from_string(&format)?,
+        None => ExportFormat::Csv,
+    };
+
+    let exported_transactions =
+        transaction_exports_service::export(&state.db, &found_user, validated_params, format)
+            .await?;
+
+    Ok((
+        StatusCode::OK,
+        [
+            (CONTENT_TYPE, String::from(format.get_content_type())),
+            (
+                CONTENT_DISPOSITION,
+                format!(
+                    "attachment; filename=\"transactions.{}\"",
+                    format.get_extension()
+                ),
+            ),
+        ],
+        Body::from_stream(exported_transactions),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/transactions/transfers",
+    post,
+    tag = "transactions",
+    operation_id = "transactions_create_transfer",
+    request_body(
+        content = CreateTransactionTransferRequest,
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 201, body = SuccessResponse<CreateTransactionTransferResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_transfer(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    RequestId(request_id): RequestId,
+    ValidatedJson(request): ValidatedJson<CreateTransactionTransferRequest>,
+) -> Result<
+    (
+        StatusCode,
+        SuccessResponse<CreateTransactionTransferResponse>,
+    ),
+    AppError,
+> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let new_transfer = transaction_transfers_service::create(
+        &state.db,
+        &found_user,
+        request_id.as_deref(),
+        request,
+    )
+    .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        SuccessResponse::new(
+            "Successfully transferred between wallets",
+            CreateTransactionTransferResponse::from(new_transfer),
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/transactions/bulk",
+    post,
+    tag = "transactions",
+    operation_id = "transactions_bulk",
+    request_body(
+        content = BulkTransactionsRequest,
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<BulkTransactionsResponse>),
+        (status = 400, body = SuccessResponse<BulkTransactionsResponse>),
+        (status = 404, body = SuccessResponse<BulkTransactionsResponse>),
+        (status = 409, body = SuccessResponse<BulkTransactionsResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn bulk(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    RequestId(request_id): RequestId,
+    ValidatedJson(request): ValidatedJson<BulkTransactionsRequest>,
+) -> Result<(StatusCode, SuccessResponse<BulkTransactionsResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let bulk_result = transaction_bulk_operations_service::execute(
+        &state.db,
+        &found_user,
+        request_id.as_deref(),
+        request,
+    )
+    .await?;
+
+    if !bulk_result.committed {
+        // the first failing item decides the status of the whole request
+        let status = bulk_result
+            .results
+            .iter()
+            .find(|result| result.status >= 400)
+            .and_then(|result| StatusCode::from_u16(result.status).ok())
+            .unwrap_or(StatusCode::BAD_REQUEST);
+        return Ok((
+            status,
+            SuccessResponse::new(
+                "Bulk operation failed, no changes were applied",
+                bulk_result,
+            ),
+        ));
+    }
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new("Successfully applied the bulk operation", bulk_result),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/transactions/duplicates",
+    get,
+    tag = "transactions",
+    operation_id = "transactions_find_duplicates",
+    params(
+        ("wallet_id" = Option<i32>, Query),
+        ("start_date" = Option<String>, Query),
+        ("end_date" = Option<String>, Query),
+        ("window_days" = Option<i64>, Query, description = "defaults to 3"),
+        ("min_similarity" = Option<f64>, Query, description = "between 0 and 1, defaults to 0.8"),
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetDuplicateTransactionResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_duplicates(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Query(params): Query<FindDuplicateTransactionsParams>,
+) -> Result<
+    (
+        StatusCode,
+        SuccessResponse<Vec<GetDuplicateTransactionResponse>>,
+    ),
+    AppError,
+> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let validated_params = params.validate()?;
+
+    let found_duplicates =
+        transaction_duplicates_service::find_all(&state.db, &found_user, validated_params).await?;
+    let response = found_duplicates
+        .into_iter()
+        .map(GetDuplicateTransactionResponse::from)
+        .collect();
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new("Successfully found all duplicate transactions", response),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/transactions/duplicates/merge",
+    post,
+    tag = "transactions",
+    operation_id = "transactions_merge_duplicates",
+    request_body(
+        content = MergeDuplicateTransactionsRequest,
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<GetTransactionResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn merge_duplicates(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    RequestId(request_id): RequestId,
+    ValidatedJson(request): ValidatedJson<MergeDuplicateTransactionsRequest>,
+) -> Result<(StatusCode, SuccessResponse<GetTransactionResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let merged_transaction = transaction_duplicates_service::merge(
+        &state.db,
+        &found_user,
+        request_id.as_deref(),
+        request,
+    )
+    .await?;
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully merged duplicate transactions",
+            GetTransactionResponse::from(merged_transaction),
+        ),
+    ))
+}
+
 #[utoipa::path(
     path = "/api/v1/transactions/{id}",
     get,
@@ -104,12 +615,139 @@ pub async fn get_by_id(
     Path(id): Path<i32>,
 ) -> Result<(StatusCode, SuccessResponse<GetTransactionResponse>), AppError> {
     User::has_any_role(roles, vec![Roles::User])?;
-    let found_transaction = transactions_service::get_by_id(&state.db, &found_user, id).await?;
+    let found_transaction =
+        transactions_service::get_by_id(&state.db, &found_user, id, WalletMemberRole::Viewer)
+            .await?;
+    let tag_ids = transaction_tags_service::find_tag_ids_by_transaction_ids(&state.db, vec![id])
+        .await?
+        .remove(&id)
+        .unwrap_or_default();
     Ok((
         StatusCode::OK,
         SuccessResponse::new(
             "Successfully found a transaction",
-            GetTransactionResponse::from(found_transaction),
+            GetTransactionResponse::from(found_transaction).with_tag_ids(tag_ids),
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/transactions/{id}/history",
+    get,
+    tag = "transactions",
+    operation_id = "transactions_find_history_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetTransactionRevisionResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_history_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+) -> Result<
+    (
+        StatusCode,
+        SuccessResponse<Vec<GetTransactionRevisionResponse>>,
+    ),
+    AppError,
+> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let found_transaction_revisions =
+        transaction_revisions_service::find_all_by_transaction_id(&state.db, &found_user, id)
+            .await?;
+    let response = found_transaction_revisions
+        .into_iter()
+        .map(GetTransactionRevisionResponse::from)
+        .collect();
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new("Successfully found the transaction history", response),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/transactions/{id}/refunds",
+    post,
+    tag = "transactions",
+    operation_id = "transactions_create_refund",
+    params(
+        ("id" = i32, Path)
+    ),
+    request_body(
+        content = CreateTransactionRefundRequest,
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 201, body = SuccessResponse<CreateTransactionResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_refund(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    RequestId(request_id): RequestId,
+    Path(id): Path<i32>,
+    ValidatedJson(request): ValidatedJson<CreateTransactionRefundRequest>,
+) -> Result<(StatusCode, SuccessResponse<CreateTransactionResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let new_refund = transaction_refunds_service::create(
+        &state.db,
+        &found_user,
+        request_id.as_deref(),
+        id,
+        request,
+    )
+    .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        SuccessResponse::new(
+            "Successfully refunded a transaction",
+            CreateTransactionResponse::from(new_refund),
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/transactions/{id}/refunds",
+    get,
+    tag = "transactions",
+    operation_id = "transactions_find_refunds",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<GetTransactionRefundsResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_refunds(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+) -> Result<(StatusCode, SuccessResponse<GetTransactionRefundsResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let found_refunds = transaction_refunds_service::find_all(&state.db, &found_user, id).await?;
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully found the transaction refunds",
+            GetTransactionRefundsResponse::from(found_refunds),
         ),
     ))
 }
@@ -136,12 +774,19 @@ pub async fn get_by_id(
 pub async fn update_by_id(
     State(state): State<Arc<AppState>>,
     User(found_user, roles): User,
+    RequestId(request_id): RequestId,
     Path(id): Path<i32>,
     ValidatedJson(request): ValidatedJson<UpdateTransactionRequest>,
 ) -> Result<(StatusCode, SuccessResponse<UpdateTransactionResponse>), AppError> {
     User::has_any_role(roles, vec![Roles::User])?;
-    let updated_transaction =
-        transactions_service::update_by_id(&state.db, &found_user, id, request).await?;
+    let updated_transaction = transactions_service::update_by_id(
+        &state.db,
+        &found_user,
+        request_id.as_deref(),
+        id,
+        request,
+    )
+    .await?;
     Ok((
         StatusCode::OK,
         SuccessResponse::new(
@@ -151,6 +796,50 @@ pub async fn update_by_id(
     ))
 }
 
+#[utoipa::path(
+    path = "/api/v1/transactions/{id}/status",
+    put,
+    tag = "transactions",
+    operation_id = "transactions_update_status_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    request_body(
+        content = UpdateTransactionStatusRequest,
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<UpdateTransactionResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_status_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    RequestId(request_id): RequestId,
+    Path(id): Path<i32>,
+    ValidatedJson(request): ValidatedJson<UpdateTransactionStatusRequest>,
+) -> Result<(StatusCode, SuccessResponse<UpdateTransactionResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let updated_transaction = transactions_service::update_status_by_id(
+        &state.db,
+        &found_user,
+        request_id.as_deref(),
+        id,
+        request,
+    )
+    .await?;
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully updated a transaction status",
+            UpdateTransactionResponse::from(updated_transaction),
+        ),
+    ))
+}
+
 #[utoipa::path(
     path = "/api/v1/transactions/{id}",
     delete,
@@ -169,11 +858,94 @@ pub async fn update_by_id(
 pub async fn delete_by_id(
     State(state): State<Arc<AppState>>,
     User(found_user, roles): User,
+    RequestId(request_id): RequestId,
     Path(id): Path<i32>,
 ) -> Result<StatusCode, AppError> {
     User::has_any_role(roles, vec![Roles::User])?;
 
-    transactions_service::delete_by_id(&state.db, &found_user, id).await?;
+    transactions_service::delete_by_id(&state.db, &found_user, request_id.as_deref(), id).await?;
 
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[utoipa::path(
+    path = "/api/v1/transactions/trash",
+    get,
+    tag = "transactions",
+    operation_id = "transactions_find_all_deleted",
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetTransactionResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_all_deleted(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+) -> Result<(StatusCode, SuccessResponse<Vec<GetTransactionResponse>>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let found_transactions = transactions_service::find_all_deleted(&state.db, &found_user).await?;
+    let mut found_tag_ids = transaction_tags_service::find_tag_ids_by_transaction_ids(
+        &state.db,
+        found_transactions
+            .iter()
+            .map(|transaction| transaction.id)
+            .collect(),
+    )
+    .await?;
+    let response = found_transactions
+        .into_iter()
+        .map(|transaction| {
+            let tag_ids = found_tag_ids.remove(&transaction.id).unwrap_or_default();
+            GetTransactionResponse::from(transaction).with_tag_ids(tag_ids)
+        })
+        .collect();
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new("Successfully found all deleted transactions", response),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/transactions/{id}/restore",
+    post,
+    tag = "transactions",
+    operation_id = "transactions_restore_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<GetTransactionResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn restore_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    RequestId(request_id): RequestId,
+    Path(id): Path<i32>,
+) -> Result<(StatusCode, SuccessResponse<GetTransactionResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let restored_transaction =
+        transactions_service::restore_by_id(&state.db, &found_user, request_id.as_deref(), id)
+            .await?;
+    let tag_ids = transaction_tags_service::find_tag_ids_by_transaction_ids(
+        &state.db,
+        vec![restored_transaction.id],
+    )
+    .await?
+    .remove(&restored_transaction.id)
+    .unwrap_or_default();
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully restored a transaction",
+            GetTransactionResponse::from(restored_transaction).with_tag_ids(tag_ids),
+        ),
+    ))
+}
@@ -37,6 +37,7 @@ pub async fn get_self(
             GetSelfResponse {
                 username: found_user.username,
                 email: found_user.email,
+                reporting_currency: found_user.reporting_currency,
                 created_at: found_user.created_at,
             },
         ),
@@ -76,6 +77,7 @@ pub async fn update_self(
             UpdateSelfResponse {
                 username: updated_found_user.username,
                 email: updated_found_user.email,
+                reporting_currency: updated_found_user.reporting_currency,
             },
         ),
     ))
@@ -0,0 +1,247 @@
+use crate::AppState;
+use crate::dto::request::wallet_members_dto::{
+    CreateWalletMemberRequest, UpdateWalletMemberRequest,
+};
+use crate::dto::response::global::success_response::SuccessResponse;
+use crate::dto::response::wallet_members_dto::{
+    GetWalletInvitationResponse, GetWalletMemberResponse,
+};
+use crate::enums::roles::Roles;
+use crate::errors::AppError;
+use crate::extractors::json::ValidatedJson;
+use crate::extractors::user::User;
+use crate::services::wallet_members_service;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+#[utoipa::path(
+    path = "/api/v1/wallets/{id}/members",
+    post,
+    tag = "wallet-members",
+    operation_id = "wallet_members_create",
+    params(
+        ("id" = i32, Path)
+    ),
+    request_body(
+        content = CreateWalletMemberRequest,
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 201, body = SuccessResponse<GetWalletMemberResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+    ValidatedJson(request): ValidatedJson<CreateWalletMemberRequest>,
+) -> Result<(StatusCode, SuccessResponse<GetWalletMemberResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let new_wallet_member =
+        wallet_members_service::create(&state.db, &found_user, id, request).await?;
+    Ok((
+        StatusCode::CREATED,
+        SuccessResponse::new(
+            "Successfully invited a wallet member",
+            GetWalletMemberResponse::from(new_wallet_member),
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/wallets/{id}/members",
+    get,
+    tag = "wallet-members",
+    operation_id = "wallet_members_find_all",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetWalletMemberResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_all(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+) -> Result<(StatusCode, SuccessResponse<Vec<GetWalletMemberResponse>>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let found_wallet_members = wallet_members_service::find_all(&state.db, &found_user, id)
+        .await?
+        .into_iter()
+        .map(GetWalletMemberResponse::from)
+        .collect();
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully found all wallet members",
+            found_wallet_members,
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/wallets/{id}/members/{member_id}",
+    put,
+    tag = "wallet-members",
+    operation_id = "wallet_members_update_by_id",
+    params(
+        ("id" = i32, Path),
+        ("member_id" = i32, Path)
+    ),
+    request_body(
+        content = UpdateWalletMemberRequest,
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<GetWalletMemberResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path((id, member_id)): Path<(i32, i32)>,
+    ValidatedJson(request): ValidatedJson<UpdateWalletMemberRequest>,
+) -> Result<(StatusCode, SuccessResponse<GetWalletMemberResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let updated_wallet_member =
+        wallet_members_service::update_by_id(&state.db, &found_user, id, member_id, request)
+            .await?;
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully updated a wallet member",
+            GetWalletMemberResponse::from(updated_wallet_member),
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/wallets/{id}/members/{member_id}",
+    delete,
+    tag = "wallet-members",
+    operation_id = "wallet_members_delete_by_id",
+    params(
+        ("id" = i32, Path),
+        ("member_id" = i32, Path)
+    ),
+    responses(
+        (status = 204)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path((id, member_id)): Path<(i32, i32)>,
+) -> Result<StatusCode, AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    wallet_members_service::delete_by_id(&state.db, &found_user, id, member_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    path = "/api/v1/wallets/invitations",
+    get,
+    tag = "wallet-members",
+    operation_id = "wallet_members_find_all_invitations",
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetWalletInvitationResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_all_invitations(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+) -> Result<
+    (
+        StatusCode,
+        SuccessResponse<Vec<GetWalletInvitationResponse>>,
+    ),
+    AppError,
+> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let found_invitations = wallet_members_service::find_all_invitations(&state.db, &found_user)
+        .await?
+        .into_iter()
+        .map(GetWalletInvitationResponse::from)
+        .collect();
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully found all wallet invitations",
+            found_invitations,
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/wallets/invitations/{id}/accept",
+    post,
+    tag = "wallet-members",
+    operation_id = "wallet_members_accept_invitation_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<GetWalletMemberResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn accept_invitation_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+) -> Result<(StatusCode, SuccessResponse<GetWalletMemberResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let accepted_wallet_member =
+        wallet_members_service::accept_invitation_by_id(&state.db, &found_user, id).await?;
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully accepted a wallet invitation",
+            GetWalletMemberResponse::from(accepted_wallet_member),
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/wallets/invitations/{id}",
+    delete,
+    tag = "wallet-members",
+    operation_id = "wallet_members_decline_invitation_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 204)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn decline_invitation_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+) -> Result<StatusCode, AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    wallet_members_service::decline_invitation_by_id(&state.db, &found_user, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
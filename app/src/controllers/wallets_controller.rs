@@ -1,19 +1,23 @@
 use crate::AppState;
 use crate::dto::request::wallets_dto::{
-    CreateWalletRequest, FindAllWalletsParams, UpdateWalletRequest,
+    CreateWalletRequest, FindAllWalletsParams, FindWalletBalanceHistoryParams,
+    GetWalletBalanceParams, UpdateWalletRequest,
 };
 use crate::dto::response::global::success_response::SuccessResponse;
 use crate::dto::response::wallets_dto::{
-    CreateWalletResponse, GetWalletResponse, UpdateWalletResponse,
+    CreateWalletResponse, GetWalletBalanceHistoryResponse, GetWalletBalanceResponse,
+    GetWalletResponse, UpdateWalletResponse,
 };
+use crate::entities::sea_orm_active_enums::WalletMemberRole;
 use crate::enums::roles::Roles;
 use crate::errors::AppError;
 use crate::extractors::json::ValidatedJson;
 use crate::extractors::user::User;
-use crate::services::wallets_service;
+use crate::services::{wallet_balance_snapshots_service, wallets_service};
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use std::sync::Arc;
+use time::OffsetDateTime;
 
 #[utoipa::path(
     path = "/api/v1/wallets",
@@ -44,11 +48,7 @@ pub async fn create(
         StatusCode::CREATED,
         SuccessResponse::new(
             "Successfully create new wallet",
-            CreateWalletResponse {
-                id: new_wallet.id,
-                name: new_wallet.name,
-                description: new_wallet.description,
-            },
+            CreateWalletResponse::from(new_wallet),
         ),
     ))
 }
@@ -60,6 +60,7 @@ pub async fn create(
     operation_id = "wallets_find_all",
     params(
         ("max_fetch" = Option<u64>, Query),
+        ("include_archived" = Option<bool>, Query),
     ),
     responses(
         (status = 200, body = SuccessResponse<Vec<GetWalletResponse>>)
@@ -78,13 +79,13 @@ pub async fn find_all(
     User::has_any_role(roles, vec![Roles::User])?;
 
     let found_wallets = wallets_service::find_all(&state.db, &found_user, params).await?;
+    let projected_balances =
+        wallets_service::find_projected_balances(&state.db, &found_wallets).await?;
     let response = found_wallets
         .into_iter()
-        .map(|wallet| GetWalletResponse {
-            id: wallet.id,
-            name: wallet.name,
-            balance: wallet.balance.to_string(),
-            description: wallet.description,
+        .map(|wallet| {
+            let projected_balance = projected_balances[&wallet.id];
+            GetWalletResponse::from((wallet, projected_balance))
         })
         .collect();
 
@@ -118,18 +119,96 @@ pub async fn get_by_id(
 
     User::has_any_role(roles, vec![Roles::User])?;
 
-    let found_wallet = wallets_service::get_by_id(&state.db, &found_user, id).await?;
+    let found_wallet =
+        wallets_service::get_by_id(&state.db, &found_user, id, WalletMemberRole::Viewer).await?;
+    let projected_balances =
+        wallets_service::find_projected_balances(&state.db, std::slice::from_ref(&found_wallet))
+            .await?;
+    let projected_balance = projected_balances[&found_wallet.id];
 
     Ok((
         StatusCode::OK,
         SuccessResponse::new(
             "Successfully get a wallet",
-            GetWalletResponse {
-                id: found_wallet.id,
-                name: found_wallet.name,
-                balance: found_wallet.balance.to_string(),
-                description: found_wallet.description,
-            },
+            GetWalletResponse::from((found_wallet, projected_balance)),
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/wallets/{id}/balance",
+    get,
+    tag = "wallets",
+    operation_id = "wallets_get_balance_by_id",
+    params(
+        ("id" = i32, Path),
+        ("as_of" = Option<String>, Query, description = "YYYY-MM-DD, defaults to today"),
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<GetWalletBalanceResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_balance_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+    Query(params): Query<GetWalletBalanceParams>,
+) -> Result<(StatusCode, SuccessResponse<GetWalletBalanceResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let as_of = params
+        .as_of
+        .unwrap_or_else(|| OffsetDateTime::now_utc().date());
+    let (found_wallet, balance) =
+        wallets_service::get_balance_as_of(&state.db, &found_user, id, as_of).await?;
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully get the wallet balance",
+            GetWalletBalanceResponse::from((found_wallet, as_of, balance)),
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/wallets/{id}/balance/history",
+    get,
+    tag = "wallets",
+    operation_id = "wallets_find_balance_history_by_id",
+    params(
+        ("id" = i32, Path),
+        ("granularity" = Option<String>, Query, description = "day, week or month, defaults to day"),
+        ("start_date" = Option<String>, Query, description = "defaults to 30 days, 12 weeks or 12 months before end_date"),
+        ("end_date" = Option<String>, Query, description = "defaults to today"),
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<GetWalletBalanceHistoryResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_balance_history_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+    Query(params): Query<FindWalletBalanceHistoryParams>,
+) -> Result<(StatusCode, SuccessResponse<GetWalletBalanceHistoryResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let params = params.validate()?;
+    let (found_wallet, points) =
+        wallet_balance_snapshots_service::find_history(&state.db, &found_user, id, &params).await?;
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully found the wallet balance history",
+            GetWalletBalanceHistoryResponse::from((found_wallet, params.granularity, points)),
         ),
     ))
 }
@@ -167,12 +246,7 @@ pub async fn update_by_id(
         StatusCode::OK,
         SuccessResponse::new(
             "Successfully update a wallet",
-            UpdateWalletResponse {
-                id: updated_wallet.id,
-                name: updated_wallet.name,
-                balance: updated_wallet.balance.to_string(),
-                description: updated_wallet.description,
-            },
+            UpdateWalletResponse::from(updated_wallet),
         ),
     ))
 }
@@ -205,3 +279,145 @@ pub async fn delete_by_id(
 
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[utoipa::path(
+    path = "/api/v1/wallets/{id}/archive",
+    post,
+    tag = "wallets",
+    operation_id = "wallets_archive_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<GetWalletResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn archive_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+) -> Result<(StatusCode, SuccessResponse<GetWalletResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let archived_wallet = wallets_service::archive_by_id(&state.db, &found_user, id).await?;
+    let projected_balances =
+        wallets_service::find_projected_balances(&state.db, std::slice::from_ref(&archived_wallet))
+            .await?;
+    let projected_balance = projected_balances[&archived_wallet.id];
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully archived a wallet",
+            GetWalletResponse::from((archived_wallet, projected_balance)),
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/wallets/{id}/unarchive",
+    post,
+    tag = "wallets",
+    operation_id = "wallets_unarchive_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<GetWalletResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn unarchive_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+) -> Result<(StatusCode, SuccessResponse<GetWalletResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let unarchived_wallet = wallets_service::unarchive_by_id(&state.db, &found_user, id).await?;
+    let projected_balances = wallets_service::find_projected_balances(
+        &state.db,
+        std::slice::from_ref(&unarchived_wallet),
+    )
+    .await?;
+    let projected_balance = projected_balances[&unarchived_wallet.id];
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully unarchived a wallet",
+            GetWalletResponse::from((unarchived_wallet, projected_balance)),
+        ),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/wallets/trash",
+    get,
+    tag = "wallets",
+    operation_id = "wallets_find_all_deleted",
+    responses(
+        (status = 200, body = SuccessResponse<Vec<GetWalletResponse>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn find_all_deleted(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+) -> Result<(StatusCode, SuccessResponse<Vec<GetWalletResponse>>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+
+    let found_wallets = wallets_service::find_all_deleted(&state.db, &found_user).await?;
+    let projected_balances =
+        wallets_service::find_projected_balances(&state.db, &found_wallets).await?;
+    let response = found_wallets
+        .into_iter()
+        .map(|wallet| {
+            let projected_balance = projected_balances[&wallet.id];
+            GetWalletResponse::from((wallet, projected_balance))
+        })
+        .collect();
+
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new("Successfully found all deleted wallets", response),
+    ))
+}
+
+#[utoipa::path(
+    path = "/api/v1/wallets/{id}/restore",
+    post,
+    tag = "wallets",
+    operation_id = "wallets_restore_by_id",
+    params(
+        ("id" = i32, Path)
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<GetWalletResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn restore_by_id(
+    State(state): State<Arc<AppState>>,
+    User(found_user, roles): User,
+    Path(id): Path<i32>,
+) -> Result<(StatusCode, SuccessResponse<GetWalletResponse>), AppError> {
+    User::has_any_role(roles, vec![Roles::User])?;
+    let restored_wallet = wallets_service::restore_by_id(&state.db, &found_user, id).await?;
+    let projected_balances =
+        wallets_service::find_projected_balances(&state.db, std::slice::from_ref(&restored_wallet))
+            .await?;
+    let projected_balance = projected_balances[&restored_wallet.id];
+    Ok((
+        StatusCode::OK,
+        SuccessResponse::new(
+            "Successfully restored a wallet",
+            GetWalletResponse::from((restored_wallet, projected_balance)),
+        ),
+    ))
+}
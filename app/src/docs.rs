@@ -8,15 +8,29 @@ use utoipa::{Modify, OpenApi};
         crate::controllers::auth_controller::login,
         crate::controllers::auth_controller::refresh,
 
+        crate::controllers::balance_checks_controller::check,
+        crate::controllers::balance_checks_controller::repair,
+
         crate::controllers::budget_configs_controller::find_all,
         crate::controllers::budget_configs_controller::get_by_id,
         crate::controllers::budget_configs_controller::update_by_id,
         crate::controllers::budget_configs_controller::delete_by_id,
+        crate::controllers::budget_configs_controller::find_all_deleted,
+        crate::controllers::budget_configs_controller::restore_by_id,
 
         crate::controllers::categories_controller::create,
         crate::controllers::categories_controller::find_all,
         crate::controllers::categories_controller::update_by_id,
         crate::controllers::categories_controller::delete_by_id,
+        crate::controllers::categories_controller::find_all_by_wallet_id,
+        crate::controllers::categories_controller::find_all_deleted,
+        crate::controllers::categories_controller::restore_by_id,
+
+        crate::controllers::exchange_rates_controller::create,
+        crate::controllers::exchange_rates_controller::find_all,
+        crate::controllers::exchange_rates_controller::import,
+        crate::controllers::exchange_rates_controller::convert,
+        crate::controllers::exchange_rates_controller::delete_by_id,
 
         crate::controllers::users_controller::get_self,
         crate::controllers::users_controller::update_self,
@@ -25,20 +39,109 @@ use utoipa::{Modify, OpenApi};
         crate::controllers::wallets_controller::create,
         crate::controllers::wallets_controller::find_all,
         crate::controllers::wallets_controller::get_by_id,
+        crate::controllers::wallets_controller::get_balance_by_id,
+        crate::controllers::wallets_controller::find_balance_history_by_id,
         crate::controllers::wallets_controller::update_by_id,
         crate::controllers::wallets_controller::delete_by_id,
+        crate::controllers::wallets_controller::archive_by_id,
+        crate::controllers::wallets_controller::unarchive_by_id,
+        crate::controllers::wallets_controller::find_all_deleted,
+        crate::controllers::wallets_controller::restore_by_id,
+
+        crate::controllers::wallet_members_controller::create,
+        crate::controllers::wallet_members_controller::find_all,
+        crate::controllers::wallet_members_controller::update_by_id,
+        crate::controllers::wallet_members_controller::delete_by_id,
+        crate::controllers::wallet_members_controller::find_all_invitations,
+        crate::controllers::wallet_members_controller::accept_invitation_by_id,
+        crate::controllers::wallet_members_controller::decline_invitation_by_id,
+
+        crate::controllers::credit_card_statements_controller::find_all,
+        crate::controllers::credit_card_statements_controller::find_upcoming,
+
+        crate::controllers::reconciliations_controller::create,
+        crate::controllers::reconciliations_controller::find_all,
+        crate::controllers::reconciliations_controller::get_by_id,
+        crate::controllers::reconciliations_controller::complete_by_id,
+        crate::controllers::reconciliations_controller::delete_by_id,
+        crate::controllers::reconciliations_controller::find_all_deleted,
+        crate::controllers::reconciliations_controller::restore_by_id,
 
         crate::controllers::budgets_controller::create,
         crate::controllers::budgets_controller::find_all,
+        crate::controllers::budgets_controller::find_all_by_wallet_id,
         crate::controllers::budgets_controller::get_by_id,
         crate::controllers::budgets_controller::update_by_id,
         crate::controllers::budgets_controller::delete_by_id,
+        crate::controllers::budgets_controller::find_all_deleted,
+        crate::controllers::budgets_controller::restore_by_id,
 
         crate::controllers::transactions_controller::create,
         crate::controllers::transactions_controller::find_all,
+        crate::controllers::transactions_controller::bulk,
+        crate::controllers::transactions_controller::create_transfer,
+        crate::controllers::transactions_controller::search,
+        crate::controllers::transactions_controller::find_all_nearby,
+        crate::controllers::transactions_controller::find_place_totals,
+        crate::controllers::transactions_controller::export,
+        crate::controllers::transactions_controller::find_duplicates,
+        crate::controllers::transactions_controller::merge_duplicates,
         crate::controllers::transactions_controller::get_by_id,
+        crate::controllers::transactions_controller::find_history_by_id,
+        crate::controllers::transactions_controller::create_refund,
+        crate::controllers::transactions_controller::find_refunds,
         crate::controllers::transactions_controller::update_by_id,
+        crate::controllers::transactions_controller::update_status_by_id,
         crate::controllers::transactions_controller::delete_by_id,
+        crate::controllers::transactions_controller::find_all_deleted,
+        crate::controllers::transactions_controller::restore_by_id,
+
+        crate::controllers::transaction_attachments_controller::create,
+        crate::controllers::transaction_attachments_controller::find_all,
+        crate::controllers::transaction_attachments_controller::download_by_id,
+        crate::controllers::transaction_attachments_controller::delete_by_id,
+        crate::controllers::transaction_attachments_controller::find_all_deleted,
+        crate::controllers::transaction_attachments_controller::restore_by_id,
+
+        crate::controllers::transaction_rules_controller::create,
+        crate::controllers::transaction_rules_controller::find_all,
+        crate::controllers::transaction_rules_controller::get_by_id,
+        crate::controllers::transaction_rules_controller::update_by_id,
+        crate::controllers::transaction_rules_controller::delete_by_id,
+        crate::controllers::transaction_rules_controller::find_all_deleted,
+        crate::controllers::transaction_rules_controller::restore_by_id,
+        crate::controllers::transaction_rules_controller::test_by_id,
+        crate::controllers::transaction_rules_controller::apply_all,
+
+        crate::controllers::tags_controller::create,
+        crate::controllers::tags_controller::find_all,
+        crate::controllers::tags_controller::find_totals,
+        crate::controllers::tags_controller::get_by_id,
+        crate::controllers::tags_controller::update_by_id,
+        crate::controllers::tags_controller::delete_by_id,
+        crate::controllers::tags_controller::find_all_deleted,
+        crate::controllers::tags_controller::restore_by_id,
+
+        crate::controllers::installment_plans_controller::create,
+        crate::controllers::installment_plans_controller::find_all,
+        crate::controllers::installment_plans_controller::get_by_id,
+        crate::controllers::installment_plans_controller::pay_off_by_id,
+        crate::controllers::installment_plans_controller::delete_by_id,
+        crate::controllers::installment_plans_controller::find_all_deleted,
+        crate::controllers::installment_plans_controller::restore_by_id,
+
+        crate::controllers::net_worth_controller::get_current,
+        crate::controllers::net_worth_controller::find_history,
+
+        crate::controllers::payees_controller::create,
+        crate::controllers::payees_controller::find_all,
+        crate::controllers::payees_controller::find_totals,
+        crate::controllers::payees_controller::apply_all,
+        crate::controllers::payees_controller::get_by_id,
+        crate::controllers::payees_controller::update_by_id,
+        crate::controllers::payees_controller::delete_by_id,
+        crate::controllers::payees_controller::find_all_deleted,
+        crate::controllers::payees_controller::restore_by_id,
     ),
     modifiers(&SecurityAddon),
 )]
@@ -2,9 +2,19 @@ pub mod auth_dto;
 pub mod budget_configs_dto;
 pub mod budgets_dto;
 pub mod categories_dto;
+pub mod credit_card_statements_dto;
+pub mod exchange_rates_dto;
+pub mod installment_plans_dto;
+pub mod net_worth_dto;
+pub mod payees_dto;
+pub mod reconciliations_dto;
 pub mod refresh_tokens_dto;
 pub mod roles_dto;
+pub mod tags_dto;
+pub mod transaction_attachments_dto;
+pub mod transaction_rules_dto;
 pub mod transactions_dto;
 pub mod user_roles_dto;
 pub mod users_dto;
+pub mod wallet_members_dto;
 pub mod wallets_dto;
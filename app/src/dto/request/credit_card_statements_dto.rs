@@ -0,0 +1,5 @@
+mod find_all_credit_card_statements_params;
+mod find_upcoming_credit_card_statements_params;
+
+pub use find_all_credit_card_statements_params::FindAllCreditCardStatementsParams;
+pub use find_upcoming_credit_card_statements_params::FindUpcomingCreditCardStatementsParams;
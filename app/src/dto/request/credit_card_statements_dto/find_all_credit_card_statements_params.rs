@@ -0,0 +1,22 @@
+use crate::constants::credit_card_constants::{DEFAULT_STATEMENT_COUNT, MAX_STATEMENT_COUNT};
+use crate::errors::AppError;
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct FindAllCreditCardStatementsParams {
+    // the open statement counts as one of them
+    pub count: Option<u32>,
+}
+
+impl FindAllCreditCardStatementsParams {
+    pub fn validate(self) -> Result<u32, AppError> {
+        let count = self.count.unwrap_or(DEFAULT_STATEMENT_COUNT);
+        if !(1..=MAX_STATEMENT_COUNT).contains(&count) {
+            return Err(AppError::ParseQuery(format!(
+                "count must be between 1 and {MAX_STATEMENT_COUNT}"
+            )));
+        }
+        Ok(count)
+    }
+}
@@ -0,0 +1,22 @@
+use crate::constants::credit_card_constants::{DEFAULT_REMINDER_DAYS, MAX_REMINDER_DAYS};
+use crate::errors::AppError;
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct FindUpcomingCreditCardStatementsParams {
+    // overdue statements are always included
+    pub days: Option<i64>,
+}
+
+impl FindUpcomingCreditCardStatementsParams {
+    pub fn validate(self) -> Result<i64, AppError> {
+        let days = self.days.unwrap_or(DEFAULT_REMINDER_DAYS);
+        if !(0..=MAX_REMINDER_DAYS).contains(&days) {
+            return Err(AppError::ParseQuery(format!(
+                "days must be between 0 and {MAX_REMINDER_DAYS}"
+            )));
+        }
+        Ok(days)
+    }
+}
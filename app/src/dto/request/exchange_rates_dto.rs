@@ -0,0 +1,13 @@
+mod convert_currency_params;
+mod create_exchange_rate_request;
+mod find_all_exchange_rates_params;
+mod import_exchange_rates_params;
+mod import_exchange_rates_request;
+
+pub use convert_currency_params::ConvertCurrencyParams;
+pub use create_exchange_rate_request::CreateExchangeRateRequest;
+pub use find_all_exchange_rates_params::{
+    FindAllExchangeRatesParams, ValidatedFindAllExchangeRatesParams,
+};
+pub use import_exchange_rates_params::ImportExchangeRatesParams;
+pub use import_exchange_rates_request::ImportExchangeRatesRequest;
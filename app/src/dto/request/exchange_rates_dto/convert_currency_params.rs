@@ -0,0 +1,40 @@
+use crate::errors::AppError;
+use crate::utils::currency_utils::{find_minor_units, parse_amount};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use time::Date;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct ConvertCurrencyParams {
+    pub amount: String,
+    pub from: String,
+    pub to: Option<String>,
+    pub date: Option<Date>,
+}
+
+impl ConvertCurrencyParams {
+    pub fn validate(self) -> Result<ValidatedConvertCurrencyParams, AppError> {
+        if let Some(ref to) = self.to
+            && find_minor_units(to).is_none()
+        {
+            return Err(AppError::ParseQuery(format!("unknown currency {to}")));
+        }
+        let amount = parse_amount(&self.amount, &self.from)
+            .map_err(|_| AppError::ParseQuery(format!("invalid amount in {}", self.from)))?;
+
+        Ok(ValidatedConvertCurrencyParams {
+            amount,
+            from: self.from,
+            to: self.to,
+            date: self.date,
+        })
+    }
+}
+
+pub struct ValidatedConvertCurrencyParams {
+    pub amount: Decimal,
+    pub from: String,
+    pub to: Option<String>,
+    pub date: Option<Date>,
+}
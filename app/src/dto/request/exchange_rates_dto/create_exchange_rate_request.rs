@@ -0,0 +1,31 @@
+use serde::Deserialize;
+use time::Date;
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+#[derive(Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_create_exchange_rate_currencies"))]
+pub struct CreateExchangeRateRequest {
+    #[validate(custom(function = "crate::utils::validation::validate_currency"))]
+    pub base_currency: String,
+
+    #[validate(custom(function = "crate::utils::validation::validate_currency"))]
+    pub quote_currency: String,
+
+    pub rate_date: Date,
+
+    // how many quote_currency one base_currency buys
+    pub rate: String,
+}
+
+fn validate_create_exchange_rate_currencies(
+    request: &CreateExchangeRateRequest,
+) -> Result<(), ValidationError> {
+    if request.base_currency == request.quote_currency {
+        return Err(ValidationError::new(
+            "base_currency and quote_currency must be different",
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use crate::constants::exchange_rate_constants::{
+    DEFAULT_EXCHANGE_RATE_PAGE_SIZE, MAX_EXCHANGE_RATE_PAGE_SIZE,
+};
+use crate::errors::AppError;
+use crate::utils::currency_utils::find_minor_units;
+use serde::Deserialize;
+use time::Date;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct FindAllExchangeRatesParams {
+    pub base_currency: Option<String>,
+    pub quote_currency: Option<String>,
+    pub start_date: Option<Date>,
+    pub end_date: Option<Date>,
+    pub page: Option<u64>,
+    pub page_size: Option<u64>,
+}
+
+impl FindAllExchangeRatesParams {
+    pub fn validate(self) -> Result<ValidatedFindAllExchangeRatesParams, AppError> {
+        for currency in [&self.base_currency, &self.quote_currency]
+            .into_iter()
+            .flatten()
+        {
+            if find_minor_units(currency).is_none() {
+                return Err(AppError::ParseQuery(format!("unknown currency {currency}")));
+            }
+        }
+        if let (Some(start_date), Some(end_date)) = (self.start_date, self.end_date)
+            && start_date > end_date
+        {
+            return Err(AppError::ParseQuery(String::from(
+                "start_date cannot be after end_date",
+            )));
+        }
+
+        let page = self.page.unwrap_or(1);
+        if page == 0 {
+            return Err(AppError::ParseQuery(String::from("page cannot be 0")));
+        }
+        let page_size = self.page_size.unwrap_or(DEFAULT_EXCHANGE_RATE_PAGE_SIZE);
+        if !(1..=MAX_EXCHANGE_RATE_PAGE_SIZE).contains(&page_size) {
+            return Err(AppError::ParseQuery(format!(
+                "page_size must be between 1 and {MAX_EXCHANGE_RATE_PAGE_SIZE}"
+            )));
+        }
+
+        Ok(ValidatedFindAllExchangeRatesParams {
+            base_currency: self.base_currency,
+            quote_currency: self.quote_currency,
+            start_date: self.start_date,
+            end_date: self.end_date,
+            page,
+            page_size,
+        })
+    }
+}
+
+pub struct ValidatedFindAllExchangeRatesParams {
+    pub base_currency: Option<String>,
+    pub quote_currency: Option<String>,
+    pub start_date: Option<Date>,
+    pub end_date: Option<Date>,
+    pub page: u64,
+    pub page_size: u64,
+}
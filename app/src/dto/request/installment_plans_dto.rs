@@ -0,0 +1,5 @@
+mod create_installment_plan_request;
+mod pay_off_installment_plan_request;
+
+pub use create_installment_plan_request::CreateInstallmentPlanRequest;
+pub use pay_off_installment_plan_request::PayOffInstallmentPlanRequest;
@@ -0,0 +1,32 @@
+use serde::Deserialize;
+use time::Date;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateInstallmentPlanRequest {
+    #[validate(range(min = 1, message = "wallet_id cannot be less than 1"))]
+    pub wallet_id: i32,
+
+    #[validate(range(min = 1, message = "category_id cannot be less than 1"))]
+    pub category_id: Option<i32>,
+
+    pub budget_id: Option<i32>,
+
+    #[validate(range(min = 1, message = "payee_id cannot be less than 1"))]
+    pub payee_id: Option<i32>,
+
+    pub description: Option<String>,
+
+    pub total_amount: String,
+
+    #[validate(range(
+        min = 2,
+        max = 360,
+        message = "installment_count must be between 2 and 360"
+    ))]
+    pub installment_count: i32,
+
+    pub first_issued_at: Date,
+}
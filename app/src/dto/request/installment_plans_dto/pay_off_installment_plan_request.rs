@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use time::Date;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PayOffInstallmentPlanRequest {
+    pub paid_off_at: Option<Date>,
+}
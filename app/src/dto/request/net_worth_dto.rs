@@ -0,0 +1,3 @@
+mod find_net_worth_history_params;
+
+pub use find_net_worth_history_params::FindNetWorthHistoryParams;
@@ -0,0 +1,26 @@
+use crate::dto::request::wallets_dto::{
+    FindWalletBalanceHistoryParams, ValidatedFindWalletBalanceHistoryParams,
+};
+use crate::enums::balance_history_granularity::BalanceHistoryGranularity;
+use crate::errors::AppError;
+use serde::Deserialize;
+use time::Date;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct FindNetWorthHistoryParams {
+    pub start_date: Option<Date>,
+    pub end_date: Option<Date>,
+}
+
+impl FindNetWorthHistoryParams {
+    // the history always has one point per month, the range rules are the ones of a wallet
+    pub fn validate(self) -> Result<ValidatedFindWalletBalanceHistoryParams, AppError> {
+        FindWalletBalanceHistoryParams {
+            granularity: Some(String::from(BalanceHistoryGranularity::Month.get_value())),
+            start_date: self.start_date,
+            end_date: self.end_date,
+        }
+        .validate()
+    }
+}
@@ -0,0 +1,9 @@
+mod create_payee_request;
+mod get_payee_totals_params;
+mod payee_alias_request;
+mod update_payee_request;
+
+pub use create_payee_request::CreatePayeeRequest;
+pub use get_payee_totals_params::GetPayeeTotalsParams;
+pub use payee_alias_request::PayeeAliasRequest;
+pub use update_payee_request::UpdatePayeeRequest;
@@ -0,0 +1,17 @@
+use crate::dto::request::payees_dto::PayeeAliasRequest;
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct CreatePayeeRequest {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Name must be between 1 and 100 characters"
+    ))]
+    pub name: String,
+
+    #[validate(nested)]
+    pub aliases: Option<Vec<PayeeAliasRequest>>,
+}
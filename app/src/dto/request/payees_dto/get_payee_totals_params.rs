@@ -0,0 +1,23 @@
+use crate::errors::AppError;
+use serde::Deserialize;
+use time::Date;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct GetPayeeTotalsParams {
+    pub start_date: Option<Date>,
+    pub end_date: Option<Date>,
+}
+
+impl GetPayeeTotalsParams {
+    pub fn validate(self) -> Result<GetPayeeTotalsParams, AppError> {
+        if let (Some(start_date), Some(end_date)) = (self.start_date, self.end_date)
+            && start_date > end_date
+        {
+            return Err(AppError::ParseQuery(String::from(
+                "start_date cannot be after end_date",
+            )));
+        }
+        Ok(self)
+    }
+}
@@ -0,0 +1,16 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PayeeAliasRequest {
+    #[validate(length(
+        min = 1,
+        max = 255,
+        message = "Pattern must be between 1 and 255 characters"
+    ))]
+    pub pattern: String,
+
+    pub is_regex: Option<bool>,
+}
@@ -0,0 +1,26 @@
+use crate::dto::request::payees_dto::{CreatePayeeRequest, PayeeAliasRequest};
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct UpdatePayeeRequest {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Name must be between 1 and 100 characters"
+    ))]
+    pub name: String,
+
+    #[validate(nested)]
+    pub aliases: Option<Vec<PayeeAliasRequest>>,
+}
+
+impl From<UpdatePayeeRequest> for CreatePayeeRequest {
+    fn from(payload: UpdatePayeeRequest) -> Self {
+        CreatePayeeRequest {
+            name: payload.name,
+            aliases: payload.aliases,
+        }
+    }
+}
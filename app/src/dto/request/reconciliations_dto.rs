@@ -0,0 +1,3 @@
+mod create_reconciliation_request;
+
+pub use create_reconciliation_request::CreateReconciliationRequest;
@@ -0,0 +1,13 @@
+use serde::Deserialize;
+use time::Date;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateReconciliationRequest {
+    // TODO: add validation
+    pub statement_balance: String,
+
+    pub statement_date: Date,
+}
@@ -0,0 +1,7 @@
+mod create_tag_request;
+mod get_tag_totals_params;
+mod update_tag_request;
+
+pub use create_tag_request::CreateTagRequest;
+pub use get_tag_totals_params::GetTagTotalsParams;
+pub use update_tag_request::UpdateTagRequest;
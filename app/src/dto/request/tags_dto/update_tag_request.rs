@@ -0,0 +1,13 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct UpdateTagRequest {
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Name must be between 1 and 50 characters"
+    ))]
+    pub name: String,
+}
@@ -0,0 +1,3 @@
+mod upload_transaction_attachment_request;
+
+pub use upload_transaction_attachment_request::UploadTransactionAttachmentRequest;
@@ -0,0 +1,8 @@
+use utoipa::ToSchema;
+
+#[allow(dead_code)]
+#[derive(ToSchema)]
+pub struct UploadTransactionAttachmentRequest {
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+}
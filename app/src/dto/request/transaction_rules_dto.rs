@@ -0,0 +1,5 @@
+mod create_transaction_rule_request;
+mod update_transaction_rule_request;
+
+pub use create_transaction_rule_request::CreateTransactionRuleRequest;
+pub use update_transaction_rule_request::UpdateTransactionRuleRequest;
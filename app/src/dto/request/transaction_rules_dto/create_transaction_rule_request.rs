@@ -0,0 +1,35 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTransactionRuleRequest {
+    #[validate(length(min = 3, message = "Name must be at least 3 characters long"))]
+    pub name: String,
+
+    pub priority: Option<i32>,
+
+    #[validate(length(min = 1, message = "description_contains cannot be empty"))]
+    pub description_contains: Option<String>,
+
+    #[validate(length(min = 1, message = "description_regex cannot be empty"))]
+    pub description_regex: Option<String>,
+
+    pub min_amount: Option<String>,
+
+    pub max_amount: Option<String>,
+
+    #[validate(range(min = 1, message = "wallet_id cannot be less than 1"))]
+    pub wallet_id: Option<i32>,
+
+    pub flow_direction: Option<String>,
+
+    #[validate(range(min = 1, message = "category_id cannot be less than 1"))]
+    pub category_id: Option<i32>,
+
+    #[validate(range(min = 1, message = "budget_id cannot be less than 1"))]
+    pub budget_id: Option<i32>,
+
+    pub tag_ids: Option<Vec<i32>>,
+}
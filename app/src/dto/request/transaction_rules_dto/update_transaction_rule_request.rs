@@ -0,0 +1,54 @@
+use crate::dto::request::transaction_rules_dto::CreateTransactionRuleRequest;
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateTransactionRuleRequest {
+    #[validate(length(min = 3, message = "Name must be at least 3 characters long"))]
+    pub name: String,
+
+    pub priority: Option<i32>,
+
+    #[validate(length(min = 1, message = "description_contains cannot be empty"))]
+    pub description_contains: Option<String>,
+
+    #[validate(length(min = 1, message = "description_regex cannot be empty"))]
+    pub description_regex: Option<String>,
+
+    pub min_amount: Option<String>,
+
+    pub max_amount: Option<String>,
+
+    #[validate(range(min = 1, message = "wallet_id cannot be less than 1"))]
+    pub wallet_id: Option<i32>,
+
+    pub flow_direction: Option<String>,
+
+    #[validate(range(min = 1, message = "category_id cannot be less than 1"))]
+    pub category_id: Option<i32>,
+
+    #[validate(range(min = 1, message = "budget_id cannot be less than 1"))]
+    pub budget_id: Option<i32>,
+
+    pub tag_ids: Option<Vec<i32>>,
+}
+
+impl From<UpdateTransactionRuleRequest> for CreateTransactionRuleRequest {
+    fn from(payload: UpdateTransactionRuleRequest) -> Self {
+        CreateTransactionRuleRequest {
+            name: payload.name,
+            priority: payload.priority,
+            description_contains: payload.description_contains,
+            description_regex: payload.description_regex,
+            min_amount: payload.min_amount,
+            max_amount: payload.max_amount,
+            wallet_id: payload.wallet_id,
+            flow_direction: payload.flow_direction,
+            category_id: payload.category_id,
+            budget_id: payload.budget_id,
+            tag_ids: payload.tag_ids,
+        }
+    }
+}
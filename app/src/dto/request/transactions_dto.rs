@@ -1,5 +1,29 @@
+mod bulk_transactions_request;
+mod create_transaction_refund_request;
 mod create_transaction_request;
+mod create_transaction_transfer_request;
+mod export_transactions_params;
+mod find_all_transactions_params;
+mod find_duplicate_transactions_params;
+mod find_transactions_by_location_params;
+mod merge_duplicate_transactions_request;
+mod search_transactions_params;
 mod update_transaction_request;
+mod update_transaction_status_request;
 
+pub use bulk_transactions_request::{BulkTransactionOperationRequest, BulkTransactionsRequest};
+pub use create_transaction_refund_request::CreateTransactionRefundRequest;
 pub use create_transaction_request::CreateTransactionRequest;
+pub use create_transaction_transfer_request::CreateTransactionTransferRequest;
+pub use export_transactions_params::ExportTransactionsParams;
+pub use find_all_transactions_params::{
+    FindAllTransactionsParams, ValidatedFindAllTransactionsParams,
+};
+pub use find_duplicate_transactions_params::{
+    FindDuplicateTransactionsParams, ValidatedFindDuplicateTransactionsParams,
+};
+pub use find_transactions_by_location_params::{FindTransactionsByLocationParams, LocationArea};
+pub use merge_duplicate_transactions_request::MergeDuplicateTransactionsRequest;
+pub use search_transactions_params::{SearchTransactionsParams, ValidatedSearchTransactionsParams};
 pub use update_transaction_request::UpdateTransactionRequest;
+pub use update_transaction_status_request::UpdateTransactionStatusRequest;
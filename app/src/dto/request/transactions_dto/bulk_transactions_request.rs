@@ -0,0 +1,33 @@
+use crate::dto::request::transactions_dto::CreateTransactionRequest;
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkTransactionsRequest {
+    #[validate(nested)]
+    pub operations: Vec<BulkTransactionOperationRequest>,
+}
+
+#[derive(Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkTransactionOperationRequest {
+    pub action: String,
+
+    #[validate(nested)]
+    pub transaction: Option<CreateTransactionRequest>,
+
+    pub ids: Option<Vec<i32>>,
+
+    #[validate(range(min = 1, message = "category_id cannot be less than 1"))]
+    pub category_id: Option<i32>,
+
+    #[validate(range(min = 1, message = "budget_id cannot be less than 1"))]
+    pub budget_id: Option<i32>,
+
+    #[validate(range(min = 1, message = "wallet_id cannot be less than 1"))]
+    pub wallet_id: Option<i32>,
+
+    pub tag_ids: Option<Vec<i32>>,
+}
@@ -0,0 +1,19 @@
+use serde::Deserialize;
+use time::Date;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTransactionRefundRequest {
+    pub amount: String,
+
+    #[validate(range(min = 1, message = "wallet_id cannot be less than 1"))]
+    pub wallet_id: Option<i32>,
+
+    pub description: Option<String>,
+
+    pub status: Option<String>,
+
+    pub issued_at: Option<Date>,
+}
@@ -1,26 +1,62 @@
+use crate::utils::validation::validate_coordinates;
 use serde::Deserialize;
 use time::Date;
 use utoipa::ToSchema;
-use validator::Validate;
+use validator::{Validate, ValidationError};
 
 #[derive(Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_create_transaction_location"))]
 pub struct CreateTransactionRequest {
     #[validate(range(min = 1, message = "category_id cannot be less than 1"))]
-    pub category_id: i32,
+    pub category_id: Option<i32>,
 
     pub budget_id: Option<i32>,
 
+    #[validate(range(min = 1, message = "payee_id cannot be less than 1"))]
+    pub payee_id: Option<i32>,
+
     #[validate(range(min = 1, message = "category_id cannot be less than 1"))]
     pub wallet_id: i32,
 
     // TODO: add validation
     pub amount: String,
 
+    // defaults to the currency of the wallet and has to match it
+    #[validate(custom(function = "crate::utils::validation::validate_currency"))]
+    pub currency: Option<String>,
+
     pub description: Option<String>,
 
     // TODO: add validation
     pub flow_direction: String,
 
+    pub status: Option<String>,
+
     pub issued_at: Date,
+
+    pub tag_ids: Option<Vec<i32>>,
+
+    #[validate(range(
+        min = -90.0,
+        max = 90.0,
+        message = "latitude must be between -90 and 90"
+    ))]
+    pub latitude: Option<f64>,
+
+    #[validate(range(
+        min = -180.0,
+        max = 180.0,
+        message = "longitude must be between -180 and 180"
+    ))]
+    pub longitude: Option<f64>,
+
+    #[validate(length(max = 255, message = "place_name cannot be longer than 255 characters"))]
+    pub place_name: Option<String>,
+}
+
+fn validate_create_transaction_location(
+    request: &CreateTransactionRequest,
+) -> Result<(), ValidationError> {
+    validate_coordinates(request.latitude, request.longitude)
 }
@@ -0,0 +1,29 @@
+use serde::Deserialize;
+use time::Date;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTransactionTransferRequest {
+    #[validate(range(min = 1, message = "from_wallet_id cannot be less than 1"))]
+    pub from_wallet_id: i32,
+
+    #[validate(range(min = 1, message = "to_wallet_id cannot be less than 1"))]
+    pub to_wallet_id: i32,
+
+    #[validate(range(min = 1, message = "category_id cannot be less than 1"))]
+    pub category_id: i32,
+
+    // in the currency of the source wallet
+    pub amount: String,
+
+    // what arrives in the destination wallet, converted with the exchange rates when missing
+    pub to_amount: Option<String>,
+
+    pub description: Option<String>,
+
+    pub status: Option<String>,
+
+    pub issued_at: Option<Date>,
+}
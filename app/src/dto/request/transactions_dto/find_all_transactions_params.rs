@@ -0,0 +1,120 @@
+use crate::entities::sea_orm_active_enums::{TransactionStatus, TransactionType};
+use crate::errors::AppError;
+use rust_decimal::Decimal;
+use sea_orm::ActiveEnum;
+use serde::Deserialize;
+use std::str::FromStr;
+use time::Date;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct FindAllTransactionsParams {
+    pub start_date: Option<Date>,
+    pub end_date: Option<Date>,
+    pub category_id: Option<i32>,
+    pub wallet_id: Option<i32>,
+    pub budget_id: Option<i32>,
+    pub payee_id: Option<i32>,
+    pub flow_direction: Option<String>,
+    pub status: Option<String>,
+    pub min_amount: Option<String>,
+    pub max_amount: Option<String>,
+    pub tag_ids: Option<String>,
+    pub tag_match: Option<String>,
+    pub scheduled: Option<bool>,
+}
+
+impl FindAllTransactionsParams {
+    pub fn validate(self) -> Result<ValidatedFindAllTransactionsParams, AppError> {
+        if let (Some(start_date), Some(end_date)) = (self.start_date, self.end_date)
+            && start_date > end_date
+        {
+            return Err(AppError::ParseQuery(String::from(
+                "start_date cannot be after end_date",
+            )));
+        }
+
+        let flow_direction = match self.flow_direction {
+            Some(flow_direction) => Some(
+                TransactionType::try_from_value(&flow_direction)
+                    .map_err(|_| AppError::ParseQuery(String::from("invalid flow_direction")))?,
+            ),
+            None => None,
+        };
+        let status = match self.status {
+            Some(status) => Some(
+                TransactionStatus::try_from_value(&status)
+                    .map_err(|_| AppError::ParseQuery(String::from("invalid status")))?,
+            ),
+            None => None,
+        };
+        let min_amount = match self.min_amount {
+            Some(min_amount) => Some(Decimal::from_str(&min_amount)?),
+            None => None,
+        };
+        let max_amount = match self.max_amount {
+            Some(max_amount) => Some(Decimal::from_str(&max_amount)?),
+            None => None,
+        };
+
+        let tag_ids = match self.tag_ids {
+            Some(tag_ids) => {
+                let mut tag_ids = tag_ids
+                    .split(',')
+                    .map(|tag_id| tag_id.trim().parse::<i32>())
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| {
+                        AppError::ParseQuery(String::from(
+                            "tag_ids must be a comma separated list of ids",
+                        ))
+                    })?;
+                tag_ids.sort_unstable();
+                tag_ids.dedup();
+                Some(tag_ids)
+            }
+            None => None,
+        };
+        let match_all_tags = match self.tag_match.as_deref() {
+            None | Some("any") => false,
+            Some("all") => true,
+            Some(_) => {
+                return Err(AppError::ParseQuery(String::from(
+                    "tag_match must be either any or all",
+                )));
+            }
+        };
+
+        Ok(ValidatedFindAllTransactionsParams {
+            start_date: self.start_date,
+            end_date: self.end_date,
+            category_id: self.category_id,
+            wallet_id: self.wallet_id,
+            budget_id: self.budget_id,
+            payee_id: self.payee_id,
+            flow_direction,
+            status,
+            min_amount,
+            max_amount,
+            tag_ids,
+            match_all_tags,
+            scheduled: self.scheduled,
+        })
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct ValidatedFindAllTransactionsParams {
+    pub start_date: Option<Date>,
+    pub end_date: Option<Date>,
+    pub category_id: Option<i32>,
+    pub wallet_id: Option<i32>,
+    pub budget_id: Option<i32>,
+    pub payee_id: Option<i32>,
+    pub flow_direction: Option<TransactionType>,
+    pub status: Option<TransactionStatus>,
+    pub min_amount: Option<Decimal>,
+    pub max_amount: Option<Decimal>,
+    pub tag_ids: Option<Vec<i32>>,
+    pub match_all_tags: bool,
+    pub scheduled: Option<bool>,
+}
@@ -0,0 +1,60 @@
+use crate::errors::AppError;
+use serde::Deserialize;
+use time::Date;
+use utoipa::ToSchema;
+
+const DEFAULT_WINDOW_DAYS: i64 = 3;
+const MAX_WINDOW_DAYS: i64 = 31;
+const DEFAULT_MIN_SIMILARITY: f64 = 0.8;
+
+#[derive(Deserialize, ToSchema)]
+pub struct FindDuplicateTransactionsParams {
+    pub wallet_id: Option<i32>,
+    pub start_date: Option<Date>,
+    pub end_date: Option<Date>,
+    pub window_days: Option<i64>,
+    pub min_similarity: Option<f64>,
+}
+
+impl FindDuplicateTransactionsParams {
+    pub fn validate(self) -> Result<ValidatedFindDuplicateTransactionsParams, AppError> {
+        let window_days = self.window_days.unwrap_or(DEFAULT_WINDOW_DAYS);
+        if !(0..=MAX_WINDOW_DAYS).contains(&window_days) {
+            return Err(AppError::ParseQuery(format!(
+                "window_days must be between 0 and {MAX_WINDOW_DAYS}"
+            )));
+        }
+
+        let min_similarity = self.min_similarity.unwrap_or(DEFAULT_MIN_SIMILARITY);
+        if !(0.0..=1.0).contains(&min_similarity) {
+            return Err(AppError::ParseQuery(String::from(
+                "min_similarity must be between 0 and 1",
+            )));
+        }
+
+        if let (Some(start_date), Some(end_date)) = (self.start_date, self.end_date)
+            && start_date > end_date
+        {
+            return Err(AppError::ParseQuery(String::from(
+                "start_date cannot be after end_date",
+            )));
+        }
+
+        Ok(ValidatedFindDuplicateTransactionsParams {
+            wallet_id: self.wallet_id,
+            start_date: self.start_date,
+            end_date: self.end_date,
+            window_days,
+            min_similarity,
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct ValidatedFindDuplicateTransactionsParams {
+    pub wallet_id: Option<i32>,
+    pub start_date: Option<Date>,
+    pub end_date: Option<Date>,
+    pub window_days: i64,
+    pub min_similarity: f64,
+}
@@ -0,0 +1,118 @@
+use crate::constants::transaction_constants::MAX_LOCATION_RADIUS;
+use crate::errors::AppError;
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct FindTransactionsByLocationParams {
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub radius: Option<f64>,
+    pub min_latitude: Option<f64>,
+    pub min_longitude: Option<f64>,
+    pub max_latitude: Option<f64>,
+    pub max_longitude: Option<f64>,
+}
+
+fn validate_latitude(name: &str, latitude: f64) -> Result<f64, AppError> {
+    if !(-90.0..=90.0).contains(&latitude) {
+        return Err(AppError::ParseQuery(format!(
+            "{name} must be between -90 and 90"
+        )));
+    }
+    Ok(latitude)
+}
+
+fn validate_longitude(name: &str, longitude: f64) -> Result<f64, AppError> {
+    if !(-180.0..=180.0).contains(&longitude) {
+        return Err(AppError::ParseQuery(format!(
+            "{name} must be between -180 and 180"
+        )));
+    }
+    Ok(longitude)
+}
+
+impl FindTransactionsByLocationParams {
+    pub fn validate(self) -> Result<Option<LocationArea>, AppError> {
+        let has_radius =
+            self.latitude.is_some() || self.longitude.is_some() || self.radius.is_some();
+        let has_bounding_box = self.min_latitude.is_some()
+            || self.min_longitude.is_some()
+            || self.max_latitude.is_some()
+            || self.max_longitude.is_some();
+
+        match (has_radius, has_bounding_box) {
+            (false, false) => Ok(None),
+            (true, true) => Err(AppError::ParseQuery(String::from(
+                "Either latitude, longitude and radius or a bounding box can be given, not both",
+            ))),
+            (true, false) => {
+                let (Some(latitude), Some(longitude), Some(radius)) =
+                    (self.latitude, self.longitude, self.radius)
+                else {
+                    return Err(AppError::ParseQuery(String::from(
+                        "latitude, longitude and radius must be given together",
+                    )));
+                };
+                if !(radius > 0.0 && radius <= MAX_LOCATION_RADIUS) {
+                    return Err(AppError::ParseQuery(format!(
+                        "radius must be greater than 0 and at most {MAX_LOCATION_RADIUS} meters"
+                    )));
+                }
+
+                Ok(Some(LocationArea::Radius {
+                    latitude: validate_latitude("latitude", latitude)?,
+                    longitude: validate_longitude("longitude", longitude)?,
+                    radius,
+                }))
+            }
+            (false, true) => {
+                let (
+                    Some(min_latitude),
+                    Some(min_longitude),
+                    Some(max_latitude),
+                    Some(max_longitude),
+                ) = (
+                    self.min_latitude,
+                    self.min_longitude,
+                    self.max_latitude,
+                    self.max_longitude,
+                )
+                else {
+                    return Err(AppError::ParseQuery(String::from(
+                        "min_latitude, min_longitude, max_latitude and max_longitude must be given together",
+                    )));
+                };
+                if min_latitude > max_latitude {
+                    return Err(AppError::ParseQuery(String::from(
+                        "min_latitude cannot be greater than max_latitude",
+                    )));
+                }
+
+                Ok(Some(LocationArea::BoundingBox {
+                    min_latitude: validate_latitude("min_latitude", min_latitude)?,
+                    min_longitude: validate_longitude("min_longitude", min_longitude)?,
+                    max_latitude: validate_latitude("max_latitude", max_latitude)?,
+                    max_longitude: validate_longitude("max_longitude", max_longitude)?,
+                }))
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum LocationArea {
+    // radius in meters around the point
+    Radius {
+        latitude: f64,
+        longitude: f64,
+        radius: f64,
+    },
+    // min_longitude greater than max_longitude means the box crosses the antimeridian
+    BoundingBox {
+        min_latitude: f64,
+        min_longitude: f64,
+        max_latitude: f64,
+        max_longitude: f64,
+    },
+}
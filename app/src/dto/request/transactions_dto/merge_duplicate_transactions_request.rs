@@ -0,0 +1,13 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeDuplicateTransactionsRequest {
+    #[validate(range(min = 1, message = "keep_id cannot be less than 1"))]
+    pub keep_id: i32,
+
+    #[validate(range(min = 1, message = "remove_id cannot be less than 1"))]
+    pub remove_id: i32,
+}
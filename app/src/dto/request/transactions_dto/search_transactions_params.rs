@@ -0,0 +1,54 @@
+use crate::constants::transaction_constants::{
+    DEFAULT_TRANSACTION_SEARCH_PAGE_SIZE, MAX_TRANSACTION_SEARCH_PAGE_SIZE,
+    MAX_TRANSACTION_SEARCH_QUERY_LENGTH,
+};
+use crate::errors::AppError;
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct SearchTransactionsParams {
+    pub q: Option<String>,
+    pub page: Option<u64>,
+    pub page_size: Option<u64>,
+}
+
+impl SearchTransactionsParams {
+    pub fn validate(self) -> Result<ValidatedSearchTransactionsParams, AppError> {
+        let query = self.q.as_deref().map(str::trim).unwrap_or_default();
+        if query.is_empty() {
+            return Err(AppError::ParseQuery(String::from("q cannot be empty")));
+        }
+        if query.chars().count() > MAX_TRANSACTION_SEARCH_QUERY_LENGTH {
+            return Err(AppError::ParseQuery(format!(
+                "q cannot be longer than {MAX_TRANSACTION_SEARCH_QUERY_LENGTH} characters"
+            )));
+        }
+
+        let page = self.page.unwrap_or(1);
+        if page == 0 {
+            return Err(AppError::ParseQuery(String::from("page cannot be 0")));
+        }
+        let page_size = self
+            .page_size
+            .unwrap_or(DEFAULT_TRANSACTION_SEARCH_PAGE_SIZE);
+        if !(1..=MAX_TRANSACTION_SEARCH_PAGE_SIZE).contains(&page_size) {
+            return Err(AppError::ParseQuery(format!(
+                "page_size must be between 1 and {MAX_TRANSACTION_SEARCH_PAGE_SIZE}"
+            )));
+        }
+
+        Ok(ValidatedSearchTransactionsParams {
+            query: String::from(query),
+            page,
+            page_size,
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct ValidatedSearchTransactionsParams {
+    pub query: String,
+    pub page: u64,
+    pub page_size: u64,
+}
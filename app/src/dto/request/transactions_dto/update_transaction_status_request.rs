@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct UpdateTransactionStatusRequest {
+    // TODO: add validation
+    pub status: String,
+}
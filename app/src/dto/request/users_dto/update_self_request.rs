@@ -11,4 +11,8 @@ pub struct UpdateSelfRequest {
     pub email: String,
 
     pub password: String,
+
+    // totals across wallets are converted into this currency, left unchanged when missing
+    #[validate(custom(function = "crate::utils::validation::validate_currency"))]
+    pub reporting_currency: Option<String>,
 }
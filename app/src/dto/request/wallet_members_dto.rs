@@ -0,0 +1,5 @@
+mod create_wallet_member_request;
+mod update_wallet_member_request;
+
+pub use create_wallet_member_request::CreateWalletMemberRequest;
+pub use update_wallet_member_request::UpdateWalletMemberRequest;
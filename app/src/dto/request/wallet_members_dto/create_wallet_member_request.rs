@@ -0,0 +1,16 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateWalletMemberRequest {
+    // either the username or the email of the invited user, not both
+    pub username: Option<String>,
+
+    #[validate(email(message = "Invalid email"))]
+    pub email: Option<String>,
+
+    // EDITOR or VIEWER
+    pub role: String,
+}
@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateWalletMemberRequest {
+    // EDITOR or VIEWER
+    pub role: String,
+}
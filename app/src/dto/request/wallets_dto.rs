@@ -1,7 +1,13 @@
 mod create_wallet_request;
 mod find_all_wallets_params;
+mod find_wallet_balance_history_params;
+mod get_wallet_balance_params;
 mod update_wallet_request;
 
 pub use create_wallet_request::CreateWalletRequest;
 pub use find_all_wallets_params::FindAllWalletsParams;
+pub use find_wallet_balance_history_params::{
+    FindWalletBalanceHistoryParams, ValidatedFindWalletBalanceHistoryParams,
+};
+pub use get_wallet_balance_params::GetWalletBalanceParams;
 pub use update_wallet_request::UpdateWalletRequest;
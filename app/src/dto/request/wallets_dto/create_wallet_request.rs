@@ -1,4 +1,5 @@
 use serde::Deserialize;
+use time::Date;
 use utoipa::ToSchema;
 use validator::Validate;
 
@@ -8,4 +9,42 @@ pub struct CreateWalletRequest {
     pub name: String,
 
     pub description: Option<String>,
+
+    // cannot be changed once the wallet is created, defaults to IDR
+    #[validate(custom(function = "crate::utils::validation::validate_currency"))]
+    pub currency: Option<String>,
+
+    // negative for cards and loans that already owe money, defaults to 0
+    pub opening_balance: Option<String>,
+
+    // the day the opening balance was counted, defaults to today
+    pub opening_date: Option<Date>,
+
+    // leaves the wallet out of net worth and other totals, defaults to false
+    pub exclude_from_totals: Option<bool>,
+
+    // CASH, BANK, CREDIT_CARD, SAVINGS, LOAN or INVESTMENT, defaults to CASH
+    pub wallet_type: Option<String>,
+
+    // credit cards only
+    pub credit_limit: Option<String>,
+
+    // credit cards only, required for them
+    #[validate(range(
+        min = 1,
+        max = 31,
+        message = "statement_closing_day must be between 1 and 31"
+    ))]
+    pub statement_closing_day: Option<i16>,
+
+    // credit cards only, required for them
+    #[validate(range(
+        min = 1,
+        max = 31,
+        message = "payment_due_day must be between 1 and 31"
+    ))]
+    pub payment_due_day: Option<i16>,
+
+    // savings and loans only, yearly percentage
+    pub interest_rate: Option<String>,
 }
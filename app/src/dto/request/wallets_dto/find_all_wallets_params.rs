@@ -4,4 +4,7 @@ use utoipa::ToSchema;
 #[derive(Deserialize, ToSchema)]
 pub struct FindAllWalletsParams {
     pub max_fetch: Option<u64>,
+
+    // archived wallets are left out unless this is true
+    pub include_archived: Option<bool>,
 }
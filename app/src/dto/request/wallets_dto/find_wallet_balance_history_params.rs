@@ -0,0 +1,58 @@
+use crate::constants::wallet_constants::MAX_BALANCE_HISTORY_POINTS;
+use crate::enums::balance_history_granularity::BalanceHistoryGranularity;
+use crate::errors::AppError;
+use serde::Deserialize;
+use time::{Date, OffsetDateTime};
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct FindWalletBalanceHistoryParams {
+    pub granularity: Option<String>,
+    pub start_date: Option<Date>,
+    pub end_date: Option<Date>,
+}
+
+impl FindWalletBalanceHistoryParams {
+    pub fn validate(self) -> Result<ValidatedFindWalletBalanceHistoryParams, AppError> {
+        let granularity = match self.granularity {
+            Some(ref granularity) => BalanceHistoryGranularity::from_string(granularity)?,
+            None => BalanceHistoryGranularity::Day,
+        };
+        let end_date = self
+            .end_date
+            .unwrap_or_else(|| OffsetDateTime::now_utc().date());
+        let start_date = match self.start_date {
+            Some(start_date) => start_date,
+            None => granularity.find_default_start_date(end_date)?,
+        };
+        if start_date > end_date {
+            return Err(AppError::ParseQuery(String::from(
+                "start_date cannot be after end_date",
+            )));
+        }
+
+        let days = (end_date - start_date).whole_days();
+        let points = match granularity {
+            BalanceHistoryGranularity::Day => days + 1,
+            BalanceHistoryGranularity::Week => days / 7 + 2,
+            BalanceHistoryGranularity::Month => days / 28 + 2,
+        };
+        if points > MAX_BALANCE_HISTORY_POINTS {
+            return Err(AppError::ParseQuery(format!(
+                "the range cannot hold more than {MAX_BALANCE_HISTORY_POINTS} points, use a coarser granularity"
+            )));
+        }
+
+        Ok(ValidatedFindWalletBalanceHistoryParams {
+            granularity,
+            start_date,
+            end_date,
+        })
+    }
+}
+
+pub struct ValidatedFindWalletBalanceHistoryParams {
+    pub granularity: BalanceHistoryGranularity,
+    pub start_date: Date,
+    pub end_date: Date,
+}
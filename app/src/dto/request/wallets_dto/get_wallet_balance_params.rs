@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use time::Date;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct GetWalletBalanceParams {
+    // defaults to today
+    pub as_of: Option<Date>,
+}
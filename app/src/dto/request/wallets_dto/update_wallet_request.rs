@@ -8,4 +8,32 @@ pub struct UpdateWalletRequest {
     pub name: String,
 
     pub description: Option<String>,
+
+    // keeps the current setting when missing
+    pub exclude_from_totals: Option<bool>,
+
+    // CASH, BANK, CREDIT_CARD, SAVINGS, LOAN or INVESTMENT, keeps the current type when missing
+    pub wallet_type: Option<String>,
+
+    // credit cards only, keeps the current limit when missing
+    pub credit_limit: Option<String>,
+
+    // credit cards only, keeps the current day when missing
+    #[validate(range(
+        min = 1,
+        max = 31,
+        message = "statement_closing_day must be between 1 and 31"
+    ))]
+    pub statement_closing_day: Option<i16>,
+
+    // credit cards only, keeps the current day when missing
+    #[validate(range(
+        min = 1,
+        max = 31,
+        message = "payment_due_day must be between 1 and 31"
+    ))]
+    pub payment_due_day: Option<i16>,
+
+    // savings and loans only, yearly percentage, keeps the current rate when missing
+    pub interest_rate: Option<String>,
 }
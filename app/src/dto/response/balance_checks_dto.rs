@@ -0,0 +1,5 @@
+mod get_balance_check_response;
+
+pub use get_balance_check_response::{
+    BalanceCheckReport, BalanceDiscrepancy, GetBalanceCheckResponse, SnapshotDiscrepancy,
+};
@@ -0,0 +1,122 @@
+use rust_decimal::Decimal;
+use serde::Serialize;
+use time::Date;
+use utoipa::ToSchema;
+
+pub struct BalanceDiscrepancy {
+    pub id: i32,
+    pub user_id: i32,
+    pub name: String,
+    pub recorded_amount: Decimal,
+    pub expected_amount: Decimal,
+}
+
+pub struct SnapshotDiscrepancy {
+    pub wallet_id: i32,
+    pub user_id: i32,
+    pub name: String,
+    pub first_date: Date,
+    pub mismatched_dates: usize,
+}
+
+pub struct BalanceCheckReport {
+    pub checked_wallets: usize,
+    pub checked_budgets: usize,
+    pub wallet_discrepancies: Vec<BalanceDiscrepancy>,
+    pub budget_discrepancies: Vec<BalanceDiscrepancy>,
+    pub snapshot_discrepancies: Vec<SnapshotDiscrepancy>,
+    pub repaired: bool,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBalanceCheckResponse {
+    pub checked_wallets: usize,
+
+    pub checked_budgets: usize,
+
+    pub wallet_discrepancies: Vec<BalanceDiscrepancyResponse>,
+
+    pub budget_discrepancies: Vec<BalanceDiscrepancyResponse>,
+
+    // wallets whose daily balance history disagrees with the ledger
+    pub snapshot_discrepancies: Vec<SnapshotDiscrepancyResponse>,
+
+    // whether the recorded amounts were overwritten with the expected ones
+    pub repaired: bool,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceDiscrepancyResponse {
+    pub id: i32,
+
+    pub user_id: i32,
+
+    pub name: String,
+
+    pub recorded_amount: String,
+
+    pub expected_amount: String,
+
+    pub difference: String,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotDiscrepancyResponse {
+    pub wallet_id: i32,
+
+    pub user_id: i32,
+
+    pub name: String,
+
+    // the earliest day whose recorded closing balance is wrong
+    pub first_date: Date,
+
+    pub mismatched_dates: usize,
+}
+
+impl From<BalanceDiscrepancy> for BalanceDiscrepancyResponse {
+    fn from(discrepancy: BalanceDiscrepancy) -> Self {
+        BalanceDiscrepancyResponse {
+            id: discrepancy.id,
+            user_id: discrepancy.user_id,
+            name: discrepancy.name,
+            recorded_amount: discrepancy.recorded_amount.to_string(),
+            expected_amount: discrepancy.expected_amount.to_string(),
+            difference: (discrepancy.recorded_amount - discrepancy.expected_amount).to_string(),
+        }
+    }
+}
+
+impl From<BalanceCheckReport> for GetBalanceCheckResponse {
+    fn from(report: BalanceCheckReport) -> Self {
+        GetBalanceCheckResponse {
+            checked_wallets: report.checked_wallets,
+            checked_budgets: report.checked_budgets,
+            wallet_discrepancies: report
+                .wallet_discrepancies
+                .into_iter()
+                .map(BalanceDiscrepancyResponse::from)
+                .collect(),
+            budget_discrepancies: report
+                .budget_discrepancies
+                .into_iter()
+                .map(BalanceDiscrepancyResponse::from)
+                .collect(),
+            snapshot_discrepancies: report
+                .snapshot_discrepancies
+                .into_iter()
+                .map(|discrepancy| SnapshotDiscrepancyResponse {
+                    wallet_id: discrepancy.wallet_id,
+                    user_id: discrepancy.user_id,
+                    name: discrepancy.name,
+                    first_date: discrepancy.first_date,
+                    mismatched_dates: discrepancy.mismatched_dates,
+                })
+                .collect(),
+            repaired: report.repaired,
+        }
+    }
+}
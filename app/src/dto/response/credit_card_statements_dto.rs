@@ -0,0 +1,3 @@
+mod get_credit_card_statement_response;
+
+pub use get_credit_card_statement_response::{CreditCardStatement, GetCreditCardStatementResponse};
@@ -0,0 +1,79 @@
+use crate::enums::credit_card_statement_status::CreditCardStatementStatus;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use time::Date;
+use utoipa::ToSchema;
+
+pub struct CreditCardStatement {
+    pub wallet_id: i32,
+    pub currency: String,
+    pub period_start: Date,
+    pub closing_date: Date,
+    pub due_date: Date,
+    pub status: CreditCardStatementStatus,
+    pub opening_balance: Decimal,
+    pub closing_balance: Decimal,
+    pub new_charges: Decimal,
+    pub payments_and_credits: Decimal,
+    pub statement_balance: Decimal,
+    pub minimum_payment: Decimal,
+    pub paid_amount: Decimal,
+    pub days_until_due: i64,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetCreditCardStatementResponse {
+    pub wallet_id: i32,
+
+    pub currency: String,
+
+    pub period_start: Date,
+
+    pub closing_date: Date,
+
+    pub due_date: Date,
+
+    pub status: String,
+
+    // signed wallet balances, negative while money is owed on the card
+    pub opening_balance: String,
+
+    pub closing_balance: String,
+
+    pub new_charges: String,
+
+    pub payments_and_credits: String,
+
+    pub statement_balance: String,
+
+    pub minimum_payment: String,
+
+    pub paid_amount: String,
+
+    pub remaining_amount: String,
+
+    pub days_until_due: i64,
+}
+
+impl From<CreditCardStatement> for GetCreditCardStatementResponse {
+    fn from(statement: CreditCardStatement) -> Self {
+        GetCreditCardStatementResponse {
+            wallet_id: statement.wallet_id,
+            currency: statement.currency,
+            period_start: statement.period_start,
+            closing_date: statement.closing_date,
+            due_date: statement.due_date,
+            status: String::from(statement.status.get_value()),
+            opening_balance: statement.opening_balance.to_string(),
+            closing_balance: statement.closing_balance.to_string(),
+            new_charges: statement.new_charges.to_string(),
+            payments_and_credits: statement.payments_and_credits.to_string(),
+            statement_balance: statement.statement_balance.to_string(),
+            minimum_payment: statement.minimum_payment.to_string(),
+            paid_amount: statement.paid_amount.to_string(),
+            remaining_amount: (statement.statement_balance - statement.paid_amount).to_string(),
+            days_until_due: statement.days_until_due,
+        }
+    }
+}
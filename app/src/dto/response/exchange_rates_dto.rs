@@ -0,0 +1,9 @@
+mod convert_currency_response;
+mod create_exchange_rate_response;
+mod get_exchange_rate_response;
+mod import_exchange_rates_response;
+
+pub use convert_currency_response::ConvertCurrencyResponse;
+pub use create_exchange_rate_response::CreateExchangeRateResponse;
+pub use get_exchange_rate_response::GetExchangeRateResponse;
+pub use import_exchange_rates_response::ImportExchangeRatesResponse;
@@ -0,0 +1,20 @@
+use serde::Serialize;
+use time::Date;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertCurrencyResponse {
+    pub amount: String,
+
+    pub from: String,
+
+    pub to: String,
+
+    pub converted_amount: String,
+
+    pub rate: String,
+
+    // the date of the rate that was used, the latest one on or before the requested date
+    pub rate_date: Date,
+}
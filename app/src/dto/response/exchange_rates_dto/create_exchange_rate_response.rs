@@ -0,0 +1,30 @@
+use crate::entities::exchange_rates;
+use serde::Serialize;
+use time::Date;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateExchangeRateResponse {
+    pub id: i32,
+
+    pub base_currency: String,
+
+    pub quote_currency: String,
+
+    pub rate_date: Date,
+
+    pub rate: String,
+}
+
+impl From<exchange_rates::Model> for CreateExchangeRateResponse {
+    fn from(exchange_rate: exchange_rates::Model) -> Self {
+        CreateExchangeRateResponse {
+            id: exchange_rate.id,
+            base_currency: exchange_rate.base_currency,
+            quote_currency: exchange_rate.quote_currency,
+            rate_date: exchange_rate.rate_date,
+            rate: exchange_rate.rate.normalize().to_string(),
+        }
+    }
+}
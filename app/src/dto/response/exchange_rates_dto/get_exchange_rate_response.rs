@@ -0,0 +1,34 @@
+use crate::entities::exchange_rates;
+use serde::Serialize;
+use time::Date;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetExchangeRateResponse {
+    pub id: i32,
+
+    pub base_currency: String,
+
+    pub quote_currency: String,
+
+    pub rate_date: Date,
+
+    pub rate: String,
+
+    // imported rates are shared, manual ones belong to the user who entered them
+    pub shared: bool,
+}
+
+impl From<exchange_rates::Model> for GetExchangeRateResponse {
+    fn from(exchange_rate: exchange_rates::Model) -> Self {
+        GetExchangeRateResponse {
+            id: exchange_rate.id,
+            base_currency: exchange_rate.base_currency,
+            quote_currency: exchange_rate.quote_currency,
+            rate_date: exchange_rate.rate_date,
+            rate: exchange_rate.rate.normalize().to_string(),
+            shared: exchange_rate.user_id.is_none(),
+        }
+    }
+}
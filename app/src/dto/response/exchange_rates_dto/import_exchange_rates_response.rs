@@ -0,0 +1,18 @@
+use serde::Serialize;
+use time::Date;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportExchangeRatesResponse {
+    pub imported_count: u64,
+
+    // rates of currencies that are no longer in ISO 4217 or without a value for the day
+    pub skipped_count: u64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<Date>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<Date>,
+}
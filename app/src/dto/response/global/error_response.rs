@@ -14,6 +14,10 @@ pub enum ErrorCode {
     Authorization,
     InvalidFormatter,
     Expired,
+    Io,
+    Export,
+    Storage,
+    Conflict,
 }
 
 #[derive(Serialize)]
@@ -0,0 +1,5 @@
+mod create_installment_plan_response;
+mod get_installment_plan_response;
+
+pub use create_installment_plan_response::CreateInstallmentPlanResponse;
+pub use get_installment_plan_response::GetInstallmentPlanResponse;
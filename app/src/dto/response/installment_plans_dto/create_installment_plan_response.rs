@@ -0,0 +1,51 @@
+use crate::dto::response::transactions_dto::GetTransactionResponse;
+use crate::entities::{installment_plans, transactions};
+use serde::Serialize;
+use time::Date;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateInstallmentPlanResponse {
+    pub id: i32,
+
+    pub wallet_id: i32,
+
+    pub budget_id: Option<i32>,
+
+    pub category_id: Option<i32>,
+
+    pub payee_id: Option<i32>,
+
+    pub description: Option<String>,
+
+    pub total_amount: String,
+
+    pub installment_count: i32,
+
+    pub first_issued_at: Date,
+
+    pub installments: Vec<GetTransactionResponse>,
+}
+
+impl From<(installment_plans::Model, Vec<transactions::Model>)> for CreateInstallmentPlanResponse {
+    fn from(
+        (installment_plan, installments): (installment_plans::Model, Vec<transactions::Model>),
+    ) -> Self {
+        CreateInstallmentPlanResponse {
+            id: installment_plan.id,
+            wallet_id: installment_plan.wallet_id,
+            budget_id: installment_plan.budget_id,
+            category_id: installment_plan.category_id,
+            payee_id: installment_plan.payee_id,
+            description: installment_plan.description,
+            total_amount: installment_plan.total_amount.to_string(),
+            installment_count: installment_plan.installment_count,
+            first_issued_at: installment_plan.first_issued_at,
+            installments: installments
+                .into_iter()
+                .map(GetTransactionResponse::from)
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::dto::response::transactions_dto::GetTransactionResponse;
+use crate::entities::{installment_plans, transactions};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use time::{Date, OffsetDateTime};
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetInstallmentPlanResponse {
+    pub id: i32,
+
+    pub wallet_id: i32,
+
+    pub budget_id: Option<i32>,
+
+    pub category_id: Option<i32>,
+
+    pub payee_id: Option<i32>,
+
+    pub description: Option<String>,
+
+    pub total_amount: String,
+
+    pub installment_count: i32,
+
+    pub first_issued_at: Date,
+
+    pub paid_off_at: Option<OffsetDateTime>,
+
+    pub paid_count: usize,
+
+    pub paid_amount: String,
+
+    pub remaining_count: usize,
+
+    pub remaining_amount: String,
+
+    pub installments: Vec<GetTransactionResponse>,
+}
+
+impl
+    From<(
+        installment_plans::Model,
+        Vec<transactions::Model>,
+        Vec<transactions::Model>,
+    )> for GetInstallmentPlanResponse
+{
+    fn from(
+        (installment_plan, paid_installments, remaining_installments): (
+            installment_plans::Model,
+            Vec<transactions::Model>,
+            Vec<transactions::Model>,
+        ),
+    ) -> Self {
+        GetInstallmentPlanResponse {
+            id: installment_plan.id,
+            wallet_id: installment_plan.wallet_id,
+            budget_id: installment_plan.budget_id,
+            category_id: installment_plan.category_id,
+            payee_id: installment_plan.payee_id,
+            description: installment_plan.description,
+            total_amount: installment_plan.total_amount.to_string(),
+            installment_count: installment_plan.installment_count,
+            first_issued_at: installment_plan.first_issued_at,
+            paid_off_at: installment_plan.paid_off_at,
+            paid_count: paid_installments.len(),
+            paid_amount: paid_installments
+                .iter()
+                .map(|installment| installment.amount)
+                .sum::<Decimal>()
+                .to_string(),
+            remaining_count: remaining_installments.len(),
+            remaining_amount: remaining_installments
+                .iter()
+                .map(|installment| installment.amount)
+                .sum::<Decimal>()
+                .to_string(),
+            installments: paid_installments
+                .into_iter()
+                .chain(remaining_installments)
+                .map(GetTransactionResponse::from)
+                .collect(),
+        }
+    }
+}
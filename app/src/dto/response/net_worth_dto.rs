@@ -0,0 +1,5 @@
+mod get_net_worth_history_response;
+mod get_net_worth_response;
+
+pub use get_net_worth_history_response::{GetNetWorthHistoryResponse, NetWorthPoint};
+pub use get_net_worth_response::{GetNetWorthResponse, NetWorthWalletTypeTotal};
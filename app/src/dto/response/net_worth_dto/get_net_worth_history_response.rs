@@ -0,0 +1,54 @@
+use rust_decimal::Decimal;
+use serde::Serialize;
+use time::Date;
+use utoipa::ToSchema;
+
+pub struct NetWorthPoint {
+    pub date: Date,
+    pub assets: Decimal,
+    pub liabilities: Decimal,
+    pub complete: bool,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetNetWorthHistoryResponse {
+    // the reporting currency of the user
+    pub currency: String,
+
+    pub points: Vec<NetWorthPointResponse>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NetWorthPointResponse {
+    // the last day of the month, or the end of the range when it cuts the month short
+    pub date: Date,
+
+    pub assets: String,
+
+    pub liabilities: String,
+
+    pub net_worth: String,
+
+    // false when a wallet had no exchange rate on or before the date and was left out
+    pub complete: bool,
+}
+
+impl From<(String, Vec<NetWorthPoint>)> for GetNetWorthHistoryResponse {
+    fn from((currency, points): (String, Vec<NetWorthPoint>)) -> Self {
+        GetNetWorthHistoryResponse {
+            currency,
+            points: points
+                .into_iter()
+                .map(|point| NetWorthPointResponse {
+                    date: point.date,
+                    assets: point.assets.to_string(),
+                    liabilities: point.liabilities.to_string(),
+                    net_worth: (point.assets - point.liabilities).to_string(),
+                    complete: point.complete,
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::entities::sea_orm_active_enums::WalletType;
+use crate::utils::wallet_utils;
+use rust_decimal::Decimal;
+use sea_orm::ActiveEnum;
+use serde::Serialize;
+use time::Date;
+use utoipa::ToSchema;
+
+pub struct NetWorthWalletTypeTotal {
+    pub wallet_type: WalletType,
+    pub wallet_count: usize,
+    pub total: Decimal,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetNetWorthResponse {
+    // the reporting currency of the user
+    pub currency: String,
+
+    pub as_of: Date,
+
+    pub assets: String,
+
+    // what is owed on credit cards and loans, positive while there is debt
+    pub liabilities: String,
+
+    pub net_worth: String,
+
+    pub wallet_types: Vec<NetWorthWalletTypeResponse>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NetWorthWalletTypeResponse {
+    pub wallet_type: String,
+
+    pub is_liability: bool,
+
+    pub wallet_count: usize,
+
+    // the summed balances, negative for liabilities that owe money
+    pub total: String,
+}
+
+impl From<(String, Date, Vec<NetWorthWalletTypeTotal>)> for GetNetWorthResponse {
+    fn from(
+        (currency, as_of, wallet_type_totals): (String, Date, Vec<NetWorthWalletTypeTotal>),
+    ) -> Self {
+        let mut assets = Decimal::ZERO;
+        let mut liabilities = Decimal::ZERO;
+        for wallet_type_total in &wallet_type_totals {
+            if wallet_utils::is_liability(&wallet_type_total.wallet_type) {
+                liabilities -= wallet_type_total.total;
+            } else {
+                assets += wallet_type_total.total;
+            }
+        }
+
+        GetNetWorthResponse {
+            currency,
+            as_of,
+            assets: assets.to_string(),
+            liabilities: liabilities.to_string(),
+            net_worth: (assets - liabilities).to_string(),
+            wallet_types: wallet_type_totals
+                .into_iter()
+                .map(|wallet_type_total| NetWorthWalletTypeResponse {
+                    is_liability: wallet_utils::is_liability(&wallet_type_total.wallet_type),
+                    wallet_type: wallet_type_total.wallet_type.into_value(),
+                    wallet_count: wallet_type_total.wallet_count,
+                    total: wallet_type_total.total.to_string(),
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+mod apply_payees_response;
+mod create_payee_response;
+mod get_payee_response;
+mod get_payee_total_response;
+mod payee_alias_response;
+mod update_payee_response;
+
+pub use apply_payees_response::ApplyPayeesResponse;
+pub use create_payee_response::CreatePayeeResponse;
+pub use get_payee_response::GetPayeeResponse;
+pub use get_payee_total_response::{GetPayeeTotalResponse, PayeeTotalRow};
+pub use payee_alias_response::PayeeAliasResponse;
+pub use update_payee_response::UpdatePayeeResponse;
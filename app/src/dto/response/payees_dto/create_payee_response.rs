@@ -0,0 +1,27 @@
+use crate::dto::response::payees_dto::PayeeAliasResponse;
+use crate::entities::{payee_aliases, payees};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePayeeResponse {
+    pub id: i32,
+
+    pub name: String,
+
+    pub aliases: Vec<PayeeAliasResponse>,
+}
+
+impl From<(payees::Model, Vec<payee_aliases::Model>)> for CreatePayeeResponse {
+    fn from((payee, payee_aliases): (payees::Model, Vec<payee_aliases::Model>)) -> Self {
+        CreatePayeeResponse {
+            id: payee.id,
+            name: payee.name,
+            aliases: payee_aliases
+                .into_iter()
+                .map(PayeeAliasResponse::from)
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::entities::sea_orm_active_enums::TransactionType;
+use rust_decimal::Decimal;
+use sea_orm::FromQueryResult;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(FromQueryResult)]
+pub struct PayeeTotalRow {
+    pub payee_id: i32,
+    pub name: String,
+    pub flow_direction: TransactionType,
+    pub currency: String,
+    pub total_amount: Decimal,
+    pub transaction_count: i64,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPayeeTotalResponse {
+    pub payee_id: i32,
+
+    pub name: String,
+
+    // the reporting currency of the user
+    pub currency: String,
+
+    pub total_income: String,
+
+    pub total_outcome: String,
+
+    pub transaction_count: i64,
+}
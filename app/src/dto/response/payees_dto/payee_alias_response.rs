@@ -0,0 +1,20 @@
+use crate::entities::payee_aliases;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PayeeAliasResponse {
+    pub pattern: String,
+
+    pub is_regex: bool,
+}
+
+impl From<payee_aliases::Model> for PayeeAliasResponse {
+    fn from(payee_alias: payee_aliases::Model) -> Self {
+        PayeeAliasResponse {
+            pattern: payee_alias.pattern,
+            is_regex: payee_alias.is_regex,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::dto::response::payees_dto::PayeeAliasResponse;
+use crate::entities::{payee_aliases, payees};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePayeeResponse {
+    pub id: i32,
+
+    pub name: String,
+
+    pub aliases: Vec<PayeeAliasResponse>,
+}
+
+impl From<(payees::Model, Vec<payee_aliases::Model>)> for UpdatePayeeResponse {
+    fn from((payee, payee_aliases): (payees::Model, Vec<payee_aliases::Model>)) -> Self {
+        UpdatePayeeResponse {
+            id: payee.id,
+            name: payee.name,
+            aliases: payee_aliases
+                .into_iter()
+                .map(PayeeAliasResponse::from)
+                .collect(),
+        }
+    }
+}
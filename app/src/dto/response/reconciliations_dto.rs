@@ -0,0 +1,7 @@
+mod create_reconciliation_response;
+mod get_reconciliation_response;
+mod get_reconciliation_summary_response;
+
+pub use create_reconciliation_response::CreateReconciliationResponse;
+pub use get_reconciliation_response::GetReconciliationResponse;
+pub use get_reconciliation_summary_response::GetReconciliationSummaryResponse;
@@ -0,0 +1,27 @@
+use crate::entities::reconciliations;
+use serde::Serialize;
+use time::Date;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateReconciliationResponse {
+    pub id: i32,
+
+    pub wallet_id: i32,
+
+    pub statement_balance: String,
+
+    pub statement_date: Date,
+}
+
+impl From<reconciliations::Model> for CreateReconciliationResponse {
+    fn from(reconciliation: reconciliations::Model) -> Self {
+        CreateReconciliationResponse {
+            id: reconciliation.id,
+            wallet_id: reconciliation.wallet_id,
+            statement_balance: reconciliation.statement_balance.to_string(),
+            statement_date: reconciliation.statement_date,
+        }
+    }
+}
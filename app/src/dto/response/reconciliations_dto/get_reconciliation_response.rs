@@ -0,0 +1,30 @@
+use crate::entities::reconciliations;
+use serde::Serialize;
+use time::{Date, OffsetDateTime};
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetReconciliationResponse {
+    pub id: i32,
+
+    pub wallet_id: i32,
+
+    pub statement_balance: String,
+
+    pub statement_date: Date,
+
+    pub completed_at: Option<OffsetDateTime>,
+}
+
+impl From<reconciliations::Model> for GetReconciliationResponse {
+    fn from(reconciliation: reconciliations::Model) -> Self {
+        GetReconciliationResponse {
+            id: reconciliation.id,
+            wallet_id: reconciliation.wallet_id,
+            statement_balance: reconciliation.statement_balance.to_string(),
+            statement_date: reconciliation.statement_date,
+            completed_at: reconciliation.completed_at,
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::dto::response::transactions_dto::GetTransactionResponse;
+use crate::entities::{reconciliations, transactions};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use time::{Date, OffsetDateTime};
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetReconciliationSummaryResponse {
+    pub id: i32,
+
+    pub wallet_id: i32,
+
+    pub statement_balance: String,
+
+    pub statement_date: Date,
+
+    pub completed_at: Option<OffsetDateTime>,
+
+    pub cleared_balance: String,
+
+    pub difference: String,
+
+    pub transactions: Vec<GetTransactionResponse>,
+}
+
+impl From<(reconciliations::Model, Decimal, Vec<transactions::Model>)>
+    for GetReconciliationSummaryResponse
+{
+    fn from(
+        (reconciliation, cleared_balance, transactions): (
+            reconciliations::Model,
+            Decimal,
+            Vec<transactions::Model>,
+        ),
+    ) -> Self {
+        GetReconciliationSummaryResponse {
+            id: reconciliation.id,
+            wallet_id: reconciliation.wallet_id,
+            statement_balance: reconciliation.statement_balance.to_string(),
+            statement_date: reconciliation.statement_date,
+            completed_at: reconciliation.completed_at,
+            cleared_balance: cleared_balance.to_string(),
+            difference: (reconciliation.statement_balance - cleared_balance).to_string(),
+            transactions: transactions
+                .into_iter()
+                .map(GetTransactionResponse::from)
+                .collect(),
+        }
+    }
+}
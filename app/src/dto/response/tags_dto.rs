@@ -0,0 +1,9 @@
+mod create_tag_response;
+mod get_tag_response;
+mod get_tag_total_response;
+mod update_tag_response;
+
+pub use create_tag_response::CreateTagResponse;
+pub use get_tag_response::GetTagResponse;
+pub use get_tag_total_response::{GetTagTotalResponse, TagTotalRow};
+pub use update_tag_response::UpdateTagResponse;
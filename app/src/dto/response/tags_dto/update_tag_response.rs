@@ -0,0 +1,20 @@
+use crate::entities::tags;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateTagResponse {
+    pub id: i32,
+
+    pub name: String,
+}
+
+impl From<tags::Model> for UpdateTagResponse {
+    fn from(tag: tags::Model) -> Self {
+        UpdateTagResponse {
+            id: tag.id,
+            name: tag.name,
+        }
+    }
+}
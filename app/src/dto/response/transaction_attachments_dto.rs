@@ -0,0 +1,3 @@
+mod get_transaction_attachment_response;
+
+pub use get_transaction_attachment_response::GetTransactionAttachmentResponse;
@@ -0,0 +1,33 @@
+use crate::entities::transaction_attachments;
+use serde::Serialize;
+use time::OffsetDateTime;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTransactionAttachmentResponse {
+    pub id: i32,
+
+    pub transaction_id: i32,
+
+    pub file_name: String,
+
+    pub content_type: String,
+
+    pub size: i64,
+
+    pub created_at: OffsetDateTime,
+}
+
+impl From<transaction_attachments::Model> for GetTransactionAttachmentResponse {
+    fn from(transaction_attachment: transaction_attachments::Model) -> Self {
+        GetTransactionAttachmentResponse {
+            id: transaction_attachment.id,
+            transaction_id: transaction_attachment.transaction_id,
+            file_name: transaction_attachment.file_name,
+            content_type: transaction_attachment.content_type,
+            size: transaction_attachment.size,
+            created_at: transaction_attachment.created_at,
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod get_transaction_revision_response;
+
+pub use get_transaction_revision_response::GetTransactionRevisionResponse;
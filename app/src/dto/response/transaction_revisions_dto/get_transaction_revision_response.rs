@@ -0,0 +1,67 @@
+use crate::entities::transaction_revisions;
+use sea_orm::ActiveEnum;
+use serde::Serialize;
+use serde_json::Value;
+use time::OffsetDateTime;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTransactionRevisionResponse {
+    pub id: i32,
+
+    pub transaction_id: i32,
+
+    pub action: String,
+
+    pub actor_id: Option<i32>,
+
+    // USER for changes made through the API, SYSTEM for background jobs
+    pub source: String,
+
+    pub request_id: Option<String>,
+
+    #[schema(value_type = Option<Object>)]
+    pub old_values: Option<Value>,
+
+    #[schema(value_type = Option<Object>)]
+    pub new_values: Option<Value>,
+
+    pub changed_fields: Vec<String>,
+
+    pub created_at: OffsetDateTime,
+}
+
+impl From<transaction_revisions::Model> for GetTransactionRevisionResponse {
+    fn from(transaction_revision: transaction_revisions::Model) -> Self {
+        let old_fields = transaction_revision
+            .old_values
+            .as_ref()
+            .and_then(Value::as_object);
+        let new_fields = transaction_revision
+            .new_values
+            .as_ref()
+            .and_then(Value::as_object);
+        let changed_fields = match (old_fields, new_fields) {
+            (Some(old_fields), Some(new_fields)) => new_fields
+                .iter()
+                .filter(|(field, value)| old_fields.get(*field) != Some(*value))
+                .map(|(field, _)| field.clone())
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        GetTransactionRevisionResponse {
+            id: transaction_revision.id,
+            transaction_id: transaction_revision.transaction_id,
+            action: transaction_revision.action.to_value(),
+            actor_id: transaction_revision.actor_id,
+            source: transaction_revision.source.to_value(),
+            request_id: transaction_revision.request_id,
+            old_values: transaction_revision.old_values,
+            new_values: transaction_revision.new_values,
+            changed_fields,
+            created_at: transaction_revision.created_at,
+        }
+    }
+}
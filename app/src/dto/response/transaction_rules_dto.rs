@@ -0,0 +1,9 @@
+mod apply_transaction_rules_response;
+mod create_transaction_rule_response;
+mod get_transaction_rule_response;
+mod update_transaction_rule_response;
+
+pub use apply_transaction_rules_response::ApplyTransactionRulesResponse;
+pub use create_transaction_rule_response::CreateTransactionRuleResponse;
+pub use get_transaction_rule_response::GetTransactionRuleResponse;
+pub use update_transaction_rule_response::UpdateTransactionRuleResponse;
@@ -0,0 +1,10 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyTransactionRulesResponse {
+    pub matched_transactions: u64,
+
+    pub updated_transactions: u64,
+}
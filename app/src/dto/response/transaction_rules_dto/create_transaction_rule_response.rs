@@ -0,0 +1,61 @@
+use crate::entities::transaction_rules;
+use sea_orm::ActiveEnum;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTransactionRuleResponse {
+    pub id: i32,
+
+    pub name: String,
+
+    pub priority: i32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description_contains: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description_regex: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_amount: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_amount: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wallet_id: Option<i32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flow_direction: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_id: Option<i32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget_id: Option<i32>,
+
+    pub tag_ids: Vec<i32>,
+}
+
+impl From<(transaction_rules::Model, Vec<i32>)> for CreateTransactionRuleResponse {
+    fn from((transaction_rule, tag_ids): (transaction_rules::Model, Vec<i32>)) -> Self {
+        CreateTransactionRuleResponse {
+            id: transaction_rule.id,
+            name: transaction_rule.name,
+            priority: transaction_rule.priority,
+            description_contains: transaction_rule.description_contains,
+            description_regex: transaction_rule.description_regex,
+            min_amount: transaction_rule.min_amount.map(|amount| amount.to_string()),
+            max_amount: transaction_rule.max_amount.map(|amount| amount.to_string()),
+            wallet_id: transaction_rule.wallet_id,
+            flow_direction: transaction_rule
+                .flow_direction
+                .map(|flow_direction| flow_direction.to_value()),
+            category_id: transaction_rule.category_id,
+            budget_id: transaction_rule.budget_id,
+            tag_ids,
+        }
+    }
+}
@@ -1,7 +1,21 @@
+mod bulk_transactions_response;
 mod create_transaction_response;
+mod create_transaction_transfer_response;
+mod export_transaction_response;
+mod get_duplicate_transaction_response;
+mod get_place_total_response;
+mod get_transaction_refunds_response;
 mod get_transaction_response;
+mod search_transaction_response;
 mod update_transaction_response;
 
+pub use bulk_transactions_response::{BulkTransactionResultResponse, BulkTransactionsResponse};
 pub use create_transaction_response::CreateTransactionResponse;
+pub use create_transaction_transfer_response::CreateTransactionTransferResponse;
+pub use export_transaction_response::{ExportTransactionResponse, ExportTransactionRow};
+pub use get_duplicate_transaction_response::GetDuplicateTransactionResponse;
+pub use get_place_total_response::{GetPlaceTotalResponse, PlaceTotalRow};
+pub use get_transaction_refunds_response::GetTransactionRefundsResponse;
 pub use get_transaction_response::GetTransactionResponse;
+pub use search_transaction_response::{SearchTransactionResponse, SearchTransactionRow};
 pub use update_transaction_response::UpdateTransactionResponse;
@@ -0,0 +1,24 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkTransactionsResponse {
+    pub committed: bool,
+
+    pub results: Vec<BulkTransactionResultResponse>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkTransactionResultResponse {
+    pub operation_index: usize,
+
+    pub action: String,
+
+    pub transaction_id: Option<i32>,
+
+    pub status: u16,
+
+    pub message: Option<String>,
+}
@@ -0,0 +1,23 @@
+use crate::dto::response::transactions_dto::GetTransactionResponse;
+use crate::entities::transactions;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTransactionTransferResponse {
+    pub from_transaction: GetTransactionResponse,
+
+    pub to_transaction: GetTransactionResponse,
+}
+
+impl From<(transactions::Model, transactions::Model)> for CreateTransactionTransferResponse {
+    fn from(
+        (from_transaction, to_transaction): (transactions::Model, transactions::Model),
+    ) -> Self {
+        CreateTransactionTransferResponse {
+            from_transaction: GetTransactionResponse::from(from_transaction),
+            to_transaction: GetTransactionResponse::from(to_transaction),
+        }
+    }
+}
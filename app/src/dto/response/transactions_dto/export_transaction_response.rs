@@ -0,0 +1,74 @@
+use crate::constants::time_constants::DATE_FORMATTER;
+use crate::entities::sea_orm_active_enums::TransactionType;
+use crate::errors::AppError;
+use rust_decimal::Decimal;
+use sea_orm::{ActiveEnum, FromQueryResult};
+use serde::Serialize;
+use time::Date;
+
+#[derive(FromQueryResult)]
+pub struct ExportTransactionRow {
+    pub id: i32,
+    pub issued_at: Date,
+    pub flow_direction: TransactionType,
+    pub amount: Decimal,
+    pub currency: String,
+    pub description: Option<String>,
+    pub category_name: String,
+    pub wallet_name: String,
+    pub budget_name: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportTransactionResponse {
+    pub id: i32,
+
+    pub issued_at: String,
+
+    pub flow_direction: String,
+
+    pub amount: String,
+
+    pub currency: String,
+
+    pub description: Option<String>,
+
+    pub category_name: String,
+
+    pub wallet_name: String,
+
+    pub budget_name: Option<String>,
+}
+
+impl ExportTransactionResponse {
+    pub const HEADERS: [&'static str; 9] = [
+        "id",
+        "issuedAt",
+        "flowDirection",
+        "amount",
+        "currency",
+        "description",
+        "categoryName",
+        "walletName",
+        "budgetName",
+    ];
+}
+
+impl TryFrom<ExportTransactionRow> for ExportTransactionResponse {
+    type Error = AppError;
+
+    fn try_from(row: ExportTransactionRow) -> Result<Self, Self::Error> {
+        Ok(ExportTransactionResponse {
+            id: row.id,
+            issued_at: row.issued_at.format(&DATE_FORMATTER)?,
+            flow_direction: row.flow_direction.to_value(),
+            amount: row.amount.to_string(),
+            currency: row.currency,
+            description: row.description,
+            category_name: row.category_name,
+            wallet_name: row.wallet_name,
+            budget_name: row.budget_name,
+        })
+    }
+}
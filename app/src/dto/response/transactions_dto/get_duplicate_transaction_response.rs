@@ -0,0 +1,26 @@
+use crate::dto::response::transactions_dto::GetTransactionResponse;
+use crate::entities::transactions;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDuplicateTransactionResponse {
+    pub original: GetTransactionResponse,
+
+    pub duplicate: GetTransactionResponse,
+
+    pub similarity: f64,
+}
+
+impl From<(transactions::Model, transactions::Model, f64)> for GetDuplicateTransactionResponse {
+    fn from(
+        (original, duplicate, similarity): (transactions::Model, transactions::Model, f64),
+    ) -> Self {
+        GetDuplicateTransactionResponse {
+            original: GetTransactionResponse::from(original),
+            duplicate: GetTransactionResponse::from(duplicate),
+            similarity,
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use rust_decimal::Decimal;
+use sea_orm::FromQueryResult;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(FromQueryResult)]
+pub struct PlaceTotalRow {
+    pub place_name: String,
+    pub currency: String,
+    pub total_income: Decimal,
+    pub total_outcome: Decimal,
+    pub transaction_count: i64,
+    pub latitude: Option<Decimal>,
+    pub longitude: Option<Decimal>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPlaceTotalResponse {
+    pub place_name: String,
+
+    pub currency: String,
+
+    pub total_income: String,
+
+    pub total_outcome: String,
+
+    pub transaction_count: i64,
+
+    pub latitude: Option<String>,
+
+    pub longitude: Option<String>,
+}
+
+impl From<PlaceTotalRow> for GetPlaceTotalResponse {
+    fn from(place_total: PlaceTotalRow) -> Self {
+        GetPlaceTotalResponse {
+            place_name: place_total.place_name,
+            currency: place_total.currency,
+            total_income: place_total.total_income.to_string(),
+            total_outcome: place_total.total_outcome.to_string(),
+            transaction_count: place_total.transaction_count,
+            latitude: place_total.latitude.map(|latitude| latitude.to_string()),
+            longitude: place_total.longitude.map(|longitude| longitude.to_string()),
+        }
+    }
+}
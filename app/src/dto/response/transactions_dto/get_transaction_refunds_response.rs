@@ -0,0 +1,35 @@
+use crate::dto::response::transactions_dto::GetTransactionResponse;
+use crate::entities::transactions;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTransactionRefundsResponse {
+    pub transaction_id: i32,
+
+    pub amount: String,
+
+    pub refunded_amount: String,
+
+    pub refundable_amount: String,
+
+    pub refunds: Vec<GetTransactionResponse>,
+}
+
+impl From<(transactions::Model, Vec<transactions::Model>)> for GetTransactionRefundsResponse {
+    fn from((transaction, refunds): (transactions::Model, Vec<transactions::Model>)) -> Self {
+        let refunded_amount: Decimal = refunds.iter().map(|refund| refund.amount).sum();
+        GetTransactionRefundsResponse {
+            transaction_id: transaction.id,
+            amount: transaction.amount.to_string(),
+            refunded_amount: refunded_amount.to_string(),
+            refundable_amount: (transaction.amount - refunded_amount).to_string(),
+            refunds: refunds
+                .into_iter()
+                .map(GetTransactionResponse::from)
+                .collect(),
+        }
+    }
+}
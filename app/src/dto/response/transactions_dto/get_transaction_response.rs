@@ -13,15 +13,49 @@ pub struct GetTransactionResponse {
 
     pub budget_id: Option<i32>,
 
+    pub payee_id: Option<i32>,
+
     pub wallet_id: i32,
 
     pub amount: String,
 
+    pub currency: String,
+
     pub description: Option<String>,
 
     pub flow_direction: String,
 
+    pub status: String,
+
     pub issued_at: Date,
+
+    pub installment_plan_id: Option<i32>,
+
+    pub installment_number: Option<i32>,
+
+    pub refunded_transaction_id: Option<i32>,
+
+    pub transfer_transaction_id: Option<i32>,
+
+    // the wallet member who booked it, differs from the owner on shared wallets
+    pub created_by_user_id: Option<i32>,
+
+    pub scheduled: bool,
+
+    pub latitude: Option<String>,
+
+    pub longitude: Option<String>,
+
+    pub place_name: Option<String>,
+
+    pub tag_ids: Vec<i32>,
+}
+
+impl GetTransactionResponse {
+    pub fn with_tag_ids(mut self, tag_ids: Vec<i32>) -> GetTransactionResponse {
+        self.tag_ids = tag_ids;
+        self
+    }
 }
 
 impl From<transactions::Model> for GetTransactionResponse {
@@ -30,11 +64,24 @@ impl From<transactions::Model> for GetTransactionResponse {
             id: transaction.id,
             category_id: transaction.category_id,
             budget_id: transaction.budget_id,
+            payee_id: transaction.payee_id,
             wallet_id: transaction.wallet_id,
             amount: transaction.amount.to_string(),
+            currency: transaction.currency,
             description: transaction.description,
             flow_direction: transaction.flow_direction.to_value(),
+            status: transaction.status.to_value(),
             issued_at: transaction.issued_at,
+            installment_plan_id: transaction.installment_plan_id,
+            installment_number: transaction.installment_number,
+            refunded_transaction_id: transaction.refunded_transaction_id,
+            transfer_transaction_id: transaction.transfer_transaction_id,
+            created_by_user_id: transaction.created_by_user_id,
+            scheduled: transaction.posted_at.is_none(),
+            latitude: transaction.latitude.map(|latitude| latitude.to_string()),
+            longitude: transaction.longitude.map(|longitude| longitude.to_string()),
+            place_name: transaction.place_name,
+            tag_ids: Vec::new(),
         }
     }
 }
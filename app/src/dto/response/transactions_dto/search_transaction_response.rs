@@ -0,0 +1,39 @@
+use crate::dto::response::transactions_dto::GetTransactionResponse;
+use crate::entities::transactions;
+use sea_orm::FromQueryResult;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(FromQueryResult)]
+pub struct SearchTransactionRow {
+    pub id: i32,
+    pub rank: f32,
+    pub highlight: String,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchTransactionResponse {
+    pub transaction: GetTransactionResponse,
+
+    pub rank: f32,
+
+    pub highlight: String,
+}
+
+impl From<(transactions::Model, f32, String)> for SearchTransactionResponse {
+    fn from((transaction, rank, highlight): (transactions::Model, f32, String)) -> Self {
+        SearchTransactionResponse {
+            transaction: GetTransactionResponse::from(transaction),
+            rank,
+            highlight,
+        }
+    }
+}
+
+impl SearchTransactionResponse {
+    pub fn with_tag_ids(mut self, tag_ids: Vec<i32>) -> SearchTransactionResponse {
+        self.transaction = self.transaction.with_tag_ids(tag_ids);
+        self
+    }
+}
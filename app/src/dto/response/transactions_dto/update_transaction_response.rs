@@ -14,15 +14,22 @@ pub struct UpdateTransactionResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub budget_id: Option<i32>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payee_id: Option<i32>,
+
     pub wallet_id: i32,
 
     pub amount: String,
 
+    pub currency: String,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
     pub flow_direction: String,
 
+    pub status: String,
+
     pub issued_at: Date,
 }
 
@@ -32,10 +39,13 @@ impl From<transactions::Model> for UpdateTransactionResponse {
             id: transaction.id,
             category_id: transaction.category_id,
             budget_id: transaction.budget_id,
+            payee_id: transaction.payee_id,
             wallet_id: transaction.wallet_id,
             amount: transaction.amount.to_string(),
+            currency: transaction.currency,
             description: transaction.description,
             flow_direction: transaction.flow_direction.to_value(),
+            status: transaction.status.to_value(),
             issued_at: transaction.issued_at,
         }
     }
@@ -9,5 +9,7 @@ pub struct GetSelfResponse {
 
     pub email: String,
 
+    pub reporting_currency: String,
+
     pub created_at: OffsetDateTime,
 }
@@ -7,4 +7,6 @@ pub struct UpdateSelfResponse {
     pub username: String,
 
     pub email: String,
+
+    pub reporting_currency: String,
 }
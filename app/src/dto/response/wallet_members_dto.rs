@@ -0,0 +1,5 @@
+mod get_wallet_invitation_response;
+mod get_wallet_member_response;
+
+pub use get_wallet_invitation_response::GetWalletInvitationResponse;
+pub use get_wallet_member_response::GetWalletMemberResponse;
@@ -0,0 +1,37 @@
+use crate::entities::{wallet_members, wallets};
+use sea_orm::ActiveEnum;
+use serde::Serialize;
+use time::OffsetDateTime;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetWalletInvitationResponse {
+    pub id: i32,
+
+    pub wallet_id: i32,
+
+    pub wallet_name: String,
+
+    pub currency: String,
+
+    pub role: String,
+
+    pub invited_by_user_id: Option<i32>,
+
+    pub created_at: OffsetDateTime,
+}
+
+impl From<(wallet_members::Model, wallets::Model)> for GetWalletInvitationResponse {
+    fn from((wallet_member, wallet): (wallet_members::Model, wallets::Model)) -> Self {
+        GetWalletInvitationResponse {
+            id: wallet_member.id,
+            wallet_id: wallet.id,
+            wallet_name: wallet.name,
+            currency: wallet.currency,
+            role: wallet_member.role.into_value(),
+            invited_by_user_id: wallet_member.invited_by_user_id,
+            created_at: wallet_member.created_at,
+        }
+    }
+}
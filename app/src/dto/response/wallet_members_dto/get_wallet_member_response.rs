@@ -0,0 +1,41 @@
+use crate::entities::{users, wallet_members};
+use sea_orm::ActiveEnum;
+use serde::Serialize;
+use time::OffsetDateTime;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetWalletMemberResponse {
+    pub id: i32,
+
+    pub wallet_id: i32,
+
+    pub user_id: i32,
+
+    pub username: String,
+
+    pub role: String,
+
+    pub invited_by_user_id: Option<i32>,
+
+    // missing while the invitation is still pending
+    pub accepted_at: Option<OffsetDateTime>,
+
+    pub created_at: OffsetDateTime,
+}
+
+impl From<(wallet_members::Model, users::Model)> for GetWalletMemberResponse {
+    fn from((wallet_member, user): (wallet_members::Model, users::Model)) -> Self {
+        GetWalletMemberResponse {
+            id: wallet_member.id,
+            wallet_id: wallet_member.wallet_id,
+            user_id: wallet_member.user_id,
+            username: user.username,
+            role: wallet_member.role.into_value(),
+            invited_by_user_id: wallet_member.invited_by_user_id,
+            accepted_at: wallet_member.accepted_at,
+            created_at: wallet_member.created_at,
+        }
+    }
+}
@@ -1,7 +1,11 @@
 mod create_wallet_response;
+mod get_wallet_balance_history_response;
+mod get_wallet_balance_response;
 mod get_wallet_response;
 mod update_wallet_response;
 
 pub use create_wallet_response::CreateWalletResponse;
+pub use get_wallet_balance_history_response::GetWalletBalanceHistoryResponse;
+pub use get_wallet_balance_response::GetWalletBalanceResponse;
 pub use get_wallet_response::GetWalletResponse;
 pub use update_wallet_response::UpdateWalletResponse;
@@ -1,4 +1,7 @@
+use crate::entities::wallets;
+use sea_orm::ActiveEnum;
 use serde::Serialize;
+use time::Date;
 use utoipa::ToSchema;
 
 #[derive(Serialize, ToSchema)]
@@ -8,6 +11,51 @@ pub struct CreateWalletResponse {
 
     pub name: String,
 
+    pub wallet_type: String,
+
+    pub currency: String,
+
+    pub opening_balance: String,
+
+    pub opening_date: Option<Date>,
+
+    pub exclude_from_totals: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credit_limit: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_closing_day: Option<i16>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_due_day: Option<i16>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interest_rate: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 }
+
+impl From<wallets::Model> for CreateWalletResponse {
+    fn from(wallet: wallets::Model) -> Self {
+        CreateWalletResponse {
+            id: wallet.id,
+            name: wallet.name,
+            wallet_type: wallet.wallet_type.into_value(),
+            currency: wallet.currency,
+            exclude_from_totals: wallet.exclude_from_totals,
+            opening_balance: wallet.opening_balance.to_string(),
+            opening_date: wallet.opening_date,
+            credit_limit: wallet
+                .credit_limit
+                .map(|credit_limit| credit_limit.to_string()),
+            statement_closing_day: wallet.statement_closing_day,
+            payment_due_day: wallet.payment_due_day,
+            interest_rate: wallet
+                .interest_rate
+                .map(|interest_rate| interest_rate.normalize().to_string()),
+            description: wallet.description,
+        }
+    }
+}
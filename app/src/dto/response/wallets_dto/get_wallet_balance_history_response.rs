@@ -0,0 +1,56 @@
+use crate::entities::wallets;
+use crate::enums::balance_history_granularity::BalanceHistoryGranularity;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use time::Date;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetWalletBalanceHistoryResponse {
+    pub wallet_id: i32,
+
+    pub currency: String,
+
+    pub granularity: String,
+
+    pub points: Vec<WalletBalancePointResponse>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletBalancePointResponse {
+    // the last day of the period, or the end of the range when it cuts the period short
+    pub date: Date,
+
+    pub balance: String,
+}
+
+impl
+    From<(
+        wallets::Model,
+        BalanceHistoryGranularity,
+        Vec<(Date, Decimal)>,
+    )> for GetWalletBalanceHistoryResponse
+{
+    fn from(
+        (wallet, granularity, points): (
+            wallets::Model,
+            BalanceHistoryGranularity,
+            Vec<(Date, Decimal)>,
+        ),
+    ) -> Self {
+        GetWalletBalanceHistoryResponse {
+            wallet_id: wallet.id,
+            currency: wallet.currency,
+            granularity: String::from(granularity.get_value()),
+            points: points
+                .into_iter()
+                .map(|(date, balance)| WalletBalancePointResponse {
+                    date,
+                    balance: balance.to_string(),
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::entities::wallets;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use time::Date;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetWalletBalanceResponse {
+    pub wallet_id: i32,
+
+    pub currency: String,
+
+    pub as_of: Date,
+
+    pub opening_balance: String,
+
+    pub opening_date: Option<Date>,
+
+    pub balance: String,
+}
+
+impl From<(wallets::Model, Date, Decimal)> for GetWalletBalanceResponse {
+    fn from((wallet, as_of, balance): (wallets::Model, Date, Decimal)) -> Self {
+        GetWalletBalanceResponse {
+            wallet_id: wallet.id,
+            currency: wallet.currency,
+            as_of,
+            opening_balance: wallet.opening_balance.to_string(),
+            opening_date: wallet.opening_date,
+            balance: balance.to_string(),
+        }
+    }
+}
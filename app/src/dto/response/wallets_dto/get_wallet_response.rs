@@ -1,4 +1,10 @@
+use crate::entities::sea_orm_active_enums::WalletType;
+use crate::entities::wallets;
+use crate::utils::wallet_utils;
+use rust_decimal::Decimal;
+use sea_orm::ActiveEnum;
 use serde::Serialize;
+use time::{Date, OffsetDateTime};
 use utoipa::ToSchema;
 
 #[derive(Serialize, ToSchema)]
@@ -8,8 +14,79 @@ pub struct GetWalletResponse {
 
     pub name: String,
 
+    pub wallet_type: String,
+
+    // negative when money is owed, like a used credit card or an open loan
     pub balance: String,
 
+    pub currency: String,
+
+    pub opening_balance: String,
+
+    pub opening_date: Option<Date>,
+
+    pub projected_balance: String,
+
+    pub is_liability: bool,
+
+    pub exclude_from_totals: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<OffsetDateTime>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub debt: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credit_limit: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub available_credit: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_closing_day: Option<i16>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_due_day: Option<i16>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interest_rate: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 }
+
+impl From<(wallets::Model, Decimal)> for GetWalletResponse {
+    fn from((wallet, projected_balance): (wallets::Model, Decimal)) -> Self {
+        let is_liability = wallet_utils::is_liability(&wallet.wallet_type);
+        GetWalletResponse {
+            id: wallet.id,
+            name: wallet.name,
+            balance: wallet.balance.to_string(),
+            currency: wallet.currency,
+            opening_balance: wallet.opening_balance.to_string(),
+            opening_date: wallet.opening_date,
+            projected_balance: projected_balance.to_string(),
+            is_liability,
+            exclude_from_totals: wallet.exclude_from_totals,
+            archived_at: wallet.archived_at,
+            debt: is_liability.then(|| (-wallet.balance).max(Decimal::ZERO).to_string()),
+            credit_limit: wallet
+                .credit_limit
+                .map(|credit_limit| credit_limit.to_string()),
+            available_credit: match wallet.wallet_type {
+                WalletType::CreditCard => wallet
+                    .credit_limit
+                    .map(|credit_limit| (credit_limit + wallet.balance).to_string()),
+                _ => None,
+            },
+            wallet_type: wallet.wallet_type.into_value(),
+            statement_closing_day: wallet.statement_closing_day,
+            payment_due_day: wallet.payment_due_day,
+            interest_rate: wallet
+                .interest_rate
+                .map(|interest_rate| interest_rate.normalize().to_string()),
+            description: wallet.description,
+        }
+    }
+}
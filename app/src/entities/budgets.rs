@@ -31,6 +31,10 @@ pub enum Relation {
         on_delete = "SetNull"
     )]
     BudgetConfigs,
+    #[sea_orm(has_many = "super::installment_plans::Entity")]
+    InstallmentPlans,
+    #[sea_orm(has_many = "super::transaction_rules::Entity")]
+    TransactionRules,
     #[sea_orm(has_many = "super::transactions::Entity")]
     Transactions,
     #[sea_orm(
@@ -49,6 +53,18 @@ impl Related<super::budget_configs::Entity> for Entity {
     }
 }
 
+impl Related<super::installment_plans::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::InstallmentPlans.def()
+    }
+}
+
+impl Related<super::transaction_rules::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TransactionRules.def()
+    }
+}
+
 impl Related<super::transactions::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Transactions.def()
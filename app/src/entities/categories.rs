@@ -16,6 +16,10 @@ pub struct Model {
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::installment_plans::Entity")]
+    InstallmentPlans,
+    #[sea_orm(has_many = "super::transaction_rules::Entity")]
+    TransactionRules,
     #[sea_orm(has_many = "super::transactions::Entity")]
     Transactions,
     #[sea_orm(
@@ -28,6 +32,18 @@ pub enum Relation {
     Users,
 }
 
+impl Related<super::installment_plans::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::InstallmentPlans.def()
+    }
+}
+
+impl Related<super::transaction_rules::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TransactionRules.def()
+    }
+}
+
 impl Related<super::transactions::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Transactions.def()
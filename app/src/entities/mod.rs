@@ -5,10 +5,23 @@ pub mod prelude;
 pub mod budget_configs;
 pub mod budgets;
 pub mod categories;
+pub mod exchange_rates;
+pub mod installment_plans;
+pub mod payee_aliases;
+pub mod payees;
+pub mod reconciliations;
 pub mod refresh_tokens;
 pub mod roles;
 pub mod sea_orm_active_enums;
+pub mod tags;
+pub mod transaction_attachments;
+pub mod transaction_revisions;
+pub mod transaction_rule_tags;
+pub mod transaction_rules;
+pub mod transaction_tags;
 pub mod transactions;
 pub mod user_roles;
 pub mod users;
+pub mod wallet_balance_snapshots;
+pub mod wallet_members;
 pub mod wallets;
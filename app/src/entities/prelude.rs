@@ -5,9 +5,22 @@
 pub use super::budget_configs::Entity as BudgetConfigs;
 pub use super::budgets::Entity as Budgets;
 pub use super::categories::Entity as Categories;
+pub use super::exchange_rates::Entity as ExchangeRates;
+pub use super::installment_plans::Entity as InstallmentPlans;
+pub use super::payee_aliases::Entity as PayeeAliases;
+pub use super::payees::Entity as Payees;
+pub use super::reconciliations::Entity as Reconciliations;
 pub use super::refresh_tokens::Entity as RefreshTokens;
 pub use super::roles::Entity as Roles;
+pub use super::tags::Entity as Tags;
+pub use super::transaction_attachments::Entity as TransactionAttachments;
+pub use super::transaction_revisions::Entity as TransactionRevisions;
+pub use super::transaction_rule_tags::Entity as TransactionRuleTags;
+pub use super::transaction_rules::Entity as TransactionRules;
+pub use super::transaction_tags::Entity as TransactionTags;
 pub use super::transactions::Entity as Transactions;
 pub use super::user_roles::Entity as UserRoles;
 pub use super::users::Entity as Users;
+pub use super::wallet_balance_snapshots::Entity as WalletBalanceSnapshots;
+pub use super::wallet_members::Entity as WalletMembers;
 pub use super::wallets::Entity as Wallets;
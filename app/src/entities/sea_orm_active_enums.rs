@@ -26,3 +26,67 @@ pub enum TransactionType {
     #[sea_orm(string_value = "OUTCOME")]
     Outcome,
 }
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "transaction_revision_action"
+)]
+pub enum TransactionRevisionAction {
+    #[sea_orm(string_value = "CREATED")]
+    Created,
+    #[sea_orm(string_value = "UPDATED")]
+    Updated,
+    #[sea_orm(string_value = "DELETED")]
+    Deleted,
+    #[sea_orm(string_value = "RESTORED")]
+    Restored,
+}
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "transaction_revision_source"
+)]
+pub enum TransactionRevisionSource {
+    #[sea_orm(string_value = "SYSTEM")]
+    System,
+    #[sea_orm(string_value = "USER")]
+    User,
+}
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "transaction_status")]
+pub enum TransactionStatus {
+    #[sea_orm(string_value = "PENDING")]
+    Pending,
+    #[sea_orm(string_value = "CLEARED")]
+    Cleared,
+    #[sea_orm(string_value = "RECONCILED")]
+    Reconciled,
+}
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "wallet_member_role")]
+pub enum WalletMemberRole {
+    #[sea_orm(string_value = "OWNER")]
+    Owner,
+    #[sea_orm(string_value = "EDITOR")]
+    Editor,
+    #[sea_orm(string_value = "VIEWER")]
+    Viewer,
+}
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "wallet_type")]
+pub enum WalletType {
+    #[sea_orm(string_value = "CASH")]
+    Cash,
+    #[sea_orm(string_value = "BANK")]
+    Bank,
+    #[sea_orm(string_value = "CREDIT_CARD")]
+    CreditCard,
+    #[sea_orm(string_value = "SAVINGS")]
+    Savings,
+    #[sea_orm(string_value = "LOAN")]
+    Loan,
+    #[sea_orm(string_value = "INVESTMENT")]
+    Investment,
+}
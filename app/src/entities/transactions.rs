@@ -1,6 +1,6 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.12
 
-use super::sea_orm_active_enums::TransactionType;
+use super::sea_orm_active_enums::{TransactionStatus, TransactionType};
 use sea_orm::entity::prelude::*;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
@@ -13,12 +13,27 @@ pub struct Model {
     pub budget_id: Option<i32>,
     pub wallet_id: i32,
     pub amount: Decimal,
+    pub currency: String,
     pub description: Option<String>,
     pub flow_direction: TransactionType,
     pub issued_at: TimeDate,
+    pub payee_id: Option<i32>,
+    pub status: TransactionStatus,
+    pub reconciliation_id: Option<i32>,
+    pub installment_plan_id: Option<i32>,
+    pub installment_number: Option<i32>,
+    pub refunded_transaction_id: Option<i32>,
+    pub transfer_transaction_id: Option<i32>,
+    pub posted_at: Option<TimeDateTimeWithTimeZone>,
+    #[sea_orm(column_type = "Decimal(Some((9, 6)))", nullable)]
+    pub latitude: Option<Decimal>,
+    #[sea_orm(column_type = "Decimal(Some((9, 6)))", nullable)]
+    pub longitude: Option<Decimal>,
+    pub place_name: Option<String>,
     pub created_at: TimeDateTimeWithTimeZone,
     pub updated_at: TimeDateTimeWithTimeZone,
     pub deleted_at: Option<TimeDateTimeWithTimeZone>,
+    pub created_by_user_id: Option<i32>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -39,6 +54,60 @@ pub enum Relation {
         on_delete = "SetNull"
     )]
     Categories,
+    #[sea_orm(
+        belongs_to = "super::installment_plans::Entity",
+        from = "Column::InstallmentPlanId",
+        to = "super::installment_plans::Column::Id",
+        on_update = "Cascade",
+        on_delete = "SetNull"
+    )]
+    InstallmentPlans,
+    #[sea_orm(
+        belongs_to = "super::payees::Entity",
+        from = "Column::PayeeId",
+        to = "super::payees::Column::Id",
+        on_update = "Cascade",
+        on_delete = "SetNull"
+    )]
+    Payees,
+    #[sea_orm(
+        belongs_to = "super::reconciliations::Entity",
+        from = "Column::ReconciliationId",
+        to = "super::reconciliations::Column::Id",
+        on_update = "Cascade",
+        on_delete = "SetNull"
+    )]
+    Reconciliations,
+    #[sea_orm(
+        belongs_to = "Entity",
+        from = "Column::RefundedTransactionId",
+        to = "Column::Id",
+        on_update = "Cascade",
+        on_delete = "SetNull"
+    )]
+    SelfRef1,
+    #[sea_orm(
+        belongs_to = "Entity",
+        from = "Column::TransferTransactionId",
+        to = "Column::Id",
+        on_update = "Cascade",
+        on_delete = "SetNull"
+    )]
+    SelfRef2,
+    #[sea_orm(has_many = "super::transaction_attachments::Entity")]
+    TransactionAttachments,
+    #[sea_orm(has_many = "super::transaction_revisions::Entity")]
+    TransactionRevisions,
+    #[sea_orm(has_many = "super::transaction_tags::Entity")]
+    TransactionTags,
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::CreatedByUserId",
+        to = "super::users::Column::Id",
+        on_update = "Cascade",
+        on_delete = "SetNull"
+    )]
+    Users2,
     #[sea_orm(
         belongs_to = "super::users::Entity",
         from = "Column::UserId",
@@ -46,7 +115,7 @@ pub enum Relation {
         on_update = "Cascade",
         on_delete = "Cascade"
     )]
-    Users,
+    Users1,
     #[sea_orm(
         belongs_to = "super::wallets::Entity",
         from = "Column::WalletId",
@@ -69,9 +138,39 @@ impl Related<super::categories::Entity> for Entity {
     }
 }
 
-impl Related<super::users::Entity> for Entity {
+impl Related<super::installment_plans::Entity> for Entity {
     fn to() -> RelationDef {
-        Relation::Users.def()
+        Relation::InstallmentPlans.def()
+    }
+}
+
+impl Related<super::payees::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Payees.def()
+    }
+}
+
+impl Related<super::reconciliations::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Reconciliations.def()
+    }
+}
+
+impl Related<super::transaction_attachments::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TransactionAttachments.def()
+    }
+}
+
+impl Related<super::transaction_revisions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TransactionRevisions.def()
+    }
+}
+
+impl Related<super::transaction_tags::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TransactionTags.def()
     }
 }
 
@@ -81,4 +180,13 @@ impl Related<super::wallets::Entity> for Entity {
     }
 }
 
+impl Related<super::tags::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::transaction_tags::Relation::Tags.def()
+    }
+    fn via() -> Option<RelationDef> {
+        Some(super::transaction_tags::Relation::Transactions.def().rev())
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
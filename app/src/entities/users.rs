@@ -10,6 +10,7 @@ pub struct Model {
     pub username: String,
     pub email: String,
     pub password: String,
+    pub reporting_currency: String,
     pub created_at: TimeDateTimeWithTimeZone,
     pub updated_at: TimeDateTimeWithTimeZone,
     pub deleted_at: Option<TimeDateTimeWithTimeZone>,
@@ -23,10 +24,22 @@ pub enum Relation {
     Budgets,
     #[sea_orm(has_many = "super::categories::Entity")]
     Categories,
+    #[sea_orm(has_many = "super::exchange_rates::Entity")]
+    ExchangeRates,
+    #[sea_orm(has_many = "super::installment_plans::Entity")]
+    InstallmentPlans,
+    #[sea_orm(has_many = "super::payees::Entity")]
+    Payees,
+    #[sea_orm(has_many = "super::reconciliations::Entity")]
+    Reconciliations,
     #[sea_orm(has_many = "super::refresh_tokens::Entity")]
     RefreshTokens,
-    #[sea_orm(has_many = "super::transactions::Entity")]
-    Transactions,
+    #[sea_orm(has_many = "super::tags::Entity")]
+    Tags,
+    #[sea_orm(has_many = "super::transaction_attachments::Entity")]
+    TransactionAttachments,
+    #[sea_orm(has_many = "super::transaction_rules::Entity")]
+    TransactionRules,
     #[sea_orm(has_many = "super::user_roles::Entity")]
     UserRoles,
     #[sea_orm(has_many = "super::wallets::Entity")]
@@ -51,15 +64,51 @@ impl Related<super::categories::Entity> for Entity {
     }
 }
 
+impl Related<super::exchange_rates::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ExchangeRates.def()
+    }
+}
+
+impl Related<super::installment_plans::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::InstallmentPlans.def()
+    }
+}
+
+impl Related<super::payees::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Payees.def()
+    }
+}
+
+impl Related<super::reconciliations::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Reconciliations.def()
+    }
+}
+
 impl Related<super::refresh_tokens::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::RefreshTokens.def()
     }
 }
 
-impl Related<super::transactions::Entity> for Entity {
+impl Related<super::tags::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Tags.def()
+    }
+}
+
+impl Related<super::transaction_attachments::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TransactionAttachments.def()
+    }
+}
+
+impl Related<super::transaction_rules::Entity> for Entity {
     fn to() -> RelationDef {
-        Relation::Transactions.def()
+        Relation::TransactionRules.def()
     }
 }
 
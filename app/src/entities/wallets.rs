@@ -1,5 +1,6 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.12
 
+use super::sea_orm_active_enums::WalletType;
 use sea_orm::entity::prelude::*;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
@@ -10,15 +11,32 @@ pub struct Model {
     pub user_id: i32,
     pub name: String,
     pub balance: Decimal,
+    pub currency: String,
     #[sea_orm(column_type = "Text", nullable)]
     pub description: Option<String>,
     pub created_at: TimeDateTimeWithTimeZone,
     pub updated_at: TimeDateTimeWithTimeZone,
     pub deleted_at: Option<TimeDateTimeWithTimeZone>,
+    pub wallet_type: WalletType,
+    pub credit_limit: Option<Decimal>,
+    pub statement_closing_day: Option<i16>,
+    pub payment_due_day: Option<i16>,
+    #[sea_orm(column_type = "Decimal(Some((7, 4)))", nullable)]
+    pub interest_rate: Option<Decimal>,
+    pub opening_balance: Decimal,
+    pub opening_date: Option<TimeDate>,
+    pub archived_at: Option<TimeDateTimeWithTimeZone>,
+    pub exclude_from_totals: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::installment_plans::Entity")]
+    InstallmentPlans,
+    #[sea_orm(has_many = "super::reconciliations::Entity")]
+    Reconciliations,
+    #[sea_orm(has_many = "super::transaction_rules::Entity")]
+    TransactionRules,
     #[sea_orm(has_many = "super::transactions::Entity")]
     Transactions,
     #[sea_orm(
@@ -29,6 +47,28 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Users,
+    #[sea_orm(has_many = "super::wallet_balance_snapshots::Entity")]
+    WalletBalanceSnapshots,
+    #[sea_orm(has_many = "super::wallet_members::Entity")]
+    WalletMembers,
+}
+
+impl Related<super::installment_plans::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::InstallmentPlans.def()
+    }
+}
+
+impl Related<super::reconciliations::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Reconciliations.def()
+    }
+}
+
+impl Related<super::transaction_rules::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TransactionRules.def()
+    }
 }
 
 impl Related<super::transactions::Entity> for Entity {
@@ -43,4 +83,16 @@ impl Related<super::users::Entity> for Entity {
     }
 }
 
+impl Related<super::wallet_balance_snapshots::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::WalletBalanceSnapshots.def()
+    }
+}
+
+impl Related<super::wallet_members::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::WalletMembers.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -1 +1,7 @@
+pub mod attachment_content_type;
+pub mod balance_history_granularity;
+pub mod bulk_transaction_action;
+pub mod credit_card_statement_status;
+pub mod exchange_rate_file_format;
+pub mod export_format;
 pub mod roles;
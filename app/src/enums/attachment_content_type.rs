@@ -0,0 +1,55 @@
+use crate::errors::AppError;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum AttachmentContentType {
+    Jpeg,
+    Png,
+    Webp,
+    Pdf,
+}
+
+impl AttachmentContentType {
+    pub fn from_bytes(content: &[u8]) -> Result<AttachmentContentType, AppError> {
+        match content {
+            [0xFF, 0xD8, 0xFF, ..] => Ok(AttachmentContentType::Jpeg),
+            [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, ..] => Ok(AttachmentContentType::Png),
+            [
+                b'R',
+                b'I',
+                b'F',
+                b'F',
+                _,
+                _,
+                _,
+                _,
+                b'W',
+                b'E',
+                b'B',
+                b'P',
+                ..,
+            ] => Ok(AttachmentContentType::Webp),
+            [b'%', b'P', b'D', b'F', b'-', ..] => Ok(AttachmentContentType::Pdf),
+            _ => Err(AppError::UnsupportedMediaType(String::from(
+                "attachment must be a jpeg, png, webp or pdf file",
+            ))),
+        }
+    }
+
+    pub fn get_content_type(&self) -> &'static str {
+        match self {
+            AttachmentContentType::Jpeg => "image/jpeg",
+            AttachmentContentType::Png => "image/png",
+            AttachmentContentType::Webp => "image/webp",
+            AttachmentContentType::Pdf => "application/pdf",
+        }
+    }
+
+    pub fn get_extension(&self) -> &'static str {
+        match self {
+            AttachmentContentType::Jpeg => "jpg",
+            AttachmentContentType::Png => "png",
+            AttachmentContentType::Webp => "webp",
+            AttachmentContentType::Pdf => "pdf",
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::errors::AppError;
+use crate::utils::date_utils::add_months;
+use time::{Date, Duration};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum BalanceHistoryGranularity {
+    Day,
+    Week,
+    Month,
+}
+
+impl BalanceHistoryGranularity {
+    pub fn from_string(granularity: &str) -> Result<BalanceHistoryGranularity, AppError> {
+        match granularity.to_lowercase().as_str() {
+            "day" => Ok(BalanceHistoryGranularity::Day),
+            "week" => Ok(BalanceHistoryGranularity::Week),
+            "month" => Ok(BalanceHistoryGranularity::Month),
+            _ => Err(AppError::ParseQuery(String::from(
+                "granularity must be one of day, week or month",
+            ))),
+        }
+    }
+
+    pub fn get_value(&self) -> &'static str {
+        match self {
+            BalanceHistoryGranularity::Day => "day",
+            BalanceHistoryGranularity::Week => "week",
+            BalanceHistoryGranularity::Month => "month",
+        }
+    }
+
+    // weeks run from monday to sunday
+    pub fn find_period_end(&self, date: Date) -> Result<Date, AppError> {
+        match self {
+            BalanceHistoryGranularity::Day => Ok(date),
+            BalanceHistoryGranularity::Week => {
+                Ok(date + Duration::days(6 - i64::from(date.weekday().number_days_from_monday())))
+            }
+            BalanceHistoryGranularity::Month => {
+                Ok(date.replace_day(date.month().length(date.year()))?)
+            }
+        }
+    }
+
+    pub fn find_default_start_date(&self, end_date: Date) -> Result<Date, AppError> {
+        match self {
+            BalanceHistoryGranularity::Day => Ok(end_date - Duration::days(29)),
+            BalanceHistoryGranularity::Week => Ok(end_date - Duration::weeks(11)),
+            BalanceHistoryGranularity::Month => add_months(end_date, -11),
+        }
+    }
+}
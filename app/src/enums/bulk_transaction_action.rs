@@ -0,0 +1,21 @@
+use crate::errors::AppError;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum BulkTransactionAction {
+    Create,
+    Update,
+    Delete,
+}
+
+impl BulkTransactionAction {
+    pub fn from_string(action: &str) -> Result<BulkTransactionAction, AppError> {
+        match action.to_lowercase().as_str() {
+            "create" => Ok(BulkTransactionAction::Create),
+            "update" => Ok(BulkTransactionAction::Update),
+            "delete" => Ok(BulkTransactionAction::Delete),
+            _ => Err(AppError::ParseBody(String::from(
+                "action must be one of create, update or delete",
+            ))),
+        }
+    }
+}
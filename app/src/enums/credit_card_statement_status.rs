@@ -0,0 +1,18 @@
+#[derive(Clone, Copy, PartialEq)]
+pub enum CreditCardStatementStatus {
+    Open,
+    Due,
+    Paid,
+    Overdue,
+}
+
+impl CreditCardStatementStatus {
+    pub fn get_value(&self) -> &'static str {
+        match self {
+            CreditCardStatementStatus::Open => "OPEN",
+            CreditCardStatementStatus::Due => "DUE",
+            CreditCardStatementStatus::Paid => "PAID",
+            CreditCardStatementStatus::Overdue => "OVERDUE",
+        }
+    }
+}
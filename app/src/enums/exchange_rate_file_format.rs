@@ -0,0 +1,35 @@
+use crate::errors::AppError;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ExchangeRateFileFormat {
+    Xml,
+    Csv,
+}
+
+impl ExchangeRateFileFormat {
+    pub fn from_string(format: &str) -> Result<ExchangeRateFileFormat, AppError> {
+        match format.to_lowercase().as_str() {
+            "xml" => Ok(ExchangeRateFileFormat::Xml),
+            "csv" => Ok(ExchangeRateFileFormat::Csv),
+            _ => Err(AppError::ParseQuery(String::from(
+                "format must be one of xml or csv",
+            ))),
+        }
+    }
+
+    pub fn from_content_type(content_type: &str) -> Result<ExchangeRateFileFormat, AppError> {
+        let mime_type = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_lowercase();
+        match mime_type.as_str() {
+            "application/xml" | "text/xml" => Ok(ExchangeRateFileFormat::Xml),
+            "text/csv" | "application/csv" => Ok(ExchangeRateFileFormat::Csv),
+            _ => Err(AppError::UnsupportedMediaType(String::from(
+                "Rate files must be sent as xml or csv, or with the format query parameter",
+            ))),
+        }
+    }
+}
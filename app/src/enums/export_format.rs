@@ -0,0 +1,39 @@
+use crate::errors::AppError;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    Csv,
+    Jsonl,
+    Xlsx,
+}
+
+impl ExportFormat {
+    pub fn from_string(format: &str) -> Result<ExportFormat, AppError> {
+        match format.to_lowercase().as_str() {
+            "csv" => Ok(ExportFormat::Csv),
+            "jsonl" => Ok(ExportFormat::Jsonl),
+            "xlsx" => Ok(ExportFormat::Xlsx),
+            _ => Err(AppError::ParseQuery(String::from(
+                "format must be one of csv, jsonl or xlsx",
+            ))),
+        }
+    }
+
+    pub fn get_content_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv; charset=utf-8",
+            ExportFormat::Jsonl => "application/jsonl; charset=utf-8",
+            ExportFormat::Xlsx => {
+                "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+            }
+        }
+    }
+
+    pub fn get_extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Jsonl => "jsonl",
+            ExportFormat::Xlsx => "xlsx",
+        }
+    }
+}
@@ -1,13 +1,20 @@
 use crate::dto::response::global::error_response::{ErrorCode, ErrorResponse};
 use argon2::password_hash::Error as ArgonError;
+use axum::extract::multipart::MultipartError;
 use axum::extract::rejection::JsonRejection as JsonRejectionError;
 use axum::http::StatusCode;
 use axum::http::header::ToStrError;
 use axum::response::{IntoResponse, Response};
+use csv::Error as CsvError;
 use jsonwebtoken::errors::{Error as JwtError, ErrorKind};
+use regex::Error as RegexError;
 use rust_decimal::Error as ParseDecimalError;
+use rust_xlsxwriter::XlsxError;
+use s3::error::S3Error;
 use sea_orm::DbErr;
+use serde_json::Error as SerializeJsonError;
 use std::env::VarError;
+use std::io::Error as IoError;
 use std::num::ParseIntError;
 use time::error::{
     ComponentRange as TimeError, Format as TimeFormatError,
@@ -30,27 +37,38 @@ pub enum AppError {
     Database(DbErr),
     ParseJson(JsonRejectionError),
     Format(TimeFormatError),
+    Io(IoError),
+    Csv(CsvError),
+    Xlsx(XlsxError),
+    SerializeJson(SerializeJsonError),
+    Regex(RegexError),
+    Multipart(MultipartError),
+    S3(S3Error),
 
     NotFound(String),
     ParseQuery(String),
     Unauthenticated(String),
     Forbidden(String),
     ParseBody(String),
+    PayloadTooLarge(String),
+    UnsupportedMediaType(String),
+    Storage(String),
+    Conflict(String),
 
     ParseRole,
 }
 
-impl IntoResponse for AppError {
-    fn into_response(self) -> Response {
-        let (status, response) = match self {
-            AppError::Argon(err) => (
+impl AppError {
+    pub fn to_status_and_response(&self) -> (StatusCode, ErrorResponse) {
+        match *self {
+            AppError::Argon(ref err) => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 ErrorResponse {
                     code: ErrorCode::Hash,
                     message: err.to_string(),
                 },
             ),
-            AppError::Time(err) => (
+            AppError::Time(ref err) => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 ErrorResponse {
                     code: ErrorCode::Hash,
@@ -86,8 +104,7 @@ impl IntoResponse for AppError {
                             code: ErrorCode::Expired,
                             message: err.to_string(),
                         },
-                    )
-                        .into_response();
+                    );
                 }
 
                 (
@@ -182,7 +199,90 @@ impl IntoResponse for AppError {
                     message: String::from(err),
                 },
             ),
-        };
+            AppError::Io(ref err) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ErrorResponse {
+                    code: ErrorCode::Io,
+                    message: err.to_string(),
+                },
+            ),
+            AppError::Csv(ref err) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ErrorResponse {
+                    code: ErrorCode::Export,
+                    message: err.to_string(),
+                },
+            ),
+            AppError::Xlsx(ref err) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ErrorResponse {
+                    code: ErrorCode::Export,
+                    message: err.to_string(),
+                },
+            ),
+            AppError::SerializeJson(ref err) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ErrorResponse {
+                    code: ErrorCode::Export,
+                    message: err.to_string(),
+                },
+            ),
+            AppError::Regex(ref err) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse {
+                    code: ErrorCode::Validation,
+                    message: err.to_string(),
+                },
+            ),
+            AppError::Multipart(ref err) => (
+                err.status(),
+                ErrorResponse {
+                    code: ErrorCode::Parse,
+                    message: err.body_text(),
+                },
+            ),
+            AppError::S3(ref err) => (
+                StatusCode::BAD_GATEWAY,
+                ErrorResponse {
+                    code: ErrorCode::Storage,
+                    message: err.to_string(),
+                },
+            ),
+            AppError::PayloadTooLarge(ref err) => (
+                StatusCode::PAYLOAD_TOO_LARGE,
+                ErrorResponse {
+                    code: ErrorCode::Validation,
+                    message: String::from(err),
+                },
+            ),
+            AppError::UnsupportedMediaType(ref err) => (
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                ErrorResponse {
+                    code: ErrorCode::Validation,
+                    message: String::from(err),
+                },
+            ),
+            AppError::Storage(ref err) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ErrorResponse {
+                    code: ErrorCode::Storage,
+                    message: String::from(err),
+                },
+            ),
+            AppError::Conflict(ref err) => (
+                StatusCode::CONFLICT,
+                ErrorResponse {
+                    code: ErrorCode::Conflict,
+                    message: String::from(err),
+                },
+            ),
+        }
+    }
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let (status, response) = self.to_status_and_response();
 
         tracing::error!("Error: {:?}", self);
         (status, response).into_response()
@@ -260,3 +360,45 @@ impl From<TimeFormatError> for AppError {
         AppError::Format(err)
     }
 }
+
+impl From<IoError> for AppError {
+    fn from(err: IoError) -> Self {
+        AppError::Io(err)
+    }
+}
+
+impl From<CsvError> for AppError {
+    fn from(err: CsvError) -> Self {
+        AppError::Csv(err)
+    }
+}
+
+impl From<XlsxError> for AppError {
+    fn from(err: XlsxError) -> Self {
+        AppError::Xlsx(err)
+    }
+}
+
+impl From<SerializeJsonError> for AppError {
+    fn from(err: SerializeJsonError) -> Self {
+        AppError::SerializeJson(err)
+    }
+}
+
+impl From<RegexError> for AppError {
+    fn from(err: RegexError) -> Self {
+        AppError::Regex(err)
+    }
+}
+
+impl From<MultipartError> for AppError {
+    fn from(err: MultipartError) -> Self {
+        AppError::Multipart(err)
+    }
+}
+
+impl From<S3Error> for AppError {
+    fn from(err: S3Error) -> Self {
+        AppError::S3(err)
+    }
+}
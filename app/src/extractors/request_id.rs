@@ -0,0 +1,19 @@
+use crate::errors::AppError;
+use axum::extract::FromRequestParts;
+use axum::http::request::Parts;
+
+const X_REQUEST_ID: &str = "x-request-id";
+
+pub struct RequestId(pub Option<String>);
+
+impl<S: Send + Sync> FromRequestParts<S> for RequestId {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let request_id = match parts.headers.get(X_REQUEST_ID) {
+            Some(request_id) => Some(request_id.to_str()?.to_owned()),
+            None => None,
+        };
+        Ok(RequestId(request_id))
+    }
+}
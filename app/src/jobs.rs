@@ -0,0 +1,3 @@
+pub mod attachment_purge_job;
+pub mod balance_checks_job;
+pub mod scheduled_transactions_job;
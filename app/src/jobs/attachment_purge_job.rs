@@ -0,0 +1,15 @@
+use crate::constants::attachment_constants::ATTACHMENT_RETENTION_DAYS;
+use crate::errors::AppError;
+use crate::services::transaction_attachments_service;
+use crate::storage::storage_backend::StorageBackend;
+use sea_orm::DatabaseConnection;
+
+pub async fn run(db: &DatabaseConnection, storage: &dyn StorageBackend) -> Result<(), AppError> {
+    let purged_attachments = transaction_attachments_service::purge_deleted(db, storage).await?;
+
+    tracing::info!(
+        "Purged {purged_attachments} attachments deleted more than {ATTACHMENT_RETENTION_DAYS} days ago"
+    );
+
+    Ok(())
+}
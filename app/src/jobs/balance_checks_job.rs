@@ -0,0 +1,50 @@
+use crate::dto::response::balance_checks_dto::BalanceDiscrepancy;
+use crate::errors::AppError;
+use crate::services::balance_checks_service;
+use sea_orm::DatabaseConnection;
+
+pub async fn run(db: &DatabaseConnection, repair: bool) -> Result<(), AppError> {
+    let report = balance_checks_service::check(db, repair).await?;
+
+    tracing::info!(
+        "Checked {} wallets and {} budgets",
+        report.checked_wallets,
+        report.checked_budgets
+    );
+    log_discrepancies("wallet", &report.wallet_discrepancies);
+    log_discrepancies("budget", &report.budget_discrepancies);
+    for discrepancy in &report.snapshot_discrepancies {
+        tracing::warn!(
+            "wallet {} ({}) of user {} has {} days of balance history that disagree with the ledger, starting on {}",
+            discrepancy.wallet_id,
+            discrepancy.name,
+            discrepancy.user_id,
+            discrepancy.mismatched_dates,
+            discrepancy.first_date
+        );
+    }
+
+    if report.repaired {
+        tracing::info!("Repaired all discrepancies");
+    } else if !(report.wallet_discrepancies.is_empty()
+        && report.budget_discrepancies.is_empty()
+        && report.snapshot_discrepancies.is_empty())
+    {
+        tracing::info!("Run again with --fix to repair the discrepancies");
+    }
+
+    Ok(())
+}
+
+fn log_discrepancies(kind: &str, discrepancies: &[BalanceDiscrepancy]) {
+    for discrepancy in discrepancies {
+        tracing::warn!(
+            "{kind} {} ({}) of user {} records {} but the ledger gives {}",
+            discrepancy.id,
+            discrepancy.name,
+            discrepancy.user_id,
+            discrepancy.recorded_amount,
+            discrepancy.expected_amount
+        );
+    }
+}
@@ -0,0 +1,38 @@
+use crate::AppState;
+use crate::constants::environment_constants::SCHEDULED_TRANSACTIONS_INTERVAL;
+use crate::constants::transaction_constants::DEFAULT_SCHEDULED_TRANSACTIONS_INTERVAL;
+use crate::services::scheduled_transactions_service;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::{self, MissedTickBehavior};
+
+// read once at startup so a bad value stops the server instead of panicking the job
+pub fn interval_from_env() -> Result<u64, String> {
+    let Ok(interval) = std::env::var(SCHEDULED_TRANSACTIONS_INTERVAL) else {
+        return Ok(DEFAULT_SCHEDULED_TRANSACTIONS_INTERVAL);
+    };
+    match interval.parse::<u64>() {
+        Ok(interval_seconds) if interval_seconds >= 1 => Ok(interval_seconds),
+        _ => Err(format!(
+            "{SCHEDULED_TRANSACTIONS_INTERVAL} must be a whole number of seconds of at least 1, got {interval:?}"
+        )),
+    }
+}
+
+pub fn spawn(state: Arc<AppState>, interval_seconds: u64) {
+    tokio::spawn(async move {
+        let mut interval = time::interval(Duration::from_secs(interval_seconds));
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+            match scheduled_transactions_service::post_all_due(&state.db).await {
+                Ok(0) => {}
+                Ok(posted_count) => {
+                    tracing::info!("Posted {posted_count} scheduled transactions");
+                }
+                Err(err) => tracing::error!("Failed to post scheduled transactions: {err:?}"),
+            }
+        }
+    });
+}
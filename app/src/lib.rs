@@ -1,5 +1,6 @@
 use crate::constants::environment_constants::{DB_URI, PORT, TIMEOUT_DURATION};
 use crate::docs::ApiDoc;
+use crate::storage::storage_backend::{self, StorageBackend};
 use axum::Router;
 use sea_orm::{Database, DatabaseConnection};
 use std::error::Error;
@@ -26,13 +27,16 @@ mod entities;
 mod enums;
 mod errors;
 mod extractors;
+mod jobs;
 mod repositories;
 mod routes;
 mod services;
+mod storage;
 mod utils;
 
 struct AppState {
     db: DatabaseConnection,
+    storage: Arc<dyn StorageBackend>,
 }
 
 #[tokio::main]
@@ -50,13 +54,22 @@ async fn start() -> Result<(), Box<dyn Error>> {
     let timeout_duration: u64 = std::env::var(TIMEOUT_DURATION)?.parse()?;
     let port = std::env::var(PORT)?;
     let address = format!("0.0.0.0:{port}");
+    let storage = storage_backend::from_env()
+        .map_err(|err| format!("Failed to configure the storage backend: {err:?}"))?;
+    let scheduled_transactions_interval = jobs::scheduled_transactions_job::interval_from_env()?;
 
     tracing::info!("Connecting to the database");
     let shared_state = Arc::new(AppState {
         db: Database::connect(db_uri).await?,
+        storage,
     });
     tracing::info!("Connected to the database");
 
+    jobs::scheduled_transactions_job::spawn(
+        Arc::clone(&shared_state),
+        scheduled_transactions_interval,
+    );
+
     let mut app = Router::new()
         .merge(routes::register())
         .with_state(Arc::clone(&shared_state))
@@ -99,6 +112,38 @@ async fn start() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+// one-off entry point for `app check-balances [--fix]`
+#[tokio::main]
+async fn check_balances(repair: bool) -> Result<(), Box<dyn Error>> {
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::from_default_env())
+        .init();
+
+    let db = Database::connect(std::env::var(DB_URI)?).await?;
+    let result = jobs::balance_checks_job::run(&db, repair).await;
+    db.close().await?;
+    result.map_err(|err| format!("Failed to check balances: {err:?}"))?;
+
+    Ok(())
+}
+
+// one-off entry point for `app purge-attachments`
+#[tokio::main]
+async fn purge_attachments() -> Result<(), Box<dyn Error>> {
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::from_default_env())
+        .init();
+
+    let storage = storage_backend::from_env()
+        .map_err(|err| format!("Failed to configure the storage backend: {err:?}"))?;
+    let db = Database::connect(std::env::var(DB_URI)?).await?;
+    let result = jobs::attachment_purge_job::run(&db, storage.as_ref()).await;
+    db.close().await?;
+    result.map_err(|err| format!("Failed to purge attachments: {err:?}"))?;
+
+    Ok(())
+}
+
 async fn shutdown_signal(state: Arc<AppState>) {
     let ctrl_c = async { signal::ctrl_c().await.unwrap() };
 
@@ -119,7 +164,12 @@ async fn shutdown_signal(state: Arc<AppState>) {
 }
 
 pub fn main() {
-    let result = start();
+    let mut args = std::env::args().skip(1);
+    let result = match args.next().as_deref() {
+        Some("check-balances") => check_balances(args.any(|arg| arg == "--fix")),
+        Some("purge-attachments") => purge_attachments(),
+        _ => start(),
+    };
 
     if let Err(err) = result {
         tracing::error!("Error: {err}");
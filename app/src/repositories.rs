@@ -1,8 +1,21 @@
 pub mod budget_configs_repository;
 pub mod budgets_repository;
 pub mod categories_repository;
+pub mod exchange_rates_repository;
+pub mod installment_plans_repository;
+pub mod payee_aliases_repository;
+pub mod payees_repository;
+pub mod reconciliations_repository;
 pub mod refresh_tokens_repository;
+pub mod tags_repository;
+pub mod transaction_attachments_repository;
+pub mod transaction_revisions_repository;
+pub mod transaction_rule_tags_repository;
+pub mod transaction_rules_repository;
+pub mod transaction_tags_repository;
 pub mod transactions_repository;
 pub mod user_roles_repository;
 pub mod users_repository;
+pub mod wallet_balance_snapshots_repository;
+pub mod wallet_members_repository;
 pub mod wallets_repository;
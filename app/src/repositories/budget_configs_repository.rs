@@ -49,3 +49,29 @@ pub async fn find_all_active_by_user_id_order_by_name_asc(
 
     Ok(found_budget_configs)
 }
+
+pub async fn find_all_deleted_by_user_id_order_by_deleted_at_desc(
+    connection: &impl ConnectionTrait,
+    user_id: i32,
+) -> Result<Vec<budget_configs::Model>, AppError> {
+    let found_budget_configs = BudgetConfigs::find()
+        .filter(budget_configs::Column::UserId.eq(user_id))
+        .filter(budget_configs::Column::DeletedAt.is_not_null())
+        .order_by_desc(budget_configs::Column::DeletedAt)
+        .all(connection)
+        .await?;
+    Ok(found_budget_configs)
+}
+
+pub async fn get_deleted_by_id_and_user_id(
+    connection: &impl ConnectionTrait,
+    budget_config_id: i32,
+    user_id: i32,
+) -> Result<Option<budget_configs::Model>, AppError> {
+    let found_budget_config = BudgetConfigs::find_by_id(budget_config_id)
+        .filter(budget_configs::Column::UserId.eq(user_id))
+        .filter(budget_configs::Column::DeletedAt.is_not_null())
+        .one(connection)
+        .await?;
+    Ok(found_budget_config)
+}
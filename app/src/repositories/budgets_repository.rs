@@ -3,7 +3,7 @@ use crate::entities::prelude::Budgets;
 use crate::errors::AppError;
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
-    QueryOrder, TryIntoModel,
+    QueryOrder, QuerySelect, TryIntoModel,
 };
 
 pub async fn save(
@@ -28,6 +28,29 @@ pub async fn find_all_active_by_user_id(
     Ok(found_budgets)
 }
 
+pub async fn find_all_active_order_by_id_asc(
+    connection: &impl ConnectionTrait,
+) -> Result<Vec<budgets::Model>, AppError> {
+    let found_budgets = Budgets::find()
+        .filter(budgets::Column::DeletedAt.is_null())
+        .order_by_asc(budgets::Column::Id)
+        .all(connection)
+        .await?;
+    Ok(found_budgets)
+}
+
+pub async fn find_all_active_order_by_id_asc_for_update(
+    connection: &impl ConnectionTrait,
+) -> Result<Vec<budgets::Model>, AppError> {
+    let found_budgets = Budgets::find()
+        .filter(budgets::Column::DeletedAt.is_null())
+        .order_by_asc(budgets::Column::Id)
+        .lock_exclusive()
+        .all(connection)
+        .await?;
+    Ok(found_budgets)
+}
+
 pub async fn get_active_by_id_and_user_id(
     db: &impl ConnectionTrait,
     budget_id: i32,
@@ -40,3 +63,29 @@ pub async fn get_active_by_id_and_user_id(
         .await?;
     Ok(found_budget)
 }
+
+pub async fn find_all_deleted_by_user_id_order_by_deleted_at_desc(
+    connection: &impl ConnectionTrait,
+    user_id: i32,
+) -> Result<Vec<budgets::Model>, AppError> {
+    let found_budgets = Budgets::find()
+        .filter(budgets::Column::UserId.eq(user_id))
+        .filter(budgets::Column::DeletedAt.is_not_null())
+        .order_by_desc(budgets::Column::DeletedAt)
+        .all(connection)
+        .await?;
+    Ok(found_budgets)
+}
+
+pub async fn get_deleted_by_id_and_user_id(
+    connection: &impl ConnectionTrait,
+    budget_id: i32,
+    user_id: i32,
+) -> Result<Option<budgets::Model>, AppError> {
+    let found_budget = Budgets::find_by_id(budget_id)
+        .filter(budgets::Column::UserId.eq(user_id))
+        .filter(budgets::Column::DeletedAt.is_not_null())
+        .one(connection)
+        .await?;
+    Ok(found_budget)
+}
@@ -7,7 +7,7 @@ use sea_orm::prelude::Expr;
 use sea_orm::sea_query::extension::postgres::PgExpr;
 use sea_orm::{
     ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait,
-    IntoActiveModel, ItemsAndPagesNumber, PaginatorTrait, QueryFilter, QueryOrder,
+    IntoActiveModel, ItemsAndPagesNumber, PaginatorTrait, QueryFilter, QueryOrder, TryIntoModel,
 };
 use time::OffsetDateTime;
 
@@ -105,3 +105,37 @@ pub async fn get_active_by_id_and_user_id(
         .await?;
     Ok(found_category)
 }
+
+pub async fn save(
+    connection: &impl ConnectionTrait,
+    category: categories::ActiveModel,
+) -> Result<categories::Model, AppError> {
+    let category = category.save(connection).await?.try_into_model()?;
+    Ok(category)
+}
+
+pub async fn find_all_deleted_by_user_id_order_by_deleted_at_desc(
+    connection: &impl ConnectionTrait,
+    user_id: i32,
+) -> Result<Vec<categories::Model>, AppError> {
+    let found_categories = Categories::find()
+        .filter(categories::Column::UserId.eq(user_id))
+        .filter(categories::Column::DeletedAt.is_not_null())
+        .order_by_desc(categories::Column::DeletedAt)
+        .all(connection)
+        .await?;
+    Ok(found_categories)
+}
+
+pub async fn get_deleted_by_id_and_user_id(
+    connection: &impl ConnectionTrait,
+    category_id: i32,
+    user_id: i32,
+) -> Result<Option<categories::Model>, AppError> {
+    let found_category = Categories::find_by_id(category_id)
+        .filter(categories::Column::UserId.eq(user_id))
+        .filter(categories::Column::DeletedAt.is_not_null())
+        .one(connection)
+        .await?;
+    Ok(found_category)
+}
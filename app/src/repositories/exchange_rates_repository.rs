@@ -0,0 +1,146 @@
+use crate::dto::request::exchange_rates_dto::ValidatedFindAllExchangeRatesParams;
+use crate::entities::exchange_rates;
+use crate::entities::prelude::ExchangeRates;
+use crate::errors::AppError;
+use sea_orm::sea_query::{Expr, OnConflict};
+use sea_orm::{
+    ColumnTrait, Condition, ConnectionTrait, EntityTrait, ItemsAndPagesNumber, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect,
+};
+use time::Date;
+
+fn on_currencies_and_rate_date_conflict() -> OnConflict {
+    OnConflict::columns([
+        exchange_rates::Column::UserId,
+        exchange_rates::Column::BaseCurrency,
+        exchange_rates::Column::QuoteCurrency,
+        exchange_rates::Column::RateDate,
+    ])
+    .update_columns([
+        exchange_rates::Column::Rate,
+        exchange_rates::Column::UpdatedAt,
+    ])
+    .to_owned()
+}
+
+pub async fn upsert(
+    connection: &impl ConnectionTrait,
+    exchange_rate: exchange_rates::ActiveModel,
+) -> Result<exchange_rates::Model, AppError> {
+    let exchange_rate = ExchangeRates::insert(exchange_rate)
+        .on_conflict(on_currencies_and_rate_date_conflict())
+        .exec_with_returning(connection)
+        .await?;
+    Ok(exchange_rate)
+}
+
+pub async fn upsert_many(
+    connection: &impl ConnectionTrait,
+    exchange_rates: Vec<exchange_rates::ActiveModel>,
+) -> Result<u64, AppError> {
+    if exchange_rates.is_empty() {
+        return Ok(0);
+    }
+
+    let upserted_rows = ExchangeRates::insert_many(exchange_rates)
+        .on_conflict(on_currencies_and_rate_date_conflict())
+        .exec_without_returning(connection)
+        .await?;
+    Ok(upserted_rows)
+}
+
+// shared rates plus the manual rates of the user
+fn visible_to(user_id: i32) -> Condition {
+    Condition::any()
+        .add(exchange_rates::Column::UserId.is_null())
+        .add(exchange_rates::Column::UserId.eq(user_id))
+}
+
+pub async fn find_all_by_user_id_and_params_order_by_rate_date_desc(
+    connection: &impl ConnectionTrait,
+    user_id: i32,
+    params: ValidatedFindAllExchangeRatesParams,
+) -> Result<(Vec<exchange_rates::Model>, ItemsAndPagesNumber), AppError> {
+    let mut found_exchange_rates_builder = ExchangeRates::find().filter(visible_to(user_id));
+    if let Some(base_currency) = params.base_currency {
+        found_exchange_rates_builder = found_exchange_rates_builder
+            .filter(exchange_rates::Column::BaseCurrency.eq(base_currency));
+    }
+    if let Some(quote_currency) = params.quote_currency {
+        found_exchange_rates_builder = found_exchange_rates_builder
+            .filter(exchange_rates::Column::QuoteCurrency.eq(quote_currency));
+    }
+    if let Some(start_date) = params.start_date {
+        found_exchange_rates_builder =
+            found_exchange_rates_builder.filter(exchange_rates::Column::RateDate.gte(start_date));
+    }
+    if let Some(end_date) = params.end_date {
+        found_exchange_rates_builder =
+            found_exchange_rates_builder.filter(exchange_rates::Column::RateDate.lte(end_date));
+    }
+
+    let paginator = found_exchange_rates_builder
+        .order_by_desc(exchange_rates::Column::RateDate)
+        .order_by_asc(exchange_rates::Column::BaseCurrency)
+        .order_by_asc(exchange_rates::Column::QuoteCurrency)
+        .order_by_asc(exchange_rates::Column::Id)
+        .paginate(connection, params.page_size);
+    let found_exchange_rates = paginator.fetch_page(params.page - 1).await?;
+    let page_information = paginator.num_items_and_pages().await?;
+
+    Ok((found_exchange_rates, page_information))
+}
+
+// either direction of the pair, the latest date first and a manual rate before a shared one
+pub async fn find_latest_by_user_id_and_currencies_and_rate_date_lte(
+    connection: &impl ConnectionTrait,
+    user_id: i32,
+    first_currency: &str,
+    second_currency: &str,
+    rate_date: Date,
+) -> Result<Option<exchange_rates::Model>, AppError> {
+    let found_exchange_rate = ExchangeRates::find()
+        .filter(visible_to(user_id))
+        .filter(
+            Condition::any()
+                .add(
+                    Condition::all()
+                        .add(exchange_rates::Column::BaseCurrency.eq(first_currency))
+                        .add(exchange_rates::Column::QuoteCurrency.eq(second_currency)),
+                )
+                .add(
+                    Condition::all()
+                        .add(exchange_rates::Column::BaseCurrency.eq(second_currency))
+                        .add(exchange_rates::Column::QuoteCurrency.eq(first_currency)),
+                ),
+        )
+        .filter(exchange_rates::Column::RateDate.lte(rate_date))
+        .order_by_desc(exchange_rates::Column::RateDate)
+        .order_by_asc(Expr::col(exchange_rates::Column::UserId).is_null())
+        .limit(1)
+        .one(connection)
+        .await?;
+    Ok(found_exchange_rate)
+}
+
+pub async fn get_manual_by_id_and_user_id(
+    connection: &impl ConnectionTrait,
+    exchange_rate_id: i32,
+    user_id: i32,
+) -> Result<Option<exchange_rates::Model>, AppError> {
+    let found_exchange_rate = ExchangeRates::find_by_id(exchange_rate_id)
+        .filter(exchange_rates::Column::UserId.eq(user_id))
+        .one(connection)
+        .await?;
+    Ok(found_exchange_rate)
+}
+
+pub async fn delete_by_id(
+    connection: &impl ConnectionTrait,
+    exchange_rate_id: i32,
+) -> Result<(), AppError> {
+    ExchangeRates::delete_by_id(exchange_rate_id)
+        .exec(connection)
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use crate::entities::installment_plans;
+use crate::entities::prelude::InstallmentPlans;
+use crate::errors::AppError;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder,
+    TryIntoModel,
+};
+
+pub async fn save(
+    connection: &impl ConnectionTrait,
+    installment_plan: installment_plans::ActiveModel,
+) -> Result<installment_plans::Model, AppError> {
+    let installment_plan = installment_plan.save(connection).await?.try_into_model()?;
+    Ok(installment_plan)
+}
+
+pub async fn find_all_active_by_user_id_order_by_first_issued_at_desc(
+    connection: &impl ConnectionTrait,
+    user_id: i32,
+) -> Result<Vec<installment_plans::Model>, AppError> {
+    let found_installment_plans = InstallmentPlans::find()
+        .filter(installment_plans::Column::UserId.eq(user_id))
+        .filter(installment_plans::Column::DeletedAt.is_null())
+        .order_by_desc(installment_plans::Column::FirstIssuedAt)
+        .order_by_desc(installment_plans::Column::Id)
+        .all(connection)
+        .await?;
+    Ok(found_installment_plans)
+}
+
+pub async fn get_active_by_id_and_user_id(
+    connection: &impl ConnectionTrait,
+    installment_plan_id: i32,
+    user_id: i32,
+) -> Result<Option<installment_plans::Model>, AppError> {
+    let found_installment_plan = InstallmentPlans::find_by_id(installment_plan_id)
+        .filter(installment_plans::Column::UserId.eq(user_id))
+        .filter(installment_plans::Column::DeletedAt.is_null())
+        .one(connection)
+        .await?;
+    Ok(found_installment_plan)
+}
+
+pub async fn find_all_deleted_by_user_id_order_by_deleted_at_desc(
+    connection: &impl ConnectionTrait,
+    user_id: i32,
+) -> Result<Vec<installment_plans::Model>, AppError> {
+    let found_installment_plans = InstallmentPlans::find()
+        .filter(installment_plans::Column::UserId.eq(user_id))
+        .filter(installment_plans::Column::DeletedAt.is_not_null())
+        .order_by_desc(installment_plans::Column::DeletedAt)
+        .all(connection)
+        .await?;
+    Ok(found_installment_plans)
+}
+
+pub async fn get_deleted_by_id_and_user_id(
+    connection: &impl ConnectionTrait,
+    installment_plan_id: i32,
+    user_id: i32,
+) -> Result<Option<installment_plans::Model>, AppError> {
+    let found_installment_plan = InstallmentPlans::find_by_id(installment_plan_id)
+        .filter(installment_plans::Column::UserId.eq(user_id))
+        .filter(installment_plans::Column::DeletedAt.is_not_null())
+        .one(connection)
+        .await?;
+    Ok(found_installment_plan)
+}
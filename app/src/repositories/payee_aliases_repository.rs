@@ -0,0 +1,41 @@
+use crate::entities::payee_aliases;
+use crate::entities::prelude::PayeeAliases;
+use crate::errors::AppError;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder};
+
+pub async fn insert_many(
+    connection: &impl ConnectionTrait,
+    payee_aliases: Vec<payee_aliases::ActiveModel>,
+) -> Result<(), AppError> {
+    if payee_aliases.is_empty() {
+        return Ok(());
+    }
+
+    PayeeAliases::insert_many(payee_aliases)
+        .exec_without_returning(connection)
+        .await?;
+    Ok(())
+}
+
+pub async fn delete_all_by_payee_id(
+    connection: &impl ConnectionTrait,
+    payee_id: i32,
+) -> Result<(), AppError> {
+    PayeeAliases::delete_many()
+        .filter(payee_aliases::Column::PayeeId.eq(payee_id))
+        .exec(connection)
+        .await?;
+    Ok(())
+}
+
+pub async fn find_all_by_payee_ids_order_by_id_asc(
+    connection: &impl ConnectionTrait,
+    payee_ids: Vec<i32>,
+) -> Result<Vec<payee_aliases::Model>, AppError> {
+    let found_payee_aliases = PayeeAliases::find()
+        .filter(payee_aliases::Column::PayeeId.is_in(payee_ids))
+        .order_by_asc(payee_aliases::Column::Id)
+        .all(connection)
+        .await?;
+    Ok(found_payee_aliases)
+}
@@ -0,0 +1,112 @@
+use crate::dto::response::payees_dto::PayeeTotalRow;
+use crate::entities::prelude::Payees;
+use crate::entities::{payees, transactions, wallets};
+use crate::errors::AppError;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, JoinType, QueryFilter, QueryOrder,
+    QuerySelect, RelationTrait, TryIntoModel,
+};
+use time::Date;
+
+pub async fn save(
+    connection: &impl ConnectionTrait,
+    payee: payees::ActiveModel,
+) -> Result<payees::Model, AppError> {
+    let payee = payee.save(connection).await?.try_into_model()?;
+    Ok(payee)
+}
+
+pub async fn find_all_active_by_user_id_order_by_name_asc(
+    connection: &impl ConnectionTrait,
+    user_id: i32,
+) -> Result<Vec<payees::Model>, AppError> {
+    let found_payees = Payees::find()
+        .filter(payees::Column::UserId.eq(user_id))
+        .filter(payees::Column::DeletedAt.is_null())
+        .order_by_asc(payees::Column::Name)
+        .all(connection)
+        .await?;
+    Ok(found_payees)
+}
+
+pub async fn get_active_by_id_and_user_id(
+    connection: &impl ConnectionTrait,
+    payee_id: i32,
+    user_id: i32,
+) -> Result<Option<payees::Model>, AppError> {
+    let found_payee = Payees::find_by_id(payee_id)
+        .filter(payees::Column::UserId.eq(user_id))
+        .filter(payees::Column::DeletedAt.is_null())
+        .one(connection)
+        .await?;
+    Ok(found_payee)
+}
+
+pub async fn find_totals_by_user_id_and_issued_at_between(
+    connection: &impl ConnectionTrait,
+    user_id: i32,
+    start_date: Option<Date>,
+    end_date: Option<Date>,
+) -> Result<Vec<PayeeTotalRow>, AppError> {
+    let mut found_totals_builder = Payees::find()
+        .select_only()
+        .column_as(payees::Column::Id, "payee_id")
+        .column(payees::Column::Name)
+        .column(transactions::Column::FlowDirection)
+        .column(transactions::Column::Currency)
+        .column_as(transactions::Column::Amount.sum(), "total_amount")
+        .column_as(transactions::Column::Id.count(), "transaction_count")
+        .join(JoinType::InnerJoin, payees::Relation::Transactions.def())
+        .join(JoinType::InnerJoin, transactions::Relation::Wallets.def())
+        .filter(payees::Column::UserId.eq(user_id))
+        .filter(payees::Column::DeletedAt.is_null())
+        .filter(transactions::Column::DeletedAt.is_null())
+        .filter(wallets::Column::ExcludeFromTotals.eq(false));
+
+    if let Some(start_date) = start_date {
+        found_totals_builder =
+            found_totals_builder.filter(transactions::Column::IssuedAt.gte(start_date));
+    }
+    if let Some(end_date) = end_date {
+        found_totals_builder =
+            found_totals_builder.filter(transactions::Column::IssuedAt.lte(end_date));
+    }
+
+    let found_totals = found_totals_builder
+        .group_by(payees::Column::Id)
+        .group_by(payees::Column::Name)
+        .group_by(transactions::Column::FlowDirection)
+        .group_by(transactions::Column::Currency)
+        .order_by_asc(payees::Column::Name)
+        .order_by_asc(payees::Column::Id)
+        .into_model::<PayeeTotalRow>()
+        .all(connection)
+        .await?;
+    Ok(found_totals)
+}
+
+pub async fn find_all_deleted_by_user_id_order_by_deleted_at_desc(
+    connection: &impl ConnectionTrait,
+    user_id: i32,
+) -> Result<Vec<payees::Model>, AppError> {
+    let found_payees = Payees::find()
+        .filter(payees::Column::UserId.eq(user_id))
+        .filter(payees::Column::DeletedAt.is_not_null())
+        .order_by_desc(payees::Column::DeletedAt)
+        .all(connection)
+        .await?;
+    Ok(found_payees)
+}
+
+pub async fn get_deleted_by_id_and_user_id(
+    connection: &impl ConnectionTrait,
+    payee_id: i32,
+    user_id: i32,
+) -> Result<Option<payees::Model>, AppError> {
+    let found_payee = Payees::find_by_id(payee_id)
+        .filter(payees::Column::UserId.eq(user_id))
+        .filter(payees::Column::DeletedAt.is_not_null())
+        .one(connection)
+        .await?;
+    Ok(found_payee)
+}
@@ -0,0 +1,89 @@
+use crate::entities::prelude::Reconciliations;
+use crate::entities::reconciliations;
+use crate::errors::AppError;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder,
+    TryIntoModel,
+};
+
+pub async fn save(
+    connection: &impl ConnectionTrait,
+    reconciliation: reconciliations::ActiveModel,
+) -> Result<reconciliations::Model, AppError> {
+    let reconciliation = reconciliation.save(connection).await?.try_into_model()?;
+    Ok(reconciliation)
+}
+
+pub async fn find_all_active_by_wallet_id_and_user_id_order_by_statement_date_desc(
+    connection: &impl ConnectionTrait,
+    wallet_id: i32,
+    user_id: i32,
+) -> Result<Vec<reconciliations::Model>, AppError> {
+    let found_reconciliations = Reconciliations::find()
+        .filter(reconciliations::Column::WalletId.eq(wallet_id))
+        .filter(reconciliations::Column::UserId.eq(user_id))
+        .filter(reconciliations::Column::DeletedAt.is_null())
+        .order_by_desc(reconciliations::Column::StatementDate)
+        .order_by_desc(reconciliations::Column::CreatedAt)
+        .all(connection)
+        .await?;
+    Ok(found_reconciliations)
+}
+
+pub async fn get_active_by_id_and_wallet_id_and_user_id(
+    connection: &impl ConnectionTrait,
+    reconciliation_id: i32,
+    wallet_id: i32,
+    user_id: i32,
+) -> Result<Option<reconciliations::Model>, AppError> {
+    let found_reconciliation = Reconciliations::find_by_id(reconciliation_id)
+        .filter(reconciliations::Column::WalletId.eq(wallet_id))
+        .filter(reconciliations::Column::UserId.eq(user_id))
+        .filter(reconciliations::Column::DeletedAt.is_null())
+        .one(connection)
+        .await?;
+    Ok(found_reconciliation)
+}
+
+pub async fn get_active_open_by_wallet_id(
+    connection: &impl ConnectionTrait,
+    wallet_id: i32,
+) -> Result<Option<reconciliations::Model>, AppError> {
+    let found_reconciliation = Reconciliations::find()
+        .filter(reconciliations::Column::WalletId.eq(wallet_id))
+        .filter(reconciliations::Column::DeletedAt.is_null())
+        .filter(reconciliations::Column::CompletedAt.is_null())
+        .one(connection)
+        .await?;
+    Ok(found_reconciliation)
+}
+
+pub async fn find_all_deleted_by_wallet_id_and_user_id_order_by_deleted_at_desc(
+    connection: &impl ConnectionTrait,
+    wallet_id: i32,
+    user_id: i32,
+) -> Result<Vec<reconciliations::Model>, AppError> {
+    let found_reconciliations = Reconciliations::find()
+        .filter(reconciliations::Column::WalletId.eq(wallet_id))
+        .filter(reconciliations::Column::UserId.eq(user_id))
+        .filter(reconciliations::Column::DeletedAt.is_not_null())
+        .order_by_desc(reconciliations::Column::DeletedAt)
+        .all(connection)
+        .await?;
+    Ok(found_reconciliations)
+}
+
+pub async fn get_deleted_by_id_and_wallet_id_and_user_id(
+    connection: &impl ConnectionTrait,
+    reconciliation_id: i32,
+    wallet_id: i32,
+    user_id: i32,
+) -> Result<Option<reconciliations::Model>, AppError> {
+    let found_reconciliation = Reconciliations::find_by_id(reconciliation_id)
+        .filter(reconciliations::Column::WalletId.eq(wallet_id))
+        .filter(reconciliations::Column::UserId.eq(user_id))
+        .filter(reconciliations::Column::DeletedAt.is_not_null())
+        .one(connection)
+        .await?;
+    Ok(found_reconciliation)
+}
@@ -0,0 +1,130 @@
+use crate::dto::response::tags_dto::TagTotalRow;
+use crate::entities::prelude::Tags;
+use crate::entities::{tags, transaction_tags, transactions, wallets};
+use crate::errors::AppError;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, JoinType, QueryFilter, QueryOrder,
+    QuerySelect, RelationTrait, TryIntoModel,
+};
+use time::Date;
+
+pub async fn save(
+    connection: &impl ConnectionTrait,
+    tag: tags::ActiveModel,
+) -> Result<tags::Model, AppError> {
+    let tag = tag.save(connection).await?.try_into_model()?;
+    Ok(tag)
+}
+
+pub async fn find_all_active_by_user_id_order_by_name_asc(
+    connection: &impl ConnectionTrait,
+    user_id: i32,
+) -> Result<Vec<tags::Model>, AppError> {
+    let found_tags = Tags::find()
+        .filter(tags::Column::UserId.eq(user_id))
+        .filter(tags::Column::DeletedAt.is_null())
+        .order_by_asc(tags::Column::Name)
+        .all(connection)
+        .await?;
+    Ok(found_tags)
+}
+
+pub async fn find_all_active_by_ids_and_user_id(
+    connection: &impl ConnectionTrait,
+    tag_ids: Vec<i32>,
+    user_id: i32,
+) -> Result<Vec<tags::Model>, AppError> {
+    let found_tags = Tags::find()
+        .filter(tags::Column::Id.is_in(tag_ids))
+        .filter(tags::Column::UserId.eq(user_id))
+        .filter(tags::Column::DeletedAt.is_null())
+        .all(connection)
+        .await?;
+    Ok(found_tags)
+}
+
+pub async fn get_active_by_id_and_user_id(
+    connection: &impl ConnectionTrait,
+    tag_id: i32,
+    user_id: i32,
+) -> Result<Option<tags::Model>, AppError> {
+    let found_tag = Tags::find_by_id(tag_id)
+        .filter(tags::Column::UserId.eq(user_id))
+        .filter(tags::Column::DeletedAt.is_null())
+        .one(connection)
+        .await?;
+    Ok(found_tag)
+}
+
+pub async fn find_totals_by_user_id_and_issued_at_between(
+    connection: &impl ConnectionTrait,
+    user_id: i32,
+    start_date: Option<Date>,
+    end_date: Option<Date>,
+) -> Result<Vec<TagTotalRow>, AppError> {
+    let mut found_totals_builder = Tags::find()
+        .select_only()
+        .column_as(tags::Column::Id, "tag_id")
+        .column(tags::Column::Name)
+        .column(transactions::Column::FlowDirection)
+        .column(transactions::Column::Currency)
+        .column_as(transactions::Column::Amount.sum(), "total_amount")
+        .column_as(transactions::Column::Id.count(), "transaction_count")
+        .join(JoinType::InnerJoin, tags::Relation::TransactionTags.def())
+        .join(
+            JoinType::InnerJoin,
+            transaction_tags::Relation::Transactions.def(),
+        )
+        .join(JoinType::InnerJoin, transactions::Relation::Wallets.def())
+        .filter(tags::Column::UserId.eq(user_id))
+        .filter(tags::Column::DeletedAt.is_null())
+        .filter(transactions::Column::DeletedAt.is_null())
+        .filter(wallets::Column::ExcludeFromTotals.eq(false));
+
+    if let Some(start_date) = start_date {
+        found_totals_builder =
+            found_totals_builder.filter(transactions::Column::IssuedAt.gte(start_date));
+    }
+    if let Some(end_date) = end_date {
+        found_totals_builder =
+            found_totals_builder.filter(transactions::Column::IssuedAt.lte(end_date));
+    }
+
+    let found_totals = found_totals_builder
+        .group_by(tags::Column::Id)
+        .group_by(tags::Column::Name)
+        .group_by(transactions::Column::FlowDirection)
+        .group_by(transactions::Column::Currency)
+        .order_by_asc(tags::Column::Name)
+        .order_by_asc(tags::Column::Id)
+        .into_model::<TagTotalRow>()
+        .all(connection)
+        .await?;
+    Ok(found_totals)
+}
+
+pub async fn find_all_deleted_by_user_id_order_by_deleted_at_desc(
+    connection: &impl ConnectionTrait,
+    user_id: i32,
+) -> Result<Vec<tags::Model>, AppError> {
+    let found_tags = Tags::find()
+        .filter(tags::Column::UserId.eq(user_id))
+        .filter(tags::Column::DeletedAt.is_not_null())
+        .order_by_desc(tags::Column::DeletedAt)
+        .all(connection)
+        .await?;
+    Ok(found_tags)
+}
+
+pub async fn get_deleted_by_id_and_user_id(
+    connection: &impl ConnectionTrait,
+    tag_id: i32,
+    user_id: i32,
+) -> Result<Option<tags::Model>, AppError> {
+    let found_tag = Tags::find_by_id(tag_id)
+        .filter(tags::Column::UserId.eq(user_id))
+        .filter(tags::Column::DeletedAt.is_not_null())
+        .one(connection)
+        .await?;
+    Ok(found_tag)
+}
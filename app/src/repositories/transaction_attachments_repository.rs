@@ -0,0 +1,103 @@
+use crate::entities::prelude::TransactionAttachments;
+use crate::entities::transaction_attachments;
+use crate::errors::AppError;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder,
+    TryIntoModel,
+};
+use time::OffsetDateTime;
+
+pub async fn save(
+    connection: &impl ConnectionTrait,
+    transaction_attachment: transaction_attachments::ActiveModel,
+) -> Result<transaction_attachments::Model, AppError> {
+    let transaction_attachment = transaction_attachment
+        .save(connection)
+        .await?
+        .try_into_model()?;
+    Ok(transaction_attachment)
+}
+
+pub async fn find_all_active_by_transaction_id_and_user_id_order_by_created_at_asc(
+    connection: &impl ConnectionTrait,
+    transaction_id: i32,
+    user_id: i32,
+) -> Result<Vec<transaction_attachments::Model>, AppError> {
+    let found_transaction_attachments = TransactionAttachments::find()
+        .filter(transaction_attachments::Column::TransactionId.eq(transaction_id))
+        .filter(transaction_attachments::Column::UserId.eq(user_id))
+        .filter(transaction_attachments::Column::DeletedAt.is_null())
+        .order_by_asc(transaction_attachments::Column::CreatedAt)
+        .all(connection)
+        .await?;
+    Ok(found_transaction_attachments)
+}
+
+pub async fn get_active_by_id_and_transaction_id_and_user_id(
+    connection: &impl ConnectionTrait,
+    transaction_attachment_id: i32,
+    transaction_id: i32,
+    user_id: i32,
+) -> Result<Option<transaction_attachments::Model>, AppError> {
+    let found_transaction_attachment =
+        TransactionAttachments::find_by_id(transaction_attachment_id)
+            .filter(transaction_attachments::Column::TransactionId.eq(transaction_id))
+            .filter(transaction_attachments::Column::UserId.eq(user_id))
+            .filter(transaction_attachments::Column::DeletedAt.is_null())
+            .one(connection)
+            .await?;
+    Ok(found_transaction_attachment)
+}
+
+pub async fn find_all_deleted_by_transaction_id_and_user_id_order_by_deleted_at_desc(
+    connection: &impl ConnectionTrait,
+    transaction_id: i32,
+    user_id: i32,
+) -> Result<Vec<transaction_attachments::Model>, AppError> {
+    let found_transaction_attachments = TransactionAttachments::find()
+        .filter(transaction_attachments::Column::TransactionId.eq(transaction_id))
+        .filter(transaction_attachments::Column::UserId.eq(user_id))
+        .filter(transaction_attachments::Column::DeletedAt.is_not_null())
+        .order_by_desc(transaction_attachments::Column::DeletedAt)
+        .all(connection)
+        .await?;
+    Ok(found_transaction_attachments)
+}
+
+pub async fn get_deleted_by_id_and_transaction_id_and_user_id(
+    connection: &impl ConnectionTrait,
+    transaction_attachment_id: i32,
+    transaction_id: i32,
+    user_id: i32,
+) -> Result<Option<transaction_attachments::Model>, AppError> {
+    let found_transaction_attachment =
+        TransactionAttachments::find_by_id(transaction_attachment_id)
+            .filter(transaction_attachments::Column::TransactionId.eq(transaction_id))
+            .filter(transaction_attachments::Column::UserId.eq(user_id))
+            .filter(transaction_attachments::Column::DeletedAt.is_not_null())
+            .one(connection)
+            .await?;
+    Ok(found_transaction_attachment)
+}
+
+pub async fn find_all_deleted_by_deleted_at_lt(
+    connection: &impl ConnectionTrait,
+    deleted_at: OffsetDateTime,
+) -> Result<Vec<transaction_attachments::Model>, AppError> {
+    let found_transaction_attachments = TransactionAttachments::find()
+        .filter(transaction_attachments::Column::DeletedAt.lt(deleted_at))
+        .order_by_asc(transaction_attachments::Column::Id)
+        .all(connection)
+        .await?;
+    Ok(found_transaction_attachments)
+}
+
+pub async fn delete_by_id(
+    connection: &impl ConnectionTrait,
+    transaction_attachment_id: i32,
+) -> Result<(), AppError> {
+    TransactionAttachments::delete_by_id(transaction_attachment_id)
+        .exec(connection)
+        .await?;
+    Ok(())
+}
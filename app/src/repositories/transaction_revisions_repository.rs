@@ -0,0 +1,44 @@
+use crate::entities::prelude::TransactionRevisions;
+use crate::entities::transaction_revisions;
+use crate::errors::AppError;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder,
+    TryIntoModel,
+};
+
+pub async fn save(
+    connection: &impl ConnectionTrait,
+    transaction_revision: transaction_revisions::ActiveModel,
+) -> Result<transaction_revisions::Model, AppError> {
+    let transaction_revision = transaction_revision
+        .save(connection)
+        .await?
+        .try_into_model()?;
+    Ok(transaction_revision)
+}
+
+pub async fn get_latest_by_transaction_id(
+    connection: &impl ConnectionTrait,
+    transaction_id: i32,
+) -> Result<Option<transaction_revisions::Model>, AppError> {
+    let found_transaction_revision = TransactionRevisions::find()
+        .filter(transaction_revisions::Column::TransactionId.eq(transaction_id))
+        .order_by_desc(transaction_revisions::Column::Id)
+        .one(connection)
+        .await?;
+    Ok(found_transaction_revision)
+}
+
+pub async fn find_all_by_transaction_id_and_user_id_order_by_id_asc(
+    connection: &impl ConnectionTrait,
+    transaction_id: i32,
+    user_id: i32,
+) -> Result<Vec<transaction_revisions::Model>, AppError> {
+    let found_transaction_revisions = TransactionRevisions::find()
+        .filter(transaction_revisions::Column::TransactionId.eq(transaction_id))
+        .filter(transaction_revisions::Column::UserId.eq(user_id))
+        .order_by_asc(transaction_revisions::Column::Id)
+        .all(connection)
+        .await?;
+    Ok(found_transaction_revisions)
+}
@@ -0,0 +1,47 @@
+use crate::entities::prelude::TransactionRuleTags;
+use crate::entities::{tags, transaction_rule_tags};
+use crate::errors::AppError;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, EntityTrait, JoinType, QueryFilter, QuerySelect, RelationTrait,
+};
+
+pub async fn insert_many(
+    connection: &impl ConnectionTrait,
+    transaction_rule_tags: Vec<transaction_rule_tags::ActiveModel>,
+) -> Result<(), AppError> {
+    if transaction_rule_tags.is_empty() {
+        return Ok(());
+    }
+
+    TransactionRuleTags::insert_many(transaction_rule_tags)
+        .exec_without_returning(connection)
+        .await?;
+    Ok(())
+}
+
+pub async fn delete_all_by_transaction_rule_id(
+    connection: &impl ConnectionTrait,
+    transaction_rule_id: i32,
+) -> Result<(), AppError> {
+    TransactionRuleTags::delete_many()
+        .filter(transaction_rule_tags::Column::TransactionRuleId.eq(transaction_rule_id))
+        .exec(connection)
+        .await?;
+    Ok(())
+}
+
+pub async fn find_all_with_active_tag_by_transaction_rule_ids(
+    connection: &impl ConnectionTrait,
+    transaction_rule_ids: Vec<i32>,
+) -> Result<Vec<transaction_rule_tags::Model>, AppError> {
+    let found_transaction_rule_tags = TransactionRuleTags::find()
+        .join(
+            JoinType::InnerJoin,
+            transaction_rule_tags::Relation::Tags.def(),
+        )
+        .filter(transaction_rule_tags::Column::TransactionRuleId.is_in(transaction_rule_ids))
+        .filter(tags::Column::DeletedAt.is_null())
+        .all(connection)
+        .await?;
+    Ok(found_transaction_rule_tags)
+}
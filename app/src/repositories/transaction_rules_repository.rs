@@ -0,0 +1,68 @@
+use crate::entities::prelude::TransactionRules;
+use crate::entities::transaction_rules;
+use crate::errors::AppError;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder,
+    TryIntoModel,
+};
+
+pub async fn save(
+    connection: &impl ConnectionTrait,
+    transaction_rule: transaction_rules::ActiveModel,
+) -> Result<transaction_rules::Model, AppError> {
+    let transaction_rule = transaction_rule.save(connection).await?.try_into_model()?;
+    Ok(transaction_rule)
+}
+
+pub async fn find_all_active_by_user_id_order_by_priority_asc(
+    connection: &impl ConnectionTrait,
+    user_id: i32,
+) -> Result<Vec<transaction_rules::Model>, AppError> {
+    let found_transaction_rules = TransactionRules::find()
+        .filter(transaction_rules::Column::UserId.eq(user_id))
+        .filter(transaction_rules::Column::DeletedAt.is_null())
+        .order_by_asc(transaction_rules::Column::Priority)
+        .order_by_asc(transaction_rules::Column::Id)
+        .all(connection)
+        .await?;
+    Ok(found_transaction_rules)
+}
+
+pub async fn get_active_by_id_and_user_id(
+    connection: &impl ConnectionTrait,
+    transaction_rule_id: i32,
+    user_id: i32,
+) -> Result<Option<transaction_rules::Model>, AppError> {
+    let found_transaction_rule = TransactionRules::find_by_id(transaction_rule_id)
+        .filter(transaction_rules::Column::UserId.eq(user_id))
+        .filter(transaction_rules::Column::DeletedAt.is_null())
+        .one(connection)
+        .await?;
+    Ok(found_transaction_rule)
+}
+
+pub async fn find_all_deleted_by_user_id_order_by_deleted_at_desc(
+    connection: &impl ConnectionTrait,
+    user_id: i32,
+) -> Result<Vec<transaction_rules::Model>, AppError> {
+    let found_transaction_rules = TransactionRules::find()
+        .filter(transaction_rules::Column::UserId.eq(user_id))
+        .filter(transaction_rules::Column::DeletedAt.is_not_null())
+        .order_by_desc(transaction_rules::Column::DeletedAt)
+        .all(connection)
+        .await?;
+    Ok(found_transaction_rules)
+}
+
+pub async fn get_deleted_by_id_and_user_id(
+    connection: &impl ConnectionTrait,
+    transaction_rule_id: i32,
+    user_id: i32,
+) -> Result<Option<transaction_rules::Model>, AppError> {
+    let found_transaction_rule = TransactionRules::find_by_id(transaction_rule_id)
+        .filter(transaction_rules::Column::UserId.eq(user_id))
+        .filter(transaction_rules::Column::DeletedAt.is_not_null())
+        .one(connection)
+        .await?;
+    Ok(found_transaction_rule)
+}
@@ -0,0 +1,53 @@
+use crate::entities::prelude::TransactionTags;
+use crate::entities::{tags, transaction_tags};
+use crate::errors::AppError;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, EntityTrait, JoinType, QueryFilter, QuerySelect, RelationTrait,
+};
+
+pub async fn insert_many_ignore_existing(
+    connection: &impl ConnectionTrait,
+    transaction_tags: Vec<transaction_tags::ActiveModel>,
+) -> Result<u64, AppError> {
+    if transaction_tags.is_empty() {
+        return Ok(0);
+    }
+
+    let inserted_rows = TransactionTags::insert_many(transaction_tags)
+        .on_conflict(
+            OnConflict::columns([
+                transaction_tags::Column::TransactionId,
+                transaction_tags::Column::TagId,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .exec_without_returning(connection)
+        .await?;
+    Ok(inserted_rows)
+}
+
+pub async fn delete_all_by_transaction_id(
+    connection: &impl ConnectionTrait,
+    transaction_id: i32,
+) -> Result<(), AppError> {
+    TransactionTags::delete_many()
+        .filter(transaction_tags::Column::TransactionId.eq(transaction_id))
+        .exec(connection)
+        .await?;
+    Ok(())
+}
+
+pub async fn find_all_with_active_tag_by_transaction_ids(
+    connection: &impl ConnectionTrait,
+    transaction_ids: Vec<i32>,
+) -> Result<Vec<transaction_tags::Model>, AppError> {
+    let found_transaction_tags = TransactionTags::find()
+        .join(JoinType::InnerJoin, transaction_tags::Relation::Tags.def())
+        .filter(transaction_tags::Column::TransactionId.is_in(transaction_ids))
+        .filter(tags::Column::DeletedAt.is_null())
+        .all(connection)
+        .await?;
+    Ok(found_transaction_tags)
+}
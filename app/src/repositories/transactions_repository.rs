@@ -1,10 +1,24 @@
+use crate::constants::transaction_constants::METERS_PER_DEGREE;
+use crate::dto::request::transactions_dto::{
+    LocationArea, ValidatedFindAllTransactionsParams, ValidatedSearchTransactionsParams,
+};
+use crate::dto::response::transactions_dto::{
+    ExportTransactionRow, PlaceTotalRow, SearchTransactionRow,
+};
+use crate::entities::prelude::TransactionTags;
 use crate::entities::prelude::Transactions;
-use crate::entities::transactions;
+use crate::entities::sea_orm_active_enums::{TransactionStatus, TransactionType};
+use crate::entities::{budgets, categories, transaction_tags, transactions, wallets};
 use crate::errors::AppError;
+use futures_util::Stream;
+use rust_decimal::Decimal;
+use sea_orm::sea_query::{Expr, Order, Query};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder,
-    TryIntoModel,
+    ActiveEnum, ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait,
+    ItemsAndPagesNumber, JoinType, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
+    RelationTrait, Select, StreamTrait, TryIntoModel,
 };
+use time::{Date, OffsetDateTime};
 
 pub async fn save(
     connection: &impl ConnectionTrait,
@@ -14,28 +28,677 @@ pub async fn save(
     Ok(transaction)
 }
 
+fn find_all_active_by_user_id_and_params(
+    user_id: i32,
+    params: ValidatedFindAllTransactionsParams,
+) -> Select<Transactions> {
+    let mut found_transactions_builder = Transactions::find()
+        .filter(transactions::Column::DeletedAt.is_null())
+        .filter(transactions::Column::UserId.eq(user_id));
+
+    if let Some(start_date) = params.start_date {
+        found_transactions_builder =
+            found_transactions_builder.filter(transactions::Column::IssuedAt.gte(start_date));
+    }
+    if let Some(end_date) = params.end_date {
+        found_transactions_builder =
+            found_transactions_builder.filter(transactions::Column::IssuedAt.lte(end_date));
+    }
+    if let Some(category_id) = params.category_id {
+        found_transactions_builder =
+            found_transactions_builder.filter(transactions::Column::CategoryId.eq(category_id));
+    }
+    if let Some(wallet_id) = params.wallet_id {
+        found_transactions_builder =
+            found_transactions_builder.filter(transactions::Column::WalletId.eq(wallet_id));
+    }
+    if let Some(budget_id) = params.budget_id {
+        found_transactions_builder =
+            found_transactions_builder.filter(transactions::Column::BudgetId.eq(budget_id));
+    }
+    if let Some(payee_id) = params.payee_id {
+        found_transactions_builder =
+            found_transactions_builder.filter(transactions::Column::PayeeId.eq(payee_id));
+    }
+    if let Some(flow_direction) = params.flow_direction {
+        found_transactions_builder = found_transactions_builder
+            .filter(transactions::Column::FlowDirection.eq(flow_direction));
+    }
+    if let Some(status) = params.status {
+        found_transactions_builder =
+            found_transactions_builder.filter(transactions::Column::Status.eq(status));
+    }
+    if let Some(min_amount) = params.min_amount {
+        found_transactions_builder =
+            found_transactions_builder.filter(transactions::Column::Amount.gte(min_amount));
+    }
+    if let Some(max_amount) = params.max_amount {
+        found_transactions_builder =
+            found_transactions_builder.filter(transactions::Column::Amount.lte(max_amount));
+    }
+    if let Some(scheduled) = params.scheduled {
+        found_transactions_builder = if scheduled {
+            found_transactions_builder.filter(transactions::Column::PostedAt.is_null())
+        } else {
+            found_transactions_builder.filter(transactions::Column::PostedAt.is_not_null())
+        };
+    }
+    if let Some(tag_ids) = params.tag_ids {
+        let tag_count = tag_ids.len() as i64;
+        let mut tagged_transactions = Query::select();
+        tagged_transactions
+            .column(transaction_tags::Column::TransactionId)
+            .from(TransactionTags)
+            .and_where(transaction_tags::Column::TagId.is_in(tag_ids));
+        if params.match_all_tags {
+            tagged_transactions
+                .group_by_col(transaction_tags::Column::TransactionId)
+                .and_having(
+                    Expr::col(transaction_tags::Column::TagId)
+                        .count_distinct()
+                        .eq(tag_count),
+                );
+        }
+
+        found_transactions_builder = found_transactions_builder
+            .filter(transactions::Column::Id.in_subquery(tagged_transactions.to_owned()));
+    }
+
+    found_transactions_builder
+}
+
+fn filter_by_location_area(
+    found_transactions_builder: Select<Transactions>,
+    location_area: LocationArea,
+) -> Select<Transactions> {
+    match location_area {
+        LocationArea::Radius {
+            latitude,
+            longitude,
+            radius,
+        } => {
+            // cheap box around the circle first so the index can be used, then the exact distance
+            let latitude_delta = radius / METERS_PER_DEGREE;
+            let longitude_delta =
+                radius / (METERS_PER_DEGREE * latitude.to_radians().cos().max(0.01));
+            found_transactions_builder
+                .filter(Expr::cust_with_values(
+                    "transactions.latitude BETWEEN $1 AND $2",
+                    [latitude - latitude_delta, latitude + latitude_delta],
+                ))
+                .filter(Expr::cust_with_values(
+                    "transactions.longitude BETWEEN $1 AND $2",
+                    [longitude - longitude_delta, longitude + longitude_delta],
+                ))
+                .filter(Expr::cust_with_values(
+                    "6371000 * 2 * asin(least(1, sqrt(power(sin(radians(transactions.latitude - $1) / 2), 2) + cos(radians($1)) * cos(radians(transactions.latitude)) * power(sin(radians(transactions.longitude - $2) / 2), 2)))) <= $3",
+                    [latitude, longitude, radius],
+                ))
+        }
+        LocationArea::BoundingBox {
+            min_latitude,
+            min_longitude,
+            max_latitude,
+            max_longitude,
+        } => {
+            let found_transactions_builder =
+                found_transactions_builder.filter(Expr::cust_with_values(
+                    "transactions.latitude BETWEEN $1 AND $2",
+                    [min_latitude, max_latitude],
+                ));
+            if min_longitude <= max_longitude {
+                found_transactions_builder.filter(Expr::cust_with_values(
+                    "transactions.longitude BETWEEN $1 AND $2",
+                    [min_longitude, max_longitude],
+                ))
+            } else {
+                found_transactions_builder.filter(Expr::cust_with_values(
+                    "(transactions.longitude >= $1 OR transactions.longitude <= $2)",
+                    [min_longitude, max_longitude],
+                ))
+            }
+        }
+    }
+}
+
+pub async fn find_all_active_by_user_id_and_location_area_order_by_issued_at_and_created_at_desc(
+    connection: &impl ConnectionTrait,
+    user_id: i32,
+    params: ValidatedFindAllTransactionsParams,
+    location_area: LocationArea,
+) -> Result<Vec<transactions::Model>, AppError> {
+    let found_transactions = filter_by_location_area(
+        find_all_active_by_user_id_and_params(user_id, params),
+        location_area,
+    )
+    .order_by_desc(transactions::Column::IssuedAt)
+    .order_by_desc(transactions::Column::CreatedAt)
+    .all(connection)
+    .await?;
+    Ok(found_transactions)
+}
+
+pub async fn find_place_totals_by_user_id_and_params(
+    connection: &impl ConnectionTrait,
+    user_id: i32,
+    params: ValidatedFindAllTransactionsParams,
+    location_area: Option<LocationArea>,
+) -> Result<Vec<PlaceTotalRow>, AppError> {
+    // wallets excluded from totals are left out of every aggregate
+    let mut found_totals_builder = find_all_active_by_user_id_and_params(user_id, params)
+        .join(JoinType::InnerJoin, transactions::Relation::Wallets.def())
+        .filter(wallets::Column::ExcludeFromTotals.eq(false))
+        .filter(transactions::Column::PlaceName.is_not_null());
+    if let Some(location_area) = location_area {
+        found_totals_builder = filter_by_location_area(found_totals_builder, location_area);
+    }
+
+    let total_outcome = || {
+        Expr::cust(
+            "SUM(CASE WHEN transactions.flow_direction = 'OUTCOME' THEN transactions.amount ELSE 0 END)",
+        )
+    };
+    let found_totals = found_totals_builder
+        .select_only()
+        .column(transactions::Column::PlaceName)
+        .column(transactions::Column::Currency)
+        .column_as(
+            Expr::cust(
+                "SUM(CASE WHEN transactions.flow_direction = 'INCOME' THEN transactions.amount ELSE 0 END)",
+            ),
+            "total_income",
+        )
+        .column_as(total_outcome(), "total_outcome")
+        .column_as(transactions::Column::Id.count(), "transaction_count")
+        .column_as(
+            Expr::cust("round(avg(transactions.latitude), 6)"),
+            "latitude",
+        )
+        .column_as(
+            Expr::cust("round(avg(transactions.longitude), 6)"),
+            "longitude",
+        )
+        .group_by(transactions::Column::PlaceName)
+        .group_by(transactions::Column::Currency)
+        .order_by(total_outcome(), Order::Desc)
+        .order_by_asc(transactions::Column::PlaceName)
+        .order_by_asc(transactions::Column::Currency)
+        .into_model::<PlaceTotalRow>()
+        .all(connection)
+        .await?;
+    Ok(found_totals)
+}
+
 pub async fn find_all_active_by_user_id_order_by_issued_at_and_created_at_desc(
     connection: &impl ConnectionTrait,
     user_id: i32,
+    params: ValidatedFindAllTransactionsParams,
+) -> Result<Vec<transactions::Model>, AppError> {
+    let found_transactions = find_all_active_by_user_id_and_params(user_id, params)
+        .order_by_desc(transactions::Column::IssuedAt)
+        .order_by_desc(transactions::Column::CreatedAt)
+        .all(connection)
+        .await?;
+    Ok(found_transactions)
+}
+
+pub async fn search_active_by_user_id_and_params_order_by_rank_desc(
+    connection: &impl ConnectionTrait,
+    user_id: i32,
+    params: ValidatedFindAllTransactionsParams,
+    search_params: ValidatedSearchTransactionsParams,
+) -> Result<(Vec<SearchTransactionRow>, ItemsAndPagesNumber), AppError> {
+    let search_query = || {
+        Expr::cust_with_values(
+            "websearch_to_tsquery('simple', $1)",
+            [search_params.query.clone()],
+        )
+    };
+
+    let found_rows_builder = find_all_active_by_user_id_and_params(user_id, params)
+        .select_only()
+        .column(transactions::Column::Id)
+        .column_as(
+            Expr::cust_with_exprs(
+                "ts_rank(transactions.search_vector, $1)",
+                [search_query()],
+            ),
+            "rank",
+        )
+        // the text is HTML escaped before the <mark> tags go in so the highlight is safe to render
+        // as is, the 'simple' config matches whole words only without any stemming
+        .column_as(
+            Expr::cust_with_exprs(
+                r#"ts_headline('simple', replace(replace(replace(replace(replace(concat_ws(' | ', transactions.description, categories.name, payees.name), '&', '&amp;'), '<', '&lt;'), '>', '&gt;'), '"', '&quot;'), '''', '&#39;'), $1, 'StartSel=<mark>, StopSel=</mark>, MaxFragments=3')"#,
+                [search_query()],
+            ),
+            "highlight",
+        )
+        .join(
+            JoinType::InnerJoin,
+            transactions::Relation::Categories.def(),
+        )
+        .join(JoinType::LeftJoin, transactions::Relation::Payees.def())
+        .filter(Expr::cust_with_exprs(
+            "transactions.search_vector @@ $1",
+            [search_query()],
+        ))
+        .order_by(
+            Expr::cust_with_exprs(
+                "ts_rank(transactions.search_vector, $1)",
+                [search_query()],
+            ),
+            Order::Desc,
+        )
+        .order_by_desc(transactions::Column::IssuedAt)
+        .order_by_desc(transactions::Column::Id)
+        .into_model::<SearchTransactionRow>();
+
+    let paginator = found_rows_builder.paginate(connection, search_params.page_size);
+    let found_rows = paginator.fetch_page(search_params.page - 1).await?;
+    let page_information = paginator.num_items_and_pages().await?;
+
+    Ok((found_rows, page_information))
+}
+
+pub async fn find_all_by_ids(
+    connection: &impl ConnectionTrait,
+    transaction_ids: Vec<i32>,
 ) -> Result<Vec<transactions::Model>, AppError> {
     let found_transactions = Transactions::find()
-        .filter(transactions::Column::DeletedAt.is_null())
-        .filter(transactions::Column::UserId.eq(user_id))
+        .filter(transactions::Column::Id.is_in(transaction_ids))
+        .all(connection)
+        .await?;
+    Ok(found_transactions)
+}
+
+pub async fn stream_all_active_export_rows_by_user_id<'a, C>(
+    connection: &'a C,
+    user_id: i32,
+    params: ValidatedFindAllTransactionsParams,
+) -> Result<impl Stream<Item = Result<ExportTransactionRow, DbErr>> + Send + 'a, AppError>
+where
+    C: ConnectionTrait + StreamTrait + Send,
+{
+    let found_rows = find_all_active_by_user_id_and_params(user_id, params)
         .order_by_desc(transactions::Column::IssuedAt)
         .order_by_desc(transactions::Column::CreatedAt)
+        .select_only()
+        .column(transactions::Column::Id)
+        .column(transactions::Column::IssuedAt)
+        .column(transactions::Column::FlowDirection)
+        .column(transactions::Column::Amount)
+        .column(transactions::Column::Currency)
+        .column(transactions::Column::Description)
+        .column_as(categories::Column::Name, "category_name")
+        .column_as(wallets::Column::Name, "wallet_name")
+        .column_as(budgets::Column::Name, "budget_name")
+        .join(
+            JoinType::InnerJoin,
+            transactions::Relation::Categories.def(),
+        )
+        .join(JoinType::InnerJoin, transactions::Relation::Wallets.def())
+        .join(JoinType::LeftJoin, transactions::Relation::Budgets.def())
+        .into_model::<ExportTransactionRow>()
+        .stream(connection)
+        .await?;
+    Ok(found_rows)
+}
+
+pub async fn get_by_id(
+    connection: &impl ConnectionTrait,
+    transaction_id: i32,
+) -> Result<Option<transactions::Model>, AppError> {
+    let found_transaction = Transactions::find_by_id(transaction_id)
+        .one(connection)
+        .await?;
+    Ok(found_transaction)
+}
+
+pub async fn get_active_by_id(
+    connection: &impl ConnectionTrait,
+    transaction_id: i32,
+) -> Result<Option<transactions::Model>, AppError> {
+    let found_transaction = Transactions::find_by_id(transaction_id)
+        .filter(transactions::Column::DeletedAt.is_null())
+        .one(connection)
+        .await?;
+    Ok(found_transaction)
+}
+
+pub async fn find_all_active_by_user_id_and_payee_id_is_null(
+    connection: &impl ConnectionTrait,
+    user_id: i32,
+) -> Result<Vec<transactions::Model>, AppError> {
+    let found_transactions = Transactions::find()
+        .filter(transactions::Column::DeletedAt.is_null())
+        .filter(transactions::Column::UserId.eq(user_id))
+        .filter(transactions::Column::PayeeId.is_null())
+        .all(connection)
+        .await?;
+    Ok(found_transactions)
+}
+
+pub async fn find_all_active_unreconciled_by_wallet_id_and_issued_at_lte(
+    connection: &impl ConnectionTrait,
+    wallet_id: i32,
+    issued_at: Date,
+) -> Result<Vec<transactions::Model>, AppError> {
+    let found_transactions = Transactions::find()
+        .filter(transactions::Column::DeletedAt.is_null())
+        .filter(transactions::Column::WalletId.eq(wallet_id))
+        .filter(transactions::Column::IssuedAt.lte(issued_at))
+        .filter(transactions::Column::Status.ne(TransactionStatus::Reconciled))
+        .order_by_asc(transactions::Column::IssuedAt)
+        .order_by_asc(transactions::Column::CreatedAt)
+        .all(connection)
+        .await?;
+    Ok(found_transactions)
+}
+
+pub async fn find_amount_sums_by_wallet_id_and_issued_at_lte(
+    connection: &impl ConnectionTrait,
+    wallet_id: i32,
+    issued_at: Date,
+) -> Result<Vec<(TransactionType, Decimal)>, AppError> {
+    let found_amount_sums = Transactions::find()
+        .select_only()
+        .column(transactions::Column::FlowDirection)
+        .column_as(transactions::Column::Amount.sum(), "total_amount")
+        .filter(transactions::Column::DeletedAt.is_null())
+        .filter(transactions::Column::WalletId.eq(wallet_id))
+        .filter(transactions::Column::IssuedAt.lte(issued_at))
+        .group_by(transactions::Column::FlowDirection)
+        .into_tuple::<(TransactionType, Decimal)>()
+        .all(connection)
+        .await?;
+    Ok(found_amount_sums)
+}
+
+pub async fn find_amount_sums_by_wallet_id_and_statuses_and_issued_at_lte(
+    connection: &impl ConnectionTrait,
+    wallet_id: i32,
+    statuses: Vec<TransactionStatus>,
+    issued_at: Date,
+) -> Result<Vec<(TransactionType, Decimal)>, AppError> {
+    let found_amount_sums = Transactions::find()
+        .select_only()
+        .column(transactions::Column::FlowDirection)
+        .column_as(transactions::Column::Amount.sum(), "total_amount")
+        .filter(transactions::Column::DeletedAt.is_null())
+        .filter(transactions::Column::WalletId.eq(wallet_id))
+        .filter(transactions::Column::IssuedAt.lte(issued_at))
+        .filter(transactions::Column::Status.is_in(statuses))
+        .group_by(transactions::Column::FlowDirection)
+        .into_tuple::<(TransactionType, Decimal)>()
+        .all(connection)
+        .await?;
+    Ok(found_amount_sums)
+}
+
+// transfers are split out so payments into a wallet can be told apart from other income
+pub async fn find_posted_amount_sums_by_wallet_id_group_by_issued_at(
+    connection: &impl ConnectionTrait,
+    wallet_id: i32,
+) -> Result<Vec<(Date, TransactionType, bool, Decimal)>, AppError> {
+    let is_transfer = || Expr::cust("transactions.transfer_transaction_id IS NOT NULL");
+    let found_amount_sums = Transactions::find()
+        .select_only()
+        .column(transactions::Column::IssuedAt)
+        .column(transactions::Column::FlowDirection)
+        .column_as(is_transfer(), "is_transfer")
+        .column_as(transactions::Column::Amount.sum(), "total_amount")
+        .filter(transactions::Column::DeletedAt.is_null())
+        .filter(transactions::Column::PostedAt.is_not_null())
+        .filter(transactions::Column::WalletId.eq(wallet_id))
+        .group_by(transactions::Column::IssuedAt)
+        .group_by(transactions::Column::FlowDirection)
+        .group_by(is_transfer())
+        .order_by_asc(transactions::Column::IssuedAt)
+        .into_tuple::<(Date, TransactionType, bool, Decimal)>()
+        .all(connection)
+        .await?;
+    Ok(found_amount_sums)
+}
+
+pub async fn find_all_active_cleared_by_wallet_id_and_issued_at_lte_for_update(
+    connection: &impl ConnectionTrait,
+    wallet_id: i32,
+    issued_at: Date,
+) -> Result<Vec<transactions::Model>, AppError> {
+    let found_transactions = Transactions::find()
+        .filter(transactions::Column::DeletedAt.is_null())
+        .filter(transactions::Column::WalletId.eq(wallet_id))
+        .filter(transactions::Column::IssuedAt.lte(issued_at))
+        .filter(transactions::Column::Status.eq(TransactionStatus::Cleared))
+        .order_by_asc(transactions::Column::Id)
+        .lock_exclusive()
+        .all(connection)
+        .await?;
+    Ok(found_transactions)
+}
+
+pub async fn update_all_cleared_by_wallet_id_and_issued_at_lte_set_reconciled(
+    connection: &impl ConnectionTrait,
+    wallet_id: i32,
+    issued_at: Date,
+    reconciliation_id: i32,
+) -> Result<u64, AppError> {
+    let updated_transactions = Transactions::update_many()
+        .col_expr(
+            transactions::Column::Status,
+            TransactionStatus::Reconciled.as_enum(),
+        )
+        .col_expr(
+            transactions::Column::ReconciliationId,
+            Expr::value(reconciliation_id),
+        )
+        .col_expr(
+            transactions::Column::UpdatedAt,
+            Expr::value(OffsetDateTime::now_utc()),
+        )
+        .filter(transactions::Column::DeletedAt.is_null())
+        .filter(transactions::Column::WalletId.eq(wallet_id))
+        .filter(transactions::Column::IssuedAt.lte(issued_at))
+        .filter(transactions::Column::Status.eq(TransactionStatus::Cleared))
+        .exec(connection)
+        .await?;
+    Ok(updated_transactions.rows_affected)
+}
+
+pub async fn find_all_active_by_installment_plan_ids_order_by_issued_at_asc(
+    connection: &impl ConnectionTrait,
+    installment_plan_ids: Vec<i32>,
+) -> Result<Vec<transactions::Model>, AppError> {
+    let found_transactions = Transactions::find()
+        .filter(transactions::Column::DeletedAt.is_null())
+        .filter(transactions::Column::InstallmentPlanId.is_in(installment_plan_ids))
+        .order_by_asc(transactions::Column::IssuedAt)
+        .order_by_asc(transactions::Column::Id)
+        .all(connection)
+        .await?;
+    Ok(found_transactions)
+}
+
+pub async fn find_all_deleted_by_installment_plan_ids_order_by_issued_at_asc(
+    connection: &impl ConnectionTrait,
+    installment_plan_ids: Vec<i32>,
+) -> Result<Vec<transactions::Model>, AppError> {
+    let found_transactions = Transactions::find()
+        .filter(transactions::Column::DeletedAt.is_not_null())
+        .filter(transactions::Column::InstallmentPlanId.is_in(installment_plan_ids))
+        .order_by_asc(transactions::Column::IssuedAt)
+        .order_by_asc(transactions::Column::Id)
+        .all(connection)
+        .await?;
+    Ok(found_transactions)
+}
+
+pub async fn find_all_active_by_refunded_transaction_id_order_by_issued_at_asc(
+    connection: &impl ConnectionTrait,
+    refunded_transaction_id: i32,
+) -> Result<Vec<transactions::Model>, AppError> {
+    let found_transactions = Transactions::find()
+        .filter(transactions::Column::DeletedAt.is_null())
+        .filter(transactions::Column::RefundedTransactionId.eq(refunded_transaction_id))
+        .order_by_asc(transactions::Column::IssuedAt)
+        .order_by_asc(transactions::Column::Id)
+        .all(connection)
+        .await?;
+    Ok(found_transactions)
+}
+
+pub async fn find_all_active_unposted_by_issued_at_lte_order_by_issued_at_asc(
+    connection: &impl ConnectionTrait,
+    issued_at: Date,
+) -> Result<Vec<transactions::Model>, AppError> {
+    let found_transactions = Transactions::find()
+        .filter(transactions::Column::DeletedAt.is_null())
+        .filter(transactions::Column::PostedAt.is_null())
+        .filter(transactions::Column::IssuedAt.lte(issued_at))
+        .order_by_asc(transactions::Column::IssuedAt)
+        .order_by_asc(transactions::Column::Id)
+        .all(connection)
+        .await?;
+    Ok(found_transactions)
+}
+
+pub async fn get_active_by_id_for_update(
+    connection: &impl ConnectionTrait,
+    transaction_id: i32,
+) -> Result<Option<transactions::Model>, AppError> {
+    let found_transaction = Transactions::find_by_id(transaction_id)
+        .filter(transactions::Column::DeletedAt.is_null())
+        .lock_exclusive()
+        .one(connection)
+        .await?;
+    Ok(found_transaction)
+}
+
+pub async fn get_active_unposted_by_id_for_update(
+    connection: &impl ConnectionTrait,
+    transaction_id: i32,
+) -> Result<Option<transactions::Model>, AppError> {
+    let found_transaction = Transactions::find_by_id(transaction_id)
+        .filter(transactions::Column::DeletedAt.is_null())
+        .filter(transactions::Column::PostedAt.is_null())
+        .lock_exclusive()
+        .one(connection)
+        .await?;
+    Ok(found_transaction)
+}
+
+pub async fn find_unposted_amount_sums_by_wallet_ids(
+    connection: &impl ConnectionTrait,
+    wallet_ids: Vec<i32>,
+) -> Result<Vec<(i32, TransactionType, Decimal)>, AppError> {
+    let found_amount_sums = Transactions::find()
+        .select_only()
+        .column(transactions::Column::WalletId)
+        .column(transactions::Column::FlowDirection)
+        .column_as(transactions::Column::Amount.sum(), "total_amount")
+        .filter(transactions::Column::DeletedAt.is_null())
+        .filter(transactions::Column::PostedAt.is_null())
+        .filter(transactions::Column::WalletId.is_in(wallet_ids))
+        .group_by(transactions::Column::WalletId)
+        .group_by(transactions::Column::FlowDirection)
+        .into_tuple::<(i32, TransactionType, Decimal)>()
+        .all(connection)
+        .await?;
+    Ok(found_amount_sums)
+}
+
+pub async fn find_posted_amount_sums_group_by_wallet_id(
+    connection: &impl ConnectionTrait,
+) -> Result<Vec<(i32, TransactionType, Decimal)>, AppError> {
+    let found_amount_sums = Transactions::find()
+        .select_only()
+        .column(transactions::Column::WalletId)
+        .column(transactions::Column::FlowDirection)
+        .column_as(transactions::Column::Amount.sum(), "total_amount")
+        .filter(transactions::Column::DeletedAt.is_null())
+        .filter(transactions::Column::PostedAt.is_not_null())
+        .group_by(transactions::Column::WalletId)
+        .group_by(transactions::Column::FlowDirection)
+        .into_tuple::<(i32, TransactionType, Decimal)>()
+        .all(connection)
+        .await?;
+    Ok(found_amount_sums)
+}
+
+pub async fn find_posted_amount_sums_group_by_wallet_id_and_issued_at(
+    connection: &impl ConnectionTrait,
+) -> Result<Vec<(i32, Date, Decimal)>, AppError> {
+    let found_amount_sums = Transactions::find()
+        .select_only()
+        .column(transactions::Column::WalletId)
+        .column(transactions::Column::IssuedAt)
+        .column_as(
+            Expr::cust(
+                "SUM(CASE \
+                    WHEN transactions.flow_direction = 'INCOME' THEN transactions.amount \
+                    ELSE -transactions.amount END)",
+            ),
+            "total_amount",
+        )
+        .filter(transactions::Column::DeletedAt.is_null())
+        .filter(transactions::Column::PostedAt.is_not_null())
+        .group_by(transactions::Column::WalletId)
+        .group_by(transactions::Column::IssuedAt)
+        .into_tuple::<(i32, Date, Decimal)>()
+        .all(connection)
+        .await?;
+    Ok(found_amount_sums)
+}
+
+// mirrors transactions_service::budget_effect, spending adds up and refunds of it give back
+pub async fn find_posted_budget_amount_sums_group_by_budget_id(
+    connection: &impl ConnectionTrait,
+) -> Result<Vec<(i32, Decimal)>, AppError> {
+    let found_amount_sums = Transactions::find()
+        .select_only()
+        .column(transactions::Column::BudgetId)
+        .column_as(
+            Expr::cust(
+                "SUM(CASE \
+                    WHEN transactions.flow_direction = 'OUTCOME' THEN transactions.amount \
+                    WHEN transactions.refunded_transaction_id IS NOT NULL THEN -transactions.amount \
+                    ELSE 0 END)",
+            ),
+            "total_amount",
+        )
+        .filter(transactions::Column::DeletedAt.is_null())
+        .filter(transactions::Column::PostedAt.is_not_null())
+        .filter(transactions::Column::BudgetId.is_not_null())
+        .filter(transactions::Column::TransferTransactionId.is_null())
+        .group_by(transactions::Column::BudgetId)
+        .into_tuple::<(i32, Decimal)>()
+        .all(connection)
+        .await?;
+    Ok(found_amount_sums)
+}
+
+pub async fn find_all_deleted_by_user_id_order_by_deleted_at_desc(
+    connection: &impl ConnectionTrait,
+    user_id: i32,
+) -> Result<Vec<transactions::Model>, AppError> {
+    let found_transactions = Transactions::find()
+        .filter(transactions::Column::UserId.eq(user_id))
+        .filter(transactions::Column::DeletedAt.is_not_null())
+        .order_by_desc(transactions::Column::DeletedAt)
         .all(connection)
         .await?;
     Ok(found_transactions)
 }
 
-pub async fn get_active_by_id_and_user_id(
+pub async fn get_deleted_by_id_and_user_id(
     connection: &impl ConnectionTrait,
     transaction_id: i32,
     user_id: i32,
 ) -> Result<Option<transactions::Model>, AppError> {
     let found_transaction = Transactions::find_by_id(transaction_id)
         .filter(transactions::Column::UserId.eq(user_id))
-        .filter(transactions::Column::DeletedAt.is_null())
+        .filter(transactions::Column::DeletedAt.is_not_null())
         .one(connection)
         .await?;
     Ok(found_transaction)
@@ -2,8 +2,8 @@ use crate::entities::prelude::Users;
 use crate::entities::{roles, users};
 use crate::errors::AppError;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, DatabaseTransaction, EntityTrait,
-    QueryFilter,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DatabaseTransaction,
+    EntityTrait, QueryFilter,
 };
 
 pub async fn create_manual(
@@ -42,6 +42,52 @@ pub async fn find_by_pk(
     Ok(get_first_user(found_users))
 }
 
+pub async fn get_active_by_username(
+    connection: &impl ConnectionTrait,
+    username: &str,
+) -> Result<Option<users::Model>, AppError> {
+    let found_user = Users::find()
+        .filter(users::Column::Username.eq(username))
+        .filter(users::Column::DeletedAt.is_null())
+        .one(connection)
+        .await?;
+    Ok(found_user)
+}
+
+pub async fn get_active_by_email(
+    connection: &impl ConnectionTrait,
+    email: &str,
+) -> Result<Option<users::Model>, AppError> {
+    let found_user = Users::find()
+        .filter(users::Column::Email.eq(email))
+        .filter(users::Column::DeletedAt.is_null())
+        .one(connection)
+        .await?;
+    Ok(found_user)
+}
+
+pub async fn get_active_by_id(
+    connection: &impl ConnectionTrait,
+    user_id: i32,
+) -> Result<Option<users::Model>, AppError> {
+    let found_user = Users::find_by_id(user_id)
+        .filter(users::Column::DeletedAt.is_null())
+        .one(connection)
+        .await?;
+    Ok(found_user)
+}
+
+pub async fn find_all_by_ids(
+    connection: &impl ConnectionTrait,
+    user_ids: Vec<i32>,
+) -> Result<Vec<users::Model>, AppError> {
+    let found_users = Users::find()
+        .filter(users::Column::Id.is_in(user_ids))
+        .all(connection)
+        .await?;
+    Ok(found_users)
+}
+
 fn get_first_user(
     found_users: Vec<(users::Model, Vec<roles::Model>)>,
 ) -> Option<(users::Model, Vec<roles::Model>)> {
@@ -0,0 +1,127 @@
+use crate::entities::prelude::WalletBalanceSnapshots;
+use crate::entities::wallet_balance_snapshots;
+use crate::errors::AppError;
+use rust_decimal::Decimal;
+use sea_orm::sea_query::{Expr, OnConflict};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder};
+use time::{Date, OffsetDateTime};
+
+pub async fn insert_if_absent(
+    connection: &impl ConnectionTrait,
+    wallet_balance_snapshot: wallet_balance_snapshots::ActiveModel,
+) -> Result<u64, AppError> {
+    let inserted_rows = WalletBalanceSnapshots::insert(wallet_balance_snapshot)
+        .on_conflict(
+            OnConflict::columns([
+                wallet_balance_snapshots::Column::WalletId,
+                wallet_balance_snapshots::Column::SnapshotDate,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .exec_without_returning(connection)
+        .await?;
+    Ok(inserted_rows)
+}
+
+pub async fn insert_many(
+    connection: &impl ConnectionTrait,
+    wallet_balance_snapshots: Vec<wallet_balance_snapshots::ActiveModel>,
+) -> Result<(), AppError> {
+    if wallet_balance_snapshots.is_empty() {
+        return Ok(());
+    }
+
+    WalletBalanceSnapshots::insert_many(wallet_balance_snapshots)
+        .exec_without_returning(connection)
+        .await?;
+    Ok(())
+}
+
+pub async fn delete_all_by_wallet_id(
+    connection: &impl ConnectionTrait,
+    wallet_id: i32,
+) -> Result<(), AppError> {
+    WalletBalanceSnapshots::delete_many()
+        .filter(wallet_balance_snapshots::Column::WalletId.eq(wallet_id))
+        .exec(connection)
+        .await?;
+    Ok(())
+}
+
+pub async fn find_all_order_by_wallet_id_asc_and_snapshot_date_asc(
+    connection: &impl ConnectionTrait,
+) -> Result<Vec<wallet_balance_snapshots::Model>, AppError> {
+    let found_wallet_balance_snapshots = WalletBalanceSnapshots::find()
+        .order_by_asc(wallet_balance_snapshots::Column::WalletId)
+        .order_by_asc(wallet_balance_snapshots::Column::SnapshotDate)
+        .all(connection)
+        .await?;
+    Ok(found_wallet_balance_snapshots)
+}
+
+pub async fn get_latest_by_wallet_id_and_snapshot_date_lt(
+    connection: &impl ConnectionTrait,
+    wallet_id: i32,
+    snapshot_date: Date,
+) -> Result<Option<wallet_balance_snapshots::Model>, AppError> {
+    let found_wallet_balance_snapshot = WalletBalanceSnapshots::find()
+        .filter(wallet_balance_snapshots::Column::WalletId.eq(wallet_id))
+        .filter(wallet_balance_snapshots::Column::SnapshotDate.lt(snapshot_date))
+        .order_by_desc(wallet_balance_snapshots::Column::SnapshotDate)
+        .one(connection)
+        .await?;
+    Ok(found_wallet_balance_snapshot)
+}
+
+pub async fn get_latest_by_wallet_id_and_snapshot_date_lte(
+    connection: &impl ConnectionTrait,
+    wallet_id: i32,
+    snapshot_date: Date,
+) -> Result<Option<wallet_balance_snapshots::Model>, AppError> {
+    let found_wallet_balance_snapshot = WalletBalanceSnapshots::find()
+        .filter(wallet_balance_snapshots::Column::WalletId.eq(wallet_id))
+        .filter(wallet_balance_snapshots::Column::SnapshotDate.lte(snapshot_date))
+        .order_by_desc(wallet_balance_snapshots::Column::SnapshotDate)
+        .one(connection)
+        .await?;
+    Ok(found_wallet_balance_snapshot)
+}
+
+pub async fn find_all_by_wallet_id_and_snapshot_date_gt_and_lte_order_by_snapshot_date_asc(
+    connection: &impl ConnectionTrait,
+    wallet_id: i32,
+    start_date: Date,
+    end_date: Date,
+) -> Result<Vec<wallet_balance_snapshots::Model>, AppError> {
+    let found_wallet_balance_snapshots = WalletBalanceSnapshots::find()
+        .filter(wallet_balance_snapshots::Column::WalletId.eq(wallet_id))
+        .filter(wallet_balance_snapshots::Column::SnapshotDate.gt(start_date))
+        .filter(wallet_balance_snapshots::Column::SnapshotDate.lte(end_date))
+        .order_by_asc(wallet_balance_snapshots::Column::SnapshotDate)
+        .all(connection)
+        .await?;
+    Ok(found_wallet_balance_snapshots)
+}
+
+pub async fn update_all_by_wallet_id_and_snapshot_date_gte_add_balance(
+    connection: &impl ConnectionTrait,
+    wallet_id: i32,
+    snapshot_date: Date,
+    amount: Decimal,
+) -> Result<u64, AppError> {
+    let updated_wallet_balance_snapshots = WalletBalanceSnapshots::update_many()
+        .col_expr(
+            wallet_balance_snapshots::Column::Balance,
+            Expr::col(wallet_balance_snapshots::Column::Balance).add(amount),
+        )
+        .col_expr(
+            wallet_balance_snapshots::Column::UpdatedAt,
+            Expr::value(OffsetDateTime::now_utc()),
+        )
+        .filter(wallet_balance_snapshots::Column::WalletId.eq(wallet_id))
+        .filter(wallet_balance_snapshots::Column::SnapshotDate.gte(snapshot_date))
+        .exec(connection)
+        .await?;
+    Ok(updated_wallet_balance_snapshots.rows_affected)
+}
@@ -0,0 +1,87 @@
+use crate::entities::prelude::WalletMembers;
+use crate::entities::wallet_members;
+use crate::errors::AppError;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, ModelTrait, QueryFilter,
+    QueryOrder, TryIntoModel,
+};
+
+pub async fn save(
+    connection: &impl ConnectionTrait,
+    wallet_member: wallet_members::ActiveModel,
+) -> Result<wallet_members::Model, AppError> {
+    let wallet_member = wallet_member.save(connection).await?.try_into_model()?;
+    Ok(wallet_member)
+}
+
+pub async fn delete(
+    connection: &impl ConnectionTrait,
+    wallet_member: wallet_members::Model,
+) -> Result<(), AppError> {
+    wallet_member.delete(connection).await?;
+    Ok(())
+}
+
+pub async fn get_by_wallet_id_and_user_id(
+    connection: &impl ConnectionTrait,
+    wallet_id: i32,
+    user_id: i32,
+) -> Result<Option<wallet_members::Model>, AppError> {
+    let found_wallet_member = WalletMembers::find()
+        .filter(wallet_members::Column::WalletId.eq(wallet_id))
+        .filter(wallet_members::Column::UserId.eq(user_id))
+        .one(connection)
+        .await?;
+    Ok(found_wallet_member)
+}
+
+pub async fn get_by_id_and_wallet_id(
+    connection: &impl ConnectionTrait,
+    wallet_member_id: i32,
+    wallet_id: i32,
+) -> Result<Option<wallet_members::Model>, AppError> {
+    let found_wallet_member = WalletMembers::find_by_id(wallet_member_id)
+        .filter(wallet_members::Column::WalletId.eq(wallet_id))
+        .one(connection)
+        .await?;
+    Ok(found_wallet_member)
+}
+
+pub async fn get_pending_by_id_and_user_id(
+    connection: &impl ConnectionTrait,
+    wallet_member_id: i32,
+    user_id: i32,
+) -> Result<Option<wallet_members::Model>, AppError> {
+    let found_wallet_member = WalletMembers::find_by_id(wallet_member_id)
+        .filter(wallet_members::Column::UserId.eq(user_id))
+        .filter(wallet_members::Column::AcceptedAt.is_null())
+        .one(connection)
+        .await?;
+    Ok(found_wallet_member)
+}
+
+pub async fn find_all_by_wallet_id_order_by_created_at_asc(
+    connection: &impl ConnectionTrait,
+    wallet_id: i32,
+) -> Result<Vec<wallet_members::Model>, AppError> {
+    let found_wallet_members = WalletMembers::find()
+        .filter(wallet_members::Column::WalletId.eq(wallet_id))
+        .order_by_asc(wallet_members::Column::CreatedAt)
+        .order_by_asc(wallet_members::Column::Id)
+        .all(connection)
+        .await?;
+    Ok(found_wallet_members)
+}
+
+pub async fn find_all_pending_by_user_id_order_by_created_at_desc(
+    connection: &impl ConnectionTrait,
+    user_id: i32,
+) -> Result<Vec<wallet_members::Model>, AppError> {
+    let found_wallet_members = WalletMembers::find()
+        .filter(wallet_members::Column::UserId.eq(user_id))
+        .filter(wallet_members::Column::AcceptedAt.is_null())
+        .order_by_desc(wallet_members::Column::CreatedAt)
+        .all(connection)
+        .await?;
+    Ok(found_wallet_members)
+}
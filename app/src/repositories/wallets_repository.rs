@@ -1,10 +1,12 @@
 use crate::dto::request::wallets_dto::FindAllWalletsParams;
-use crate::entities::prelude::Wallets;
-use crate::entities::wallets;
+use crate::entities::prelude::{WalletMembers, Wallets};
+use crate::entities::sea_orm_active_enums::WalletType;
+use crate::entities::{wallet_members, wallets};
 use crate::errors::AppError;
+use sea_orm::sea_query::Query;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
-    QueryOrder, QuerySelect, TryIntoModel,
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, EntityTrait,
+    QueryFilter, QueryOrder, QuerySelect, TryIntoModel,
 };
 
 pub async fn save(
@@ -20,10 +22,24 @@ pub async fn find_all_active_by_user_id_order_by_name_asc(
     user_id: i32,
     params: FindAllWalletsParams,
 ) -> Result<Vec<wallets::Model>, AppError> {
-    let found_wallets_builder = Wallets::find()
-        .filter(wallets::Column::UserId.eq(user_id))
+    // wallets shared with the user show up next to their own once the invitation is accepted
+    let shared_wallet_ids = Query::select()
+        .column(wallet_members::Column::WalletId)
+        .from(WalletMembers)
+        .and_where(wallet_members::Column::UserId.eq(user_id))
+        .and_where(wallet_members::Column::AcceptedAt.is_not_null())
+        .to_owned();
+    let mut found_wallets_builder = Wallets::find()
+        .filter(
+            Condition::any()
+                .add(wallets::Column::UserId.eq(user_id))
+                .add(wallets::Column::Id.in_subquery(shared_wallet_ids)),
+        )
         .filter(wallets::Column::DeletedAt.is_null())
         .order_by_asc(wallets::Column::Name);
+    if !params.include_archived.unwrap_or(false) {
+        found_wallets_builder = found_wallets_builder.filter(wallets::Column::ArchivedAt.is_null());
+    }
 
     let Some(max_fetch) = params.max_fetch else {
         let found_wallets = found_wallets_builder.all(db).await?;
@@ -35,15 +51,103 @@ pub async fn find_all_active_by_user_id_order_by_name_asc(
     Ok(found_wallets)
 }
 
-pub async fn find_active_by_id_and_user_id(
-    db: &impl ConnectionTrait,
+pub async fn find_all_active_by_user_id_and_wallet_type_order_by_name_asc(
+    connection: &impl ConnectionTrait,
+    user_id: i32,
+    wallet_type: WalletType,
+) -> Result<Vec<wallets::Model>, AppError> {
+    let found_wallets = Wallets::find()
+        .filter(wallets::Column::UserId.eq(user_id))
+        .filter(wallets::Column::WalletType.eq(wallet_type))
+        .filter(wallets::Column::DeletedAt.is_null())
+        .order_by_asc(wallets::Column::Name)
+        .all(connection)
+        .await?;
+    Ok(found_wallets)
+}
+
+pub async fn find_all_active_by_user_id_and_exclude_from_totals_is_false_order_by_name_asc(
+    connection: &impl ConnectionTrait,
+    user_id: i32,
+) -> Result<Vec<wallets::Model>, AppError> {
+    let found_wallets = Wallets::find()
+        .filter(wallets::Column::UserId.eq(user_id))
+        .filter(wallets::Column::ExcludeFromTotals.eq(false))
+        .filter(wallets::Column::DeletedAt.is_null())
+        .order_by_asc(wallets::Column::Name)
+        .all(connection)
+        .await?;
+    Ok(found_wallets)
+}
+
+pub async fn find_all_active_order_by_id_asc(
+    connection: &impl ConnectionTrait,
+) -> Result<Vec<wallets::Model>, AppError> {
+    let found_wallets = Wallets::find()
+        .filter(wallets::Column::DeletedAt.is_null())
+        .order_by_asc(wallets::Column::Id)
+        .all(connection)
+        .await?;
+    Ok(found_wallets)
+}
+
+pub async fn find_all_active_order_by_id_asc_for_update(
+    connection: &impl ConnectionTrait,
+) -> Result<Vec<wallets::Model>, AppError> {
+    let found_wallets = Wallets::find()
+        .filter(wallets::Column::DeletedAt.is_null())
+        .order_by_asc(wallets::Column::Id)
+        .lock_exclusive()
+        .all(connection)
+        .await?;
+    Ok(found_wallets)
+}
+
+pub async fn get_active_by_id(
+    connection: &impl ConnectionTrait,
+    wallet_id: i32,
+) -> Result<Option<wallets::Model>, AppError> {
+    let found_wallet = Wallets::find_by_id(wallet_id)
+        .filter(wallets::Column::DeletedAt.is_null())
+        .one(connection)
+        .await?;
+    Ok(found_wallet)
+}
+
+pub async fn find_all_active_by_ids(
+    connection: &impl ConnectionTrait,
+    wallet_ids: Vec<i32>,
+) -> Result<Vec<wallets::Model>, AppError> {
+    let found_wallets = Wallets::find()
+        .filter(wallets::Column::Id.is_in(wallet_ids))
+        .filter(wallets::Column::DeletedAt.is_null())
+        .all(connection)
+        .await?;
+    Ok(found_wallets)
+}
+
+pub async fn find_all_deleted_by_user_id_order_by_deleted_at_desc(
+    connection: &impl ConnectionTrait,
+    user_id: i32,
+) -> Result<Vec<wallets::Model>, AppError> {
+    let found_wallets = Wallets::find()
+        .filter(wallets::Column::UserId.eq(user_id))
+        .filter(wallets::Column::DeletedAt.is_not_null())
+        .order_by_desc(wallets::Column::DeletedAt)
+        .all(connection)
+        .await?;
+    Ok(found_wallets)
+}
+
+pub async fn get_deleted_by_id_and_user_id(
+    connection: &impl ConnectionTrait,
     wallet_id: i32,
     user_id: i32,
 ) -> Result<Option<wallets::Model>, AppError> {
     let found_wallet = Wallets::find_by_id(wallet_id)
         .filter(wallets::Column::UserId.eq(user_id))
-        .filter(wallets::Column::DeletedAt.is_null())
-        .one(db)
+        .filter(wallets::Column::DeletedAt.is_not_null())
+        .one(connection)
         .await?;
     Ok(found_wallet)
 }
@@ -3,9 +3,16 @@ use axum::Router;
 use std::sync::Arc;
 
 pub mod auth_route;
+pub mod balance_checks_route;
 pub mod budget_configs_route;
 pub mod budgets_route;
 pub mod categories_route;
+pub mod exchange_rates_route;
+pub mod installment_plans_route;
+pub mod net_worth_route;
+pub mod payees_route;
+pub mod tags_route;
+pub mod transaction_rules_route;
 pub mod transactions_route;
 pub mod users_route;
 pub mod wallets_route;
@@ -13,9 +20,22 @@ pub mod wallets_route;
 pub fn register() -> Router<Arc<AppState>> {
     Router::new()
         .nest("/api/v1/auth", auth_route::register())
+        .nest("/api/v1/balance-checks", balance_checks_route::register())
         .nest("/api/v1/budget-configs", budget_configs_route::register())
         .nest("/api/v1/budgets", budgets_route::register())
         .nest("/api/v1/categories", categories_route::register())
+        .nest("/api/v1/exchange-rates", exchange_rates_route::register())
+        .nest(
+            "/api/v1/installment-plans",
+            installment_plans_route::register(),
+        )
+        .nest("/api/v1/net-worth", net_worth_route::register())
+        .nest("/api/v1/payees", payees_route::register())
+        .nest("/api/v1/tags", tags_route::register())
+        .nest(
+            "/api/v1/transaction-rules",
+            transaction_rules_route::register(),
+        )
         .nest("/api/v1/transactions", transactions_route::register())
         .nest("/api/v1/users", users_route::register())
         .nest("/api/v1/wallets", wallets_route::register())
@@ -0,0 +1,11 @@
+use crate::AppState;
+use crate::controllers::balance_checks_controller;
+use axum::Router;
+use axum::routing::{get, post};
+use std::sync::Arc;
+
+pub fn register() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(balance_checks_controller::check))
+        .route("/repair", post(balance_checks_controller::repair))
+}
@@ -8,7 +8,9 @@ pub fn register() -> Router<Arc<AppState>> {
     Router::new()
         .route("/", post(budgets_controller::create))
         .route("/", get(budgets_controller::find_all))
+        .route("/trash", get(budgets_controller::find_all_deleted))
         .route("/{id}", get(budgets_controller::get_by_id))
         .route("/{id}", put(budgets_controller::update_by_id))
         .route("/{id}", delete(budgets_controller::delete_by_id))
+        .route("/{id}/restore", post(budgets_controller::restore_by_id))
 }
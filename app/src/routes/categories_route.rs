@@ -8,6 +8,8 @@ pub fn register() -> Router<Arc<AppState>> {
     Router::new()
         .route("/", post(categories_controller::create))
         .route("/", get(categories_controller::find_all))
+        .route("/trash", get(categories_controller::find_all_deleted))
         .route("/{id}", put(categories_controller::update_by_id))
         .route("/{id}", delete(categories_controller::delete_by_id))
+        .route("/{id}/restore", post(categories_controller::restore_by_id))
 }
@@ -0,0 +1,23 @@
+use crate::AppState;
+use crate::constants::exchange_rate_constants::MAX_EXCHANGE_RATE_FILE_SIZE;
+use crate::controllers::exchange_rates_controller;
+use axum::Router;
+use axum::extract::DefaultBodyLimit;
+use axum::routing::{delete, get, post};
+use std::sync::Arc;
+
+pub fn register() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", post(exchange_rates_controller::create))
+        .route("/", get(exchange_rates_controller::find_all))
+        .route(
+            "/import",
+            post(exchange_rates_controller::import)
+                // leave headroom for the multipart boundaries and part headers
+                .layer(DefaultBodyLimit::max(
+                    MAX_EXCHANGE_RATE_FILE_SIZE + 64 * 1024,
+                )),
+        )
+        .route("/convert", get(exchange_rates_controller::convert))
+        .route("/{id}", delete(exchange_rates_controller::delete_by_id))
+}
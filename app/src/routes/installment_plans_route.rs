@@ -0,0 +1,25 @@
+use crate::AppState;
+use crate::controllers::installment_plans_controller;
+use axum::Router;
+use axum::routing::{delete, get, post};
+use std::sync::Arc;
+
+pub fn register() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", post(installment_plans_controller::create))
+        .route("/", get(installment_plans_controller::find_all))
+        .route(
+            "/trash",
+            get(installment_plans_controller::find_all_deleted),
+        )
+        .route("/{id}", get(installment_plans_controller::get_by_id))
+        .route("/{id}", delete(installment_plans_controller::delete_by_id))
+        .route(
+            "/{id}/payoff",
+            post(installment_plans_controller::pay_off_by_id),
+        )
+        .route(
+            "/{id}/restore",
+            post(installment_plans_controller::restore_by_id),
+        )
+}
@@ -0,0 +1,11 @@
+use crate::AppState;
+use crate::controllers::net_worth_controller;
+use axum::Router;
+use axum::routing::get;
+use std::sync::Arc;
+
+pub fn register() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(net_worth_controller::get_current))
+        .route("/history", get(net_worth_controller::find_history))
+}
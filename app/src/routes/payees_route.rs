@@ -0,0 +1,18 @@
+use crate::AppState;
+use crate::controllers::payees_controller;
+use axum::Router;
+use axum::routing::{delete, get, post, put};
+use std::sync::Arc;
+
+pub fn register() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", post(payees_controller::create))
+        .route("/", get(payees_controller::find_all))
+        .route("/trash", get(payees_controller::find_all_deleted))
+        .route("/totals", get(payees_controller::find_totals))
+        .route("/apply", post(payees_controller::apply_all))
+        .route("/{id}", get(payees_controller::get_by_id))
+        .route("/{id}", put(payees_controller::update_by_id))
+        .route("/{id}", delete(payees_controller::delete_by_id))
+        .route("/{id}/restore", post(payees_controller::restore_by_id))
+}
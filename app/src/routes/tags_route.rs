@@ -0,0 +1,17 @@
+use crate::AppState;
+use crate::controllers::tags_controller;
+use axum::Router;
+use axum::routing::{delete, get, post, put};
+use std::sync::Arc;
+
+pub fn register() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", post(tags_controller::create))
+        .route("/", get(tags_controller::find_all))
+        .route("/trash", get(tags_controller::find_all_deleted))
+        .route("/totals", get(tags_controller::find_totals))
+        .route("/{id}", get(tags_controller::get_by_id))
+        .route("/{id}", put(tags_controller::update_by_id))
+        .route("/{id}", delete(tags_controller::delete_by_id))
+        .route("/{id}/restore", post(tags_controller::restore_by_id))
+}
@@ -0,0 +1,24 @@
+use crate::AppState;
+use crate::controllers::transaction_rules_controller;
+use axum::Router;
+use axum::routing::{delete, get, post, put};
+use std::sync::Arc;
+
+pub fn register() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", post(transaction_rules_controller::create))
+        .route("/", get(transaction_rules_controller::find_all))
+        .route(
+            "/trash",
+            get(transaction_rules_controller::find_all_deleted),
+        )
+        .route("/apply", post(transaction_rules_controller::apply_all))
+        .route("/{id}", get(transaction_rules_controller::get_by_id))
+        .route("/{id}", put(transaction_rules_controller::update_by_id))
+        .route("/{id}", delete(transaction_rules_controller::delete_by_id))
+        .route(
+            "/{id}/restore",
+            post(transaction_rules_controller::restore_by_id),
+        )
+        .route("/{id}/test", get(transaction_rules_controller::test_by_id))
+}
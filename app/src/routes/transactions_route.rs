@@ -1,6 +1,8 @@
 use crate::AppState;
-use crate::controllers::transactions_controller;
+use crate::constants::attachment_constants::MAX_ATTACHMENT_SIZE;
+use crate::controllers::{transaction_attachments_controller, transactions_controller};
 use axum::Router;
+use axum::extract::DefaultBodyLimit;
 use axum::routing::{delete, get, post, put};
 use std::sync::Arc;
 
@@ -8,7 +10,62 @@ pub fn register() -> Router<Arc<AppState>> {
     Router::new()
         .route("/", post(transactions_controller::create))
         .route("/", get(transactions_controller::find_all))
+        .route("/trash", get(transactions_controller::find_all_deleted))
+        .route("/bulk", post(transactions_controller::bulk))
+        .route("/transfers", post(transactions_controller::create_transfer))
+        .route("/search", get(transactions_controller::search))
+        .route("/nearby", get(transactions_controller::find_all_nearby))
+        .route("/places", get(transactions_controller::find_place_totals))
+        .route("/export", get(transactions_controller::export))
+        .route("/duplicates", get(transactions_controller::find_duplicates))
+        .route(
+            "/duplicates/merge",
+            post(transactions_controller::merge_duplicates),
+        )
         .route("/{id}", get(transactions_controller::get_by_id))
         .route("/{id}", put(transactions_controller::update_by_id))
         .route("/{id}", delete(transactions_controller::delete_by_id))
+        .route(
+            "/{id}/restore",
+            post(transactions_controller::restore_by_id),
+        )
+        .route(
+            "/{id}/history",
+            get(transactions_controller::find_history_by_id),
+        )
+        .route(
+            "/{id}/refunds",
+            post(transactions_controller::create_refund),
+        )
+        .route("/{id}/refunds", get(transactions_controller::find_refunds))
+        .route(
+            "/{id}/status",
+            put(transactions_controller::update_status_by_id),
+        )
+        .route(
+            "/{id}/attachments",
+            post(transaction_attachments_controller::create)
+                // leave headroom for the multipart boundaries and part headers
+                .layer(DefaultBodyLimit::max(MAX_ATTACHMENT_SIZE + 64 * 1024)),
+        )
+        .route(
+            "/{id}/attachments",
+            get(transaction_attachments_controller::find_all),
+        )
+        .route(
+            "/{id}/attachments/trash",
+            get(transaction_attachments_controller::find_all_deleted),
+        )
+        .route(
+            "/{id}/attachments/{attachment_id}",
+            get(transaction_attachments_controller::download_by_id),
+        )
+        .route(
+            "/{id}/attachments/{attachment_id}",
+            delete(transaction_attachments_controller::delete_by_id),
+        )
+        .route(
+            "/{id}/attachments/{attachment_id}/restore",
+            post(transaction_attachments_controller::restore_by_id),
+        )
 }
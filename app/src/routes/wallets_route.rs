@@ -1,5 +1,8 @@
 use crate::AppState;
-use crate::controllers::wallets_controller;
+use crate::controllers::{
+    budgets_controller, categories_controller, credit_card_statements_controller,
+    reconciliations_controller, wallet_members_controller, wallets_controller,
+};
 use axum::Router;
 use axum::routing::{delete, get, post, put};
 use std::sync::Arc;
@@ -8,7 +11,82 @@ pub fn register() -> Router<Arc<AppState>> {
     Router::new()
         .route("/", post(wallets_controller::create))
         .route("/", get(wallets_controller::find_all))
+        .route("/trash", get(wallets_controller::find_all_deleted))
+        .route(
+            "/statements/upcoming",
+            get(credit_card_statements_controller::find_upcoming),
+        )
+        .route(
+            "/invitations",
+            get(wallet_members_controller::find_all_invitations),
+        )
+        .route(
+            "/invitations/{id}",
+            delete(wallet_members_controller::decline_invitation_by_id),
+        )
+        .route(
+            "/invitations/{id}/accept",
+            post(wallet_members_controller::accept_invitation_by_id),
+        )
         .route("/{id}", get(wallets_controller::get_by_id))
         .route("/{id}", put(wallets_controller::update_by_id))
         .route("/{id}", delete(wallets_controller::delete_by_id))
+        .route("/{id}/restore", post(wallets_controller::restore_by_id))
+        .route("/{id}/archive", post(wallets_controller::archive_by_id))
+        .route("/{id}/unarchive", post(wallets_controller::unarchive_by_id))
+        .route("/{id}/balance", get(wallets_controller::get_balance_by_id))
+        .route(
+            "/{id}/balance/history",
+            get(wallets_controller::find_balance_history_by_id),
+        )
+        .route(
+            "/{id}/statements",
+            get(credit_card_statements_controller::find_all),
+        )
+        .route(
+            "/{id}/categories",
+            get(categories_controller::find_all_by_wallet_id),
+        )
+        .route(
+            "/{id}/budgets",
+            get(budgets_controller::find_all_by_wallet_id),
+        )
+        .route("/{id}/members", post(wallet_members_controller::create))
+        .route("/{id}/members", get(wallet_members_controller::find_all))
+        .route(
+            "/{id}/members/{member_id}",
+            put(wallet_members_controller::update_by_id),
+        )
+        .route(
+            "/{id}/members/{member_id}",
+            delete(wallet_members_controller::delete_by_id),
+        )
+        .route(
+            "/{id}/reconciliations",
+            post(reconciliations_controller::create),
+        )
+        .route(
+            "/{id}/reconciliations",
+            get(reconciliations_controller::find_all),
+        )
+        .route(
+            "/{id}/reconciliations/trash",
+            get(reconciliations_controller::find_all_deleted),
+        )
+        .route(
+            "/{id}/reconciliations/{reconciliation_id}",
+            get(reconciliations_controller::get_by_id),
+        )
+        .route(
+            "/{id}/reconciliations/{reconciliation_id}",
+            delete(reconciliations_controller::delete_by_id),
+        )
+        .route(
+            "/{id}/reconciliations/{reconciliation_id}/complete",
+            post(reconciliations_controller::complete_by_id),
+        )
+        .route(
+            "/{id}/reconciliations/{reconciliation_id}/restore",
+            post(reconciliations_controller::restore_by_id),
+        )
 }
@@ -1,10 +1,31 @@
 pub mod auth_service;
+pub mod balance_checks_service;
 pub mod budget_configs_service;
 pub mod budgets_service;
 pub mod categories_service;
+pub mod credit_card_statements_service;
+pub mod exchange_rate_imports_service;
+pub mod exchange_rates_service;
+pub mod installment_plans_service;
+pub mod net_worth_service;
+pub mod payees_service;
+pub mod reconciliations_service;
 pub mod refresh_tokens_service;
 pub mod roles_service;
+pub mod scheduled_transactions_service;
+pub mod tags_service;
+pub mod transaction_attachments_service;
+pub mod transaction_bulk_operations_service;
+pub mod transaction_duplicates_service;
+pub mod transaction_exports_service;
+pub mod transaction_refunds_service;
+pub mod transaction_revisions_service;
+pub mod transaction_rules_service;
+pub mod transaction_tags_service;
+pub mod transaction_transfers_service;
 pub mod transactions_service;
 pub mod user_roles_service;
 pub mod users_service;
+pub mod wallet_balance_snapshots_service;
+pub mod wallet_members_service;
 pub mod wallets_service;
@@ -0,0 +1,275 @@
+use crate::dto::response::balance_checks_dto::{
+    BalanceCheckReport, BalanceDiscrepancy, SnapshotDiscrepancy,
+};
+use crate::entities::sea_orm_active_enums::TransactionType;
+use crate::entities::wallet_balance_snapshots;
+use crate::errors::AppError;
+use crate::repositories::{
+    budgets_repository, transactions_repository, wallet_balance_snapshots_repository,
+    wallets_repository,
+};
+use rust_decimal::Decimal;
+use sea_orm::{
+    AccessMode, ActiveValue, DatabaseConnection, IntoActiveModel, IsolationLevel, TransactionTrait,
+};
+use std::collections::{BTreeMap, HashMap};
+use time::{Date, OffsetDateTime};
+
+// recomputes every wallet balance, daily balance history and budget amount from the transaction
+// ledger. A check reads one consistent snapshot without locking anything, a repair locks the
+// wallets and budgets until the end so the ledger cannot move while it is rewritten
+pub async fn check(db: &DatabaseConnection, repair: bool) -> Result<BalanceCheckReport, AppError> {
+    let txn = if repair {
+        db.begin().await?
+    } else {
+        db.begin_with_config(
+            Some(IsolationLevel::RepeatableRead),
+            Some(AccessMode::ReadOnly),
+        )
+        .await?
+    };
+
+    let found_wallets = if repair {
+        wallets_repository::find_all_active_order_by_id_asc_for_update(&txn).await?
+    } else {
+        wallets_repository::find_all_active_order_by_id_asc(&txn).await?
+    };
+    let mut wallet_amounts: HashMap<i32, Decimal> = HashMap::new();
+    for (wallet_id, flow_direction, total_amount) in
+        transactions_repository::find_posted_amount_sums_group_by_wallet_id(&txn).await?
+    {
+        *wallet_amounts.entry(wallet_id).or_default() += match flow_direction {
+            TransactionType::Income => total_amount,
+            TransactionType::Outcome => -total_amount,
+        };
+    }
+
+    let mut wallet_changes: HashMap<i32, BTreeMap<Date, Decimal>> = HashMap::new();
+    for (wallet_id, issued_at, total_amount) in
+        transactions_repository::find_posted_amount_sums_group_by_wallet_id_and_issued_at(&txn)
+            .await?
+    {
+        *wallet_changes
+            .entry(wallet_id)
+            .or_default()
+            .entry(issued_at)
+            .or_default() += total_amount;
+    }
+    let mut recorded_snapshots: HashMap<i32, Vec<(Date, Decimal)>> = HashMap::new();
+    for found_snapshot in
+        wallet_balance_snapshots_repository::find_all_order_by_wallet_id_asc_and_snapshot_date_asc(
+            &txn,
+        )
+        .await?
+    {
+        recorded_snapshots
+            .entry(found_snapshot.wallet_id)
+            .or_default()
+            .push((found_snapshot.snapshot_date, found_snapshot.balance));
+    }
+
+    let mut wallet_discrepancies = Vec::new();
+    let mut snapshot_discrepancies = Vec::new();
+    let checked_wallets = found_wallets.len();
+    for found_wallet in found_wallets {
+        let mut changes = wallet_changes.remove(&found_wallet.id).unwrap_or_default();
+        if let Some(opening_date) = found_wallet.opening_date {
+            *changes.entry(opening_date).or_default() += found_wallet.opening_balance;
+        }
+        let expected_snapshots = find_closing_balances(&changes);
+        let mismatched_dates = find_mismatched_dates(
+            &expected_snapshots,
+            recorded_snapshots
+                .get(&found_wallet.id)
+                .map_or(&[], Vec::as_slice),
+        );
+        if let Some(first_date) = mismatched_dates.first() {
+            snapshot_discrepancies.push(SnapshotDiscrepancy {
+                wallet_id: found_wallet.id,
+                user_id: found_wallet.user_id,
+                name: found_wallet.name.clone(),
+                first_date: *first_date,
+                mismatched_dates: mismatched_dates.len(),
+            });
+            if repair {
+                wallet_balance_snapshots_repository::delete_all_by_wallet_id(&txn, found_wallet.id)
+                    .await?;
+                wallet_balance_snapshots_repository::insert_many(
+                    &txn,
+                    expected_snapshots
+                        .into_iter()
+                        .map(
+                            |(snapshot_date, balance)| wallet_balance_snapshots::ActiveModel {
+                                wallet_id: ActiveValue::Set(found_wallet.id),
+                                snapshot_date: ActiveValue::Set(snapshot_date),
+                                balance: ActiveValue::Set(balance),
+                                ..Default::default()
+                            },
+                        )
+                        .collect(),
+                )
+                .await?;
+            }
+        }
+
+        let expected_balance = found_wallet.opening_balance
+            + wallet_amounts
+                .get(&found_wallet.id)
+                .copied()
+                .unwrap_or_default();
+        if found_wallet.balance == expected_balance {
+            continue;
+        }
+
+        wallet_discrepancies.push(BalanceDiscrepancy {
+            id: found_wallet.id,
+            user_id: found_wallet.user_id,
+            name: found_wallet.name.clone(),
+            recorded_amount: found_wallet.balance,
+            expected_amount: expected_balance,
+        });
+        if repair {
+            let mut found_wallet = found_wallet.into_active_model();
+            found_wallet.balance = ActiveValue::Set(expected_balance);
+            found_wallet.updated_at = ActiveValue::Set(OffsetDateTime::now_utc());
+            wallets_repository::save(&txn, found_wallet).await?;
+        }
+    }
+
+    let found_budgets = if repair {
+        budgets_repository::find_all_active_order_by_id_asc_for_update(&txn).await?
+    } else {
+        budgets_repository::find_all_active_order_by_id_asc(&txn).await?
+    };
+    let budget_amounts: HashMap<i32, Decimal> =
+        transactions_repository::find_posted_budget_amount_sums_group_by_budget_id(&txn)
+            .await?
+            .into_iter()
+            .collect();
+
+    let mut budget_discrepancies = Vec::new();
+    let checked_budgets = found_budgets.len();
+    for found_budget in found_budgets {
+        let expected_amount = budget_amounts
+            .get(&found_budget.id)
+            .copied()
+            .unwrap_or_default();
+        if found_budget.current_amount == expected_amount {
+            continue;
+        }
+
+        budget_discrepancies.push(BalanceDiscrepancy {
+            id: found_budget.id,
+            user_id: found_budget.user_id,
+            name: found_budget.name.clone(),
+            recorded_amount: found_budget.current_amount,
+            expected_amount,
+        });
+        if repair {
+            let mut found_budget = found_budget.into_active_model();
+            found_budget.current_amount = ActiveValue::Set(expected_amount);
+            found_budget.updated_at = ActiveValue::Set(OffsetDateTime::now_utc());
+            budgets_repository::save(&txn, found_budget).await?;
+        }
+    }
+
+    let repaired = repair
+        && !(wallet_discrepancies.is_empty()
+            && budget_discrepancies.is_empty()
+            && snapshot_discrepancies.is_empty());
+    if repaired {
+        txn.commit().await?;
+    } else {
+        txn.rollback().await?;
+    }
+
+    Ok(BalanceCheckReport {
+        checked_wallets,
+        checked_budgets,
+        wallet_discrepancies,
+        budget_discrepancies,
+        snapshot_discrepancies,
+        repaired,
+    })
+}
+
+fn find_closing_balances(changes: &BTreeMap<Date, Decimal>) -> Vec<(Date, Decimal)> {
+    let mut balance = Decimal::ZERO;
+    changes
+        .iter()
+        .map(|(date, amount)| {
+            balance += amount;
+            (*date, balance)
+        })
+        .collect()
+}
+
+// both sides only hold the days the balance may have changed, a day missing on one side
+// carries the closing balance of the day before, which is zero before the first one
+fn find_mismatched_dates(expected: &[(Date, Decimal)], recorded: &[(Date, Decimal)]) -> Vec<Date> {
+    let mut expected = expected.iter().peekable();
+    let mut recorded = recorded.iter().peekable();
+    let mut expected_balance = Decimal::ZERO;
+    let mut recorded_balance = Decimal::ZERO;
+    let mut mismatched_dates = Vec::new();
+    loop {
+        let date = match (expected.peek(), recorded.peek()) {
+            (Some((expected_date, _)), Some((recorded_date, _))) => {
+                *expected_date.min(recorded_date)
+            }
+            (Some((date, _)), None) | (None, Some((date, _))) => *date,
+            (None, None) => return mismatched_dates,
+        };
+        if let Some((_, balance)) = expected.next_if(|(next_date, _)| *next_date == date) {
+            expected_balance = *balance;
+        }
+        if let Some((_, balance)) = recorded.next_if(|(next_date, _)| *next_date == date) {
+            recorded_balance = *balance;
+        }
+        if expected_balance != recorded_balance {
+            mismatched_dates.push(date);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::date;
+
+    #[test]
+    fn find_mismatched_dates_ignores_days_without_a_change() {
+        let expected = [
+            (date!(2026 - 01 - 01), Decimal::from(100)),
+            (date!(2026 - 01 - 05), Decimal::from(80)),
+        ];
+        let recorded = [
+            (date!(2026 - 01 - 01), Decimal::from(100)),
+            (date!(2026 - 01 - 03), Decimal::from(100)),
+            (date!(2026 - 01 - 05), Decimal::from(80)),
+        ];
+
+        assert!(find_mismatched_dates(&expected, &recorded).is_empty());
+    }
+
+    #[test]
+    fn find_mismatched_dates_reports_every_drifted_day() {
+        let expected = [
+            (date!(2026 - 01 - 01), Decimal::from(100)),
+            (date!(2026 - 01 - 05), Decimal::from(80)),
+        ];
+        let recorded = [
+            (date!(2026 - 01 - 01), Decimal::from(100)),
+            (date!(2026 - 01 - 03), Decimal::from(90)),
+        ];
+
+        assert_eq!(
+            find_mismatched_dates(&expected, &recorded),
+            vec![date!(2026 - 01 - 03), date!(2026 - 01 - 05)]
+        );
+        assert_eq!(
+            find_mismatched_dates(&expected, &[]),
+            vec![date!(2026 - 01 - 01), date!(2026 - 01 - 05)]
+        );
+    }
+}
@@ -88,6 +88,40 @@ pub async fn find_all(
     Ok(found_budget_configs)
 }
 
+pub async fn find_all_deleted(
+    db: &DatabaseConnection,
+    user: &users::Model,
+) -> Result<Vec<budget_configs::Model>, AppError> {
+    let found_budget_configs =
+        budget_configs_repository::find_all_deleted_by_user_id_order_by_deleted_at_desc(
+            db, user.id,
+        )
+        .await?;
+    Ok(found_budget_configs)
+}
+
+pub async fn restore_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    budget_config_id: i32,
+) -> Result<budget_configs::Model, AppError> {
+    let found_budget_config =
+        budget_configs_repository::get_deleted_by_id_and_user_id(db, budget_config_id, user.id)
+            .await?;
+    let Some(found_budget_config) = found_budget_config else {
+        return Err(AppError::NotFound(String::from(
+            "Budget Config not found in trash",
+        )));
+    };
+
+    let mut found_budget_config = found_budget_config.into_active_model();
+    found_budget_config.deleted_at = ActiveValue::Set(None);
+    found_budget_config.updated_at = ActiveValue::Set(OffsetDateTime::now_utc());
+
+    let restored_budget_config = budget_configs_repository::save(db, found_budget_config).await?;
+    Ok(restored_budget_config)
+}
+
 pub async fn update_last_create(
     connection: &impl ConnectionTrait,
     user: &users::Model,
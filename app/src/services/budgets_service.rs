@@ -1,10 +1,10 @@
 use crate::dto::request::budget_configs_dto::create_budget_config_request::CreateBudgetConfigRequest;
 use crate::dto::request::budgets_dto::{CreateBudgetRequest, UpdateBudgetRequest};
-use crate::entities::sea_orm_active_enums::RepetitionTypeEnum;
+use crate::entities::sea_orm_active_enums::{RepetitionTypeEnum, WalletMemberRole};
 use crate::entities::{budgets, users};
 use crate::errors::AppError;
 use crate::repositories::budgets_repository;
-use crate::services::budget_configs_service;
+use crate::services::{budget_configs_service, wallets_service};
 use rust_decimal::Decimal;
 use sea_orm::{
     ActiveValue, ConnectionTrait, DatabaseConnection, IntoActiveModel, TransactionTrait,
@@ -125,6 +125,18 @@ pub async fn find_all(
     Ok(found_budgets)
 }
 
+// members of a shared wallet book into the ledger of its owner, so they pick from the owner's budgets
+pub async fn find_all_by_wallet_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    wallet_id: i32,
+) -> Result<Vec<budgets::Model>, AppError> {
+    let found_wallet =
+        wallets_service::get_by_id(db, user, wallet_id, WalletMemberRole::Viewer).await?;
+    let owner = wallets_service::find_owner(db, user, &found_wallet).await?;
+    find_all(db, &owner).await
+}
+
 pub async fn get_by_id(
     connection: &impl ConnectionTrait,
     user: &users::Model,
@@ -166,6 +178,37 @@ pub async fn delete_by_id(
     Ok(())
 }
 
+pub async fn find_all_deleted(
+    db: &DatabaseConnection,
+    user: &users::Model,
+) -> Result<Vec<budgets::Model>, AppError> {
+    let found_budgets =
+        budgets_repository::find_all_deleted_by_user_id_order_by_deleted_at_desc(db, user.id)
+            .await?;
+    Ok(found_budgets)
+}
+
+pub async fn restore_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    budget_id: i32,
+) -> Result<budgets::Model, AppError> {
+    let found_budget =
+        budgets_repository::get_deleted_by_id_and_user_id(db, budget_id, user.id).await?;
+    let Some(found_budget) = found_budget else {
+        return Err(AppError::NotFound(String::from(
+            "Budget not found in trash",
+        )));
+    };
+
+    let mut found_budget = found_budget.into_active_model();
+    found_budget.deleted_at = ActiveValue::Set(None);
+    found_budget.updated_at = ActiveValue::Set(OffsetDateTime::now_utc());
+
+    let restored_budget = budgets_repository::save(db, found_budget).await?;
+    Ok(restored_budget)
+}
+
 pub async fn update_amount_after_transaction(
     db: &impl ConnectionTrait,
     budget: budgets::Model,
@@ -1,10 +1,15 @@
 use crate::dto::request::categories_dto::create_category_request::CreateCategoryRequest;
 use crate::dto::request::categories_dto::get_all_categories_params::ValidatedGetAllCategoriesParams;
 use crate::dto::request::categories_dto::update_category_request::UpdateCategoryRequest;
+use crate::entities::sea_orm_active_enums::WalletMemberRole;
 use crate::entities::{categories, users};
 use crate::errors::AppError;
 use crate::repositories::categories_repository;
-use sea_orm::{ActiveValue, ConnectionTrait, DatabaseConnection, ItemsAndPagesNumber};
+use crate::services::wallets_service;
+use sea_orm::{
+    ActiveValue, ConnectionTrait, DatabaseConnection, IntoActiveModel, ItemsAndPagesNumber,
+};
+use time::OffsetDateTime;
 
 pub async fn create(
     db: &DatabaseConnection,
@@ -32,6 +37,19 @@ pub async fn find_all(
     Ok((found_categories, page_information))
 }
 
+// members of a shared wallet book into the ledger of its owner, so they pick from the owner's categories
+pub async fn find_all_by_wallet_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    wallet_id: i32,
+    params: ValidatedGetAllCategoriesParams,
+) -> Result<(Vec<categories::Model>, Option<ItemsAndPagesNumber>), AppError> {
+    let found_wallet =
+        wallets_service::get_by_id(db, user, wallet_id, WalletMemberRole::Viewer).await?;
+    let owner = wallets_service::find_owner(db, user, &found_wallet).await?;
+    find_all(db, owner.id, params).await
+}
+
 pub async fn get_by_id(
     connection: &impl ConnectionTrait,
     user: &users::Model,
@@ -66,3 +84,34 @@ pub async fn delete_by_id(
     categories_repository::delete_by_user_id_and_id(db, found_user.id, category_id).await?;
     Ok(())
 }
+
+pub async fn find_all_deleted(
+    db: &DatabaseConnection,
+    user: &users::Model,
+) -> Result<Vec<categories::Model>, AppError> {
+    let found_categories =
+        categories_repository::find_all_deleted_by_user_id_order_by_deleted_at_desc(db, user.id)
+            .await?;
+    Ok(found_categories)
+}
+
+pub async fn restore_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    category_id: i32,
+) -> Result<categories::Model, AppError> {
+    let found_category =
+        categories_repository::get_deleted_by_id_and_user_id(db, category_id, user.id).await?;
+    let Some(found_category) = found_category else {
+        return Err(AppError::NotFound(String::from(
+            "Category not found in trash",
+        )));
+    };
+
+    let mut found_category = found_category.into_active_model();
+    found_category.deleted_at = ActiveValue::Set(None);
+    found_category.updated_at = ActiveValue::Set(OffsetDateTime::now_utc());
+
+    let restored_category = categories_repository::save(db, found_category).await?;
+    Ok(restored_category)
+}
@@ -0,0 +1,389 @@
+use crate::constants::credit_card_constants::MINIMUM_PAYMENT_RATE;
+use crate::dto::response::credit_card_statements_dto::CreditCardStatement;
+use crate::entities::sea_orm_active_enums::{TransactionType, WalletMemberRole, WalletType};
+use crate::entities::{users, wallets};
+use crate::enums::credit_card_statement_status::CreditCardStatementStatus;
+use crate::errors::AppError;
+use crate::repositories::{transactions_repository, wallets_repository};
+use crate::services::wallets_service;
+use crate::utils::currency_utils::find_minor_units;
+use crate::utils::date_utils::{add_months, with_day_clamped};
+use rust_decimal::{Decimal, RoundingStrategy};
+use sea_orm::{ConnectionTrait, DatabaseConnection};
+use time::{Date, Duration, OffsetDateTime};
+
+pub async fn find_all(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    wallet_id: i32,
+    count: u32,
+) -> Result<Vec<CreditCardStatement>, AppError> {
+    let found_wallet =
+        wallets_service::get_by_id(db, user, wallet_id, WalletMemberRole::Viewer).await?;
+    let Some((closing_day, due_day)) = find_statement_days(&found_wallet) else {
+        return Err(AppError::Conflict(String::from(
+            "Statements are only available for credit card wallets",
+        )));
+    };
+    find_statements(
+        db,
+        &found_wallet,
+        closing_day,
+        due_day,
+        count,
+        OffsetDateTime::now_utc().date(),
+    )
+    .await
+}
+
+// the latest closed statement of every card that still has to be paid soon, or is already late
+pub async fn find_upcoming(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    days: i64,
+) -> Result<Vec<CreditCardStatement>, AppError> {
+    let today = OffsetDateTime::now_utc().date();
+    let found_wallets =
+        wallets_repository::find_all_active_by_user_id_and_wallet_type_order_by_name_asc(
+            db,
+            user.id,
+            WalletType::CreditCard,
+        )
+        .await?;
+
+    let mut upcoming_statements = Vec::new();
+    for found_wallet in found_wallets {
+        let Some((closing_day, due_day)) = find_statement_days(&found_wallet) else {
+            continue;
+        };
+        let statements = find_statements(db, &found_wallet, closing_day, due_day, 2, today).await?;
+        upcoming_statements.extend(statements.into_iter().filter(|statement| {
+            matches!(
+                statement.status,
+                CreditCardStatementStatus::Due | CreditCardStatementStatus::Overdue
+            ) && statement.days_until_due <= days
+        }));
+    }
+    upcoming_statements.sort_by_key(|statement| (statement.due_date, statement.wallet_id));
+    Ok(upcoming_statements)
+}
+
+fn find_statement_days(wallet: &wallets::Model) -> Option<(u8, u8)> {
+    if wallet.wallet_type != WalletType::CreditCard {
+        return None;
+    }
+    let closing_day = u8::try_from(wallet.statement_closing_day?).ok()?;
+    let due_day = u8::try_from(wallet.payment_due_day?).ok()?;
+    Some((closing_day, due_day))
+}
+
+fn find_closing_date_on_or_after(date: Date, closing_day: u8) -> Result<Date, AppError> {
+    let closing_date = with_day_clamped(date, closing_day)?;
+    if closing_date >= date {
+        return Ok(closing_date);
+    }
+    with_day_clamped(add_months(date, 1)?, closing_day)
+}
+
+fn find_due_date(closing_date: Date, due_day: u8) -> Result<Date, AppError> {
+    let due_date = with_day_clamped(closing_date, due_day)?;
+    if due_date > closing_date {
+        return Ok(due_date);
+    }
+    with_day_clamped(add_months(closing_date, 1)?, due_day)
+}
+
+async fn find_statements(
+    connection: &impl ConnectionTrait,
+    wallet: &wallets::Model,
+    closing_day: u8,
+    due_day: u8,
+    count: u32,
+    today: Date,
+) -> Result<Vec<CreditCardStatement>, AppError> {
+    let found_amount_sums =
+        transactions_repository::find_posted_amount_sums_by_wallet_id_group_by_issued_at(
+            connection, wallet.id,
+        )
+        .await?;
+    build_statements(
+        wallet,
+        &found_amount_sums,
+        closing_day,
+        due_day,
+        count,
+        today,
+    )
+}
+
+// newest first, starting with the statement that is still open today. The amount sums are the
+// posted transactions of the wallet per day, direction and whether they are transfers
+fn build_statements(
+    wallet: &wallets::Model,
+    found_amount_sums: &[(Date, TransactionType, bool, Decimal)],
+    closing_day: u8,
+    due_day: u8,
+    count: u32,
+    today: Date,
+) -> Result<Vec<CreditCardStatement>, AppError> {
+    let first_date = found_amount_sums
+        .first()
+        .map(|(issued_at, _, _, _)| *issued_at)
+        .unwrap_or(today)
+        .min(wallet.opening_date.unwrap_or(today))
+        .min(wallet.created_at.date());
+    let minor_units = find_minor_units(&wallet.currency).unwrap_or_default();
+
+    // the wallet balance already holds every posted transaction, so past balances are walked back from it
+    let balance_at = |date: Date| -> Decimal {
+        wallet.balance - wallet.opening_balance
+            + wallets_service::find_opening_balance_as_of(wallet, date)
+            - found_amount_sums
+                .iter()
+                .filter(|(issued_at, _, _, _)| *issued_at > date)
+                .map(
+                    |(_, flow_direction, _, total_amount)| match flow_direction {
+                        TransactionType::Income => *total_amount,
+                        TransactionType::Outcome => -*total_amount,
+                    },
+                )
+                .sum::<Decimal>()
+    };
+
+    let current_closing_date = find_closing_date_on_or_after(today, closing_day)?;
+    let mut statements = Vec::new();
+    for months_back in 0..count {
+        let months_back = i32::try_from(months_back).unwrap_or(i32::MAX);
+        let closing_date =
+            with_day_clamped(add_months(current_closing_date, -months_back)?, closing_day)?;
+        if months_back > 0 && closing_date < first_date {
+            break;
+        }
+        let previous_closing_date = with_day_clamped(
+            add_months(current_closing_date, -months_back - 1)?,
+            closing_day,
+        )?;
+        let period_start = previous_closing_date + Duration::days(1);
+        let due_date = find_due_date(closing_date, due_day)?;
+
+        let mut new_charges = Decimal::ZERO;
+        let mut payments_and_credits = Decimal::ZERO;
+        let mut later_payments = Decimal::ZERO;
+        for (issued_at, flow_direction, is_transfer, total_amount) in found_amount_sums {
+            if *issued_at > closing_date {
+                if *is_transfer && *flow_direction == TransactionType::Income {
+                    later_payments += *total_amount;
+                }
+            } else if *issued_at >= period_start {
+                match flow_direction {
+                    TransactionType::Income => payments_and_credits += *total_amount,
+                    TransactionType::Outcome => new_charges += *total_amount,
+                }
+            }
+        }
+
+        let closing_balance = balance_at(closing_date);
+        let statement_balance = (-closing_balance).max(Decimal::ZERO);
+        let minimum_payment = (statement_balance * MINIMUM_PAYMENT_RATE)
+            .round_dp_with_strategy(minor_units, RoundingStrategy::AwayFromZero)
+            .min(statement_balance);
+
+        let is_open = closing_date >= today;
+        let paid_amount = if is_open {
+            Decimal::ZERO
+        } else {
+            later_payments.min(statement_balance)
+        };
+        let status = if is_open {
+            CreditCardStatementStatus::Open
+        } else if paid_amount >= statement_balance {
+            CreditCardStatementStatus::Paid
+        } else if today > due_date {
+            CreditCardStatementStatus::Overdue
+        } else {
+            CreditCardStatementStatus::Due
+        };
+
+        statements.push(CreditCardStatement {
+            wallet_id: wallet.id,
+            currency: wallet.currency.clone(),
+            period_start,
+            closing_date,
+            due_date,
+            status,
+            opening_balance: balance_at(previous_closing_date),
+            closing_balance,
+            new_charges,
+            payments_and_credits,
+            statement_balance,
+            minimum_payment,
+            paid_amount,
+            days_until_due: (due_date - today).whole_days(),
+        });
+    }
+    Ok(statements)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::{date, datetime};
+
+    fn credit_card(balance: Decimal) -> wallets::Model {
+        wallets::Model {
+            id: 1,
+            user_id: 1,
+            name: String::from("Card"),
+            balance,
+            currency: String::from("USD"),
+            description: None,
+            created_at: datetime!(2025 - 12 - 01 0:00 UTC),
+            updated_at: datetime!(2025 - 12 - 01 0:00 UTC),
+            deleted_at: None,
+            wallet_type: WalletType::CreditCard,
+            credit_limit: None,
+            statement_closing_day: Some(25),
+            payment_due_day: Some(10),
+            interest_rate: None,
+            opening_balance: Decimal::ZERO,
+            opening_date: None,
+            archived_at: None,
+            exclude_from_totals: false,
+        }
+    }
+
+    #[test]
+    fn build_statements_splits_the_ledger_into_billing_periods() {
+        let amount_sums = [
+            (
+                date!(2026 - 01 - 10),
+                TransactionType::Outcome,
+                false,
+                Decimal::from(100),
+            ),
+            (
+                date!(2026 - 02 - 05),
+                TransactionType::Income,
+                true,
+                Decimal::from(100),
+            ),
+            (
+                date!(2026 - 02 - 15),
+                TransactionType::Outcome,
+                false,
+                Decimal::from(200),
+            ),
+            (
+                date!(2026 - 03 - 01),
+                TransactionType::Outcome,
+                false,
+                Decimal::from(50),
+            ),
+        ];
+
+        let statements = build_statements(
+            &credit_card(Decimal::from(-250)),
+            &amount_sums,
+            25,
+            10,
+            3,
+            date!(2026 - 03 - 05),
+        )
+        .unwrap();
+
+        assert_eq!(statements.len(), 3);
+
+        let open = &statements[0];
+        assert!(open.status == CreditCardStatementStatus::Open);
+        assert_eq!(open.period_start, date!(2026 - 02 - 26));
+        assert_eq!(open.closing_date, date!(2026 - 03 - 25));
+        assert_eq!(open.due_date, date!(2026 - 04 - 10));
+        assert_eq!(open.new_charges, Decimal::from(50));
+        assert_eq!(open.closing_balance, Decimal::from(-250));
+        assert_eq!(open.paid_amount, Decimal::ZERO);
+
+        let due = &statements[1];
+        assert!(due.status == CreditCardStatementStatus::Due);
+        assert_eq!(due.closing_date, date!(2026 - 02 - 25));
+        assert_eq!(due.due_date, date!(2026 - 03 - 10));
+        assert_eq!(due.opening_balance, Decimal::from(-100));
+        assert_eq!(due.closing_balance, Decimal::from(-200));
+        assert_eq!(due.new_charges, Decimal::from(200));
+        assert_eq!(due.payments_and_credits, Decimal::from(100));
+        assert_eq!(due.statement_balance, Decimal::from(200));
+        assert_eq!(due.minimum_payment, Decimal::from(10));
+        assert_eq!(due.days_until_due, 5);
+
+        let paid = &statements[2];
+        assert!(paid.status == CreditCardStatementStatus::Paid);
+        assert_eq!(paid.closing_date, date!(2026 - 01 - 25));
+        assert_eq!(paid.statement_balance, Decimal::from(100));
+        assert_eq!(paid.paid_amount, Decimal::from(100));
+    }
+
+    #[test]
+    fn build_statements_marks_unpaid_statements_past_their_due_date_overdue() {
+        let amount_sums = [(
+            date!(2026 - 01 - 10),
+            TransactionType::Outcome,
+            false,
+            Decimal::from(100),
+        )];
+
+        let statements = build_statements(
+            &credit_card(Decimal::from(-100)),
+            &amount_sums,
+            25,
+            10,
+            2,
+            date!(2026 - 02 - 11),
+        )
+        .unwrap();
+
+        assert!(statements[1].status == CreditCardStatementStatus::Overdue);
+        assert_eq!(statements[1].days_until_due, -1);
+    }
+
+    #[test]
+    fn build_statements_clamps_the_closing_day_to_short_months() {
+        let statements = build_statements(
+            &credit_card(Decimal::ZERO),
+            &[],
+            31,
+            10,
+            3,
+            date!(2026 - 02 - 10),
+        )
+        .unwrap();
+
+        let closing_dates: Vec<Date> = statements
+            .iter()
+            .map(|statement| statement.closing_date)
+            .collect();
+        assert_eq!(
+            closing_dates,
+            vec![
+                date!(2026 - 02 - 28),
+                date!(2026 - 01 - 31),
+                date!(2025 - 12 - 31)
+            ]
+        );
+        assert_eq!(statements[0].period_start, date!(2026 - 02 - 01));
+    }
+
+    #[test]
+    fn build_statements_stops_before_the_wallet_existed() {
+        let statements = build_statements(
+            &credit_card(Decimal::ZERO),
+            &[],
+            25,
+            10,
+            12,
+            date!(2026 - 02 - 10),
+        )
+        .unwrap();
+
+        assert_eq!(statements.len(), 3);
+        assert_eq!(statements[2].closing_date, date!(2025 - 12 - 25));
+    }
+}
@@ -0,0 +1,178 @@
+use crate::constants::exchange_rate_constants::{
+    ECB_BASE_CURRENCY, EXCHANGE_RATE_IMPORT_BATCH_SIZE, EXCHANGE_RATE_SCALE,
+};
+use crate::constants::time_constants::{DATE_FORMATTER, LONG_DATE_FORMATTER};
+use crate::dto::response::exchange_rates_dto::ImportExchangeRatesResponse;
+use crate::entities::exchange_rates;
+use crate::enums::exchange_rate_file_format::ExchangeRateFileFormat;
+use crate::errors::AppError;
+use crate::repositories::exchange_rates_repository;
+use crate::utils::currency_utils::find_minor_units;
+use quick_xml::Reader;
+use quick_xml::events::Event;
+use rust_decimal::Decimal;
+use sea_orm::{ActiveValue, DatabaseConnection, TransactionTrait};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use time::{Date, OffsetDateTime};
+
+// keyed by date and quote currency so a rate repeated in the file is only written once
+type ParsedRates = BTreeMap<(Date, String), Decimal>;
+
+fn parse_rate_date(value: &str) -> Result<Date, AppError> {
+    Date::parse(value, &DATE_FORMATTER)
+        .or_else(|_| Date::parse(value, &LONG_DATE_FORMATTER))
+        .map_err(|_| AppError::ParseBody(format!("invalid rate date {value}")))
+}
+
+// returns false when the rate has to be skipped
+fn add_rate(parsed_rates: &mut ParsedRates, rate_date: Date, currency: &str, rate: &str) -> bool {
+    if currency == ECB_BASE_CURRENCY || find_minor_units(currency).is_none() {
+        return false;
+    }
+    let Ok(rate) = Decimal::from_str(rate) else {
+        return false;
+    };
+    if rate <= Decimal::ZERO {
+        return false;
+    }
+    parsed_rates.insert(
+        (rate_date, String::from(currency)),
+        rate.round_dp(EXCHANGE_RATE_SCALE),
+    );
+    true
+}
+
+// <Cube time="2025-10-17"><Cube currency="USD" rate="1.1681"/>...</Cube>
+fn parse_xml(file: &[u8]) -> Result<(ParsedRates, u64), AppError> {
+    let content = std::str::from_utf8(file)
+        .map_err(|_| AppError::ParseBody(String::from("rate file must be utf-8")))?;
+    let mut reader = Reader::from_str(content);
+
+    let mut parsed_rates = ParsedRates::new();
+    let mut skipped_count = 0;
+    let mut rate_date = None;
+    loop {
+        let element = match reader.read_event() {
+            Ok(Event::Start(element)) | Ok(Event::Empty(element)) => element,
+            Ok(Event::Eof) => break,
+            Ok(_) => continue,
+            Err(err) => {
+                return Err(AppError::ParseBody(format!("invalid rate file: {err}")));
+            }
+        };
+        if element.local_name().as_ref() != b"Cube" {
+            continue;
+        }
+
+        let mut time = None;
+        let mut currency = None;
+        let mut rate = None;
+        for attribute in element.attributes() {
+            let attribute = attribute
+                .map_err(|err| AppError::ParseBody(format!("invalid rate file: {err}")))?;
+            let value = String::from_utf8_lossy(&attribute.value).into_owned();
+            match attribute.key.local_name().as_ref() {
+                b"time" => time = Some(value),
+                b"currency" => currency = Some(value),
+                b"rate" => rate = Some(value),
+                _ => {}
+            }
+        }
+
+        if let Some(time) = time {
+            rate_date = Some(parse_rate_date(&time)?);
+        }
+        if let (Some(currency), Some(rate)) = (currency, rate) {
+            let Some(rate_date) = rate_date else {
+                return Err(AppError::ParseBody(String::from(
+                    "invalid rate file: rate found before its date",
+                )));
+            };
+            if !add_rate(&mut parsed_rates, rate_date, &currency, &rate) {
+                skipped_count += 1;
+            }
+        }
+    }
+    Ok((parsed_rates, skipped_count))
+}
+
+// Date,USD,JPY,...
+// 2025-10-17,1.1681,175.27,...
+fn parse_csv(file: &[u8]) -> Result<(ParsedRates, u64), AppError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .flexible(true)
+        .from_reader(file);
+    let currencies = reader
+        .headers()
+        .map_err(|err| AppError::ParseBody(format!("invalid rate file: {err}")))?
+        .clone();
+
+    let mut parsed_rates = ParsedRates::new();
+    let mut skipped_count = 0;
+    for record in reader.records() {
+        let record =
+            record.map_err(|err| AppError::ParseBody(format!("invalid rate file: {err}")))?;
+        let Some(rate_date) = record.get(0).filter(|value| !value.is_empty()) else {
+            continue;
+        };
+        let rate_date = parse_rate_date(rate_date)?;
+
+        // the trailing comma of the ECB files leaves an unnamed last column
+        for (currency, rate) in currencies.iter().zip(record.iter()).skip(1) {
+            if currency.is_empty() {
+                continue;
+            }
+            if !add_rate(&mut parsed_rates, rate_date, currency, rate) {
+                skipped_count += 1;
+            }
+        }
+    }
+    Ok((parsed_rates, skipped_count))
+}
+
+// imported rates are shared by every user, all against the euro
+pub async fn import(
+    db: &DatabaseConnection,
+    format: ExchangeRateFileFormat,
+    file: &[u8],
+) -> Result<ImportExchangeRatesResponse, AppError> {
+    let (parsed_rates, skipped_count) = match format {
+        ExchangeRateFileFormat::Xml => parse_xml(file)?,
+        ExchangeRateFileFormat::Csv => parse_csv(file)?,
+    };
+    let start_date = parsed_rates.keys().map(|(rate_date, _)| *rate_date).min();
+    let end_date = parsed_rates.keys().map(|(rate_date, _)| *rate_date).max();
+
+    let now = OffsetDateTime::now_utc();
+    let new_exchange_rates: Vec<exchange_rates::ActiveModel> = parsed_rates
+        .into_iter()
+        .map(
+            |((rate_date, currency), rate)| exchange_rates::ActiveModel {
+                user_id: ActiveValue::Set(None),
+                base_currency: ActiveValue::Set(String::from(ECB_BASE_CURRENCY)),
+                quote_currency: ActiveValue::Set(currency),
+                rate_date: ActiveValue::Set(rate_date),
+                rate: ActiveValue::Set(rate),
+                updated_at: ActiveValue::Set(now),
+                ..Default::default()
+            },
+        )
+        .collect();
+
+    let txn = db.begin().await?;
+    let mut imported_count = 0;
+    for new_exchange_rates in new_exchange_rates.chunks(EXCHANGE_RATE_IMPORT_BATCH_SIZE) {
+        imported_count +=
+            exchange_rates_repository::upsert_many(&txn, new_exchange_rates.to_vec()).await?;
+    }
+    txn.commit().await?;
+
+    Ok(ImportExchangeRatesResponse {
+        imported_count,
+        skipped_count,
+        start_date,
+        end_date,
+    })
+}
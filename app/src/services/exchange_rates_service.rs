@@ -0,0 +1,145 @@
+use crate::constants::exchange_rate_constants::{ECB_BASE_CURRENCY, EXCHANGE_RATE_SCALE};
+use crate::dto::request::exchange_rates_dto::{
+    CreateExchangeRateRequest, ValidatedFindAllExchangeRatesParams,
+};
+use crate::entities::{exchange_rates, users};
+use crate::errors::AppError;
+use crate::repositories::exchange_rates_repository;
+use crate::utils::currency_utils::find_minor_units;
+use rust_decimal::{Decimal, RoundingStrategy};
+use sea_orm::{ActiveValue, ConnectionTrait, DatabaseConnection, ItemsAndPagesNumber};
+use std::str::FromStr;
+use time::{Date, OffsetDateTime};
+
+pub async fn create(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    payload: CreateExchangeRateRequest,
+) -> Result<exchange_rates::Model, AppError> {
+    let rate = Decimal::from_str(&payload.rate)?.round_dp(EXCHANGE_RATE_SCALE);
+    if rate <= Decimal::ZERO {
+        return Err(AppError::ParseBody(String::from(
+            "rate must be greater than 0",
+        )));
+    }
+
+    // entering the same pair and date again replaces the earlier manual rate
+    let new_exchange_rate = exchange_rates::ActiveModel {
+        user_id: ActiveValue::Set(Some(user.id)),
+        base_currency: ActiveValue::Set(payload.base_currency),
+        quote_currency: ActiveValue::Set(payload.quote_currency),
+        rate_date: ActiveValue::Set(payload.rate_date),
+        rate: ActiveValue::Set(rate),
+        updated_at: ActiveValue::Set(OffsetDateTime::now_utc()),
+        ..Default::default()
+    };
+    let new_exchange_rate = exchange_rates_repository::upsert(db, new_exchange_rate).await?;
+    Ok(new_exchange_rate)
+}
+
+pub async fn find_all(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    params: ValidatedFindAllExchangeRatesParams,
+) -> Result<(Vec<exchange_rates::Model>, ItemsAndPagesNumber), AppError> {
+    let found_exchange_rates =
+        exchange_rates_repository::find_all_by_user_id_and_params_order_by_rate_date_desc(
+            db, user.id, params,
+        )
+        .await?;
+    Ok(found_exchange_rates)
+}
+
+pub async fn delete_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    exchange_rate_id: i32,
+) -> Result<(), AppError> {
+    let Some(found_exchange_rate) =
+        exchange_rates_repository::get_manual_by_id_and_user_id(db, exchange_rate_id, user.id)
+            .await?
+    else {
+        return Err(AppError::NotFound(String::from(
+            "Manual exchange rate not found",
+        )));
+    };
+    exchange_rates_repository::delete_by_id(db, found_exchange_rate.id).await?;
+    Ok(())
+}
+
+async fn find_pair_rate(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    from: &str,
+    to: &str,
+    date: Date,
+) -> Result<Option<(Decimal, Date)>, AppError> {
+    let found_exchange_rate =
+        exchange_rates_repository::find_latest_by_user_id_and_currencies_and_rate_date_lte(
+            connection, user.id, from, to, date,
+        )
+        .await?;
+    Ok(found_exchange_rate.map(|exchange_rate| {
+        if exchange_rate.base_currency == from {
+            (exchange_rate.rate, exchange_rate.rate_date)
+        } else {
+            (Decimal::ONE / exchange_rate.rate, exchange_rate.rate_date)
+        }
+    }))
+}
+
+// the rate for a date is the latest one published on or before it, so weekends and holidays
+// use the last business day and nothing is ever taken from the future. a manual rate of the
+// user wins over a shared one of the same day, the inverse pair is used when only that one is
+// known, and anything else is crossed through the euro. the returned date is the oldest rate
+// that went into the result
+pub async fn find_rate(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    from: &str,
+    to: &str,
+    date: Date,
+) -> Result<(Decimal, Date), AppError> {
+    if from == to {
+        return Ok((Decimal::ONE, date));
+    }
+
+    if let Some(rate) = find_pair_rate(connection, user, from, to, date).await? {
+        return Ok((rate.0.round_dp(EXCHANGE_RATE_SCALE), rate.1));
+    }
+
+    if from != ECB_BASE_CURRENCY
+        && to != ECB_BASE_CURRENCY
+        && let Some((from_rate, from_rate_date)) =
+            find_pair_rate(connection, user, from, ECB_BASE_CURRENCY, date).await?
+        && let Some((to_rate, to_rate_date)) =
+            find_pair_rate(connection, user, ECB_BASE_CURRENCY, to, date).await?
+    {
+        return Ok((
+            (from_rate * to_rate).round_dp(EXCHANGE_RATE_SCALE),
+            from_rate_date.min(to_rate_date),
+        ));
+    }
+
+    Err(AppError::NotFound(format!(
+        "No exchange rate from {from} to {to} on or before {date}"
+    )))
+}
+
+// returns the converted amount rounded to the minor units of the target currency, the rate
+// and the date of the rate
+pub async fn convert(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    amount: Decimal,
+    from: &str,
+    to: &str,
+    date: Date,
+) -> Result<(Decimal, Decimal, Date), AppError> {
+    let (rate, rate_date) = find_rate(connection, user, from, to, date).await?;
+    let converted_amount = (amount * rate).round_dp_with_strategy(
+        find_minor_units(to).unwrap_or_default(),
+        RoundingStrategy::MidpointAwayFromZero,
+    );
+    Ok((converted_amount, rate, rate_date))
+}
@@ -0,0 +1,520 @@
+use crate::dto::request::installment_plans_dto::{
+    CreateInstallmentPlanRequest, PayOffInstallmentPlanRequest,
+};
+use crate::dto::request::transactions_dto::CreateTransactionRequest;
+use crate::entities::sea_orm_active_enums::{TransactionType, WalletMemberRole};
+use crate::entities::{installment_plans, transactions, users};
+use crate::errors::AppError;
+use crate::repositories::{installment_plans_repository, transactions_repository};
+use crate::services::{transactions_service, wallets_service};
+use crate::utils::currency_utils::{find_minor_units, parse_amount};
+use crate::utils::date_utils::add_months;
+use rust_decimal::{Decimal, RoundingStrategy};
+use sea_orm::{
+    ActiveEnum, ActiveValue, ConnectionTrait, DatabaseConnection, IntoActiveModel, TransactionTrait,
+};
+use std::collections::HashMap;
+use time::{Date, OffsetDateTime};
+
+type InstallmentPlanProgress = (
+    installment_plans::Model,
+    Vec<transactions::Model>,
+    Vec<transactions::Model>,
+);
+
+fn installment_description(
+    description: Option<&str>,
+    installment_number: i32,
+    installment_count: i32,
+) -> String {
+    match description {
+        Some(description) => format!("{description} ({installment_number}/{installment_count})"),
+        None => format!("Installment {installment_number}/{installment_count}"),
+    }
+}
+
+fn split_amount(
+    total_amount: Decimal,
+    installment_count: i32,
+    minor_units: u32,
+) -> Result<Vec<Decimal>, AppError> {
+    let installment_amount = (total_amount / Decimal::from(installment_count))
+        .round_dp_with_strategy(minor_units, RoundingStrategy::ToZero);
+    if installment_amount <= Decimal::ZERO {
+        return Err(AppError::ParseBody(String::from(
+            "total_amount is too small for the number of installments",
+        )));
+    }
+
+    // the first installment carries whatever the rounding left over
+    let mut installment_amounts = vec![installment_amount; installment_count as usize];
+    installment_amounts[0] += total_amount - installment_amount * Decimal::from(installment_count);
+    Ok(installment_amounts)
+}
+
+pub async fn create(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    request_id: Option<&str>,
+    payload: CreateInstallmentPlanRequest,
+) -> Result<(installment_plans::Model, Vec<transactions::Model>), AppError> {
+    let txn = db.begin().await?;
+
+    let found_wallet =
+        wallets_service::get_by_id(&txn, user, payload.wallet_id, WalletMemberRole::Owner).await?;
+    wallets_service::ensure_not_archived(&found_wallet)?;
+    let total_amount = parse_amount(&payload.total_amount, &found_wallet.currency)?;
+    if total_amount <= Decimal::ZERO {
+        return Err(AppError::ParseBody(String::from(
+            "total_amount must be greater than 0",
+        )));
+    }
+    let installment_amounts = split_amount(
+        total_amount,
+        payload.installment_count,
+        find_minor_units(&found_wallet.currency).unwrap_or_default(),
+    )?;
+
+    let new_installment_plan = installment_plans::ActiveModel {
+        user_id: ActiveValue::Set(user.id),
+        wallet_id: ActiveValue::Set(found_wallet.id),
+        budget_id: ActiveValue::Set(payload.budget_id),
+        category_id: ActiveValue::Set(payload.category_id),
+        payee_id: ActiveValue::Set(payload.payee_id),
+        description: ActiveValue::Set(payload.description.clone()),
+        total_amount: ActiveValue::Set(total_amount),
+        installment_count: ActiveValue::Set(payload.installment_count),
+        first_issued_at: ActiveValue::Set(payload.first_issued_at),
+        ..Default::default()
+    };
+    let new_installment_plan =
+        installment_plans_repository::save(&txn, new_installment_plan).await?;
+
+    let mut new_installments = Vec::with_capacity(installment_amounts.len());
+    for (index, installment_amount) in installment_amounts.into_iter().enumerate() {
+        let installment_number = index as i32 + 1;
+        let new_installment = transactions_service::insert(
+            &txn,
+            user,
+            request_id,
+            CreateTransactionRequest {
+                category_id: payload.category_id,
+                budget_id: payload.budget_id,
+                payee_id: payload.payee_id,
+                wallet_id: found_wallet.id,
+                amount: installment_amount.to_string(),
+                currency: None,
+                description: Some(installment_description(
+                    payload.description.as_deref(),
+                    installment_number,
+                    payload.installment_count,
+                )),
+                flow_direction: TransactionType::Outcome.to_value(),
+                status: None,
+                issued_at: add_months(payload.first_issued_at, index as i32)?,
+                tag_ids: None,
+                latitude: None,
+                longitude: None,
+                place_name: None,
+            },
+        )
+        .await?;
+        new_installments.push(
+            link_installment(
+                &txn,
+                new_installment,
+                &new_installment_plan,
+                Some(installment_number),
+            )
+            .await?,
+        );
+    }
+
+    // rules may have filled in what the request left out, keep the plan in line with its children
+    let mut new_installment_plan = new_installment_plan.into_active_model();
+    new_installment_plan.category_id = ActiveValue::Set(Some(new_installments[0].category_id));
+    new_installment_plan.budget_id = ActiveValue::Set(new_installments[0].budget_id);
+    new_installment_plan.payee_id = ActiveValue::Set(new_installments[0].payee_id);
+    let new_installment_plan =
+        installment_plans_repository::save(&txn, new_installment_plan).await?;
+
+    txn.commit().await?;
+
+    Ok((new_installment_plan, new_installments))
+}
+
+async fn link_installment(
+    connection: &impl ConnectionTrait,
+    transaction: transactions::Model,
+    installment_plan: &installment_plans::Model,
+    installment_number: Option<i32>,
+) -> Result<transactions::Model, AppError> {
+    let mut transaction = transaction.into_active_model();
+    transaction.installment_plan_id = ActiveValue::Set(Some(installment_plan.id));
+    transaction.installment_number = ActiveValue::Set(installment_number);
+    let transaction = transactions_repository::save(connection, transaction).await?;
+    Ok(transaction)
+}
+
+fn split_progress(
+    installment_plan: installment_plans::Model,
+    installments: Vec<transactions::Model>,
+    today: Date,
+) -> InstallmentPlanProgress {
+    let (paid_installments, remaining_installments) = installments
+        .into_iter()
+        .partition(|installment| installment.issued_at <= today);
+    (installment_plan, paid_installments, remaining_installments)
+}
+
+pub async fn find_all(
+    db: &DatabaseConnection,
+    user: &users::Model,
+) -> Result<Vec<InstallmentPlanProgress>, AppError> {
+    let found_installment_plans =
+        installment_plans_repository::find_all_active_by_user_id_order_by_first_issued_at_desc(
+            db, user.id,
+        )
+        .await?;
+    let found_installments =
+        transactions_repository::find_all_active_by_installment_plan_ids_order_by_issued_at_asc(
+            db,
+            found_installment_plans
+                .iter()
+                .map(|installment_plan| installment_plan.id)
+                .collect(),
+        )
+        .await?;
+
+    let mut installments_by_plan_id: HashMap<i32, Vec<transactions::Model>> = HashMap::new();
+    for found_installment in found_installments {
+        if let Some(installment_plan_id) = found_installment.installment_plan_id {
+            installments_by_plan_id
+                .entry(installment_plan_id)
+                .or_default()
+                .push(found_installment);
+        }
+    }
+
+    let today = OffsetDateTime::now_utc().date();
+    Ok(found_installment_plans
+        .into_iter()
+        .map(|installment_plan| {
+            let installments = installments_by_plan_id
+                .remove(&installment_plan.id)
+                .unwrap_or_default();
+            split_progress(installment_plan, installments, today)
+        })
+        .collect())
+}
+
+async fn get_by_id(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    installment_plan_id: i32,
+) -> Result<installment_plans::Model, AppError> {
+    let found_installment_plan = installment_plans_repository::get_active_by_id_and_user_id(
+        connection,
+        installment_plan_id,
+        user.id,
+    )
+    .await?;
+    let Some(found_installment_plan) = found_installment_plan else {
+        return Err(AppError::NotFound(String::from(
+            "Installment plan not found",
+        )));
+    };
+    Ok(found_installment_plan)
+}
+
+async fn get_progress_by_id(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    installment_plan_id: i32,
+    today: Date,
+) -> Result<InstallmentPlanProgress, AppError> {
+    let found_installment_plan = get_by_id(connection, user, installment_plan_id).await?;
+    let found_installments =
+        transactions_repository::find_all_active_by_installment_plan_ids_order_by_issued_at_asc(
+            connection,
+            vec![found_installment_plan.id],
+        )
+        .await?;
+    Ok(split_progress(
+        found_installment_plan,
+        found_installments,
+        today,
+    ))
+}
+
+pub async fn get_progress(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    installment_plan_id: i32,
+) -> Result<InstallmentPlanProgress, AppError> {
+    get_progress_by_id(
+        db,
+        user,
+        installment_plan_id,
+        OffsetDateTime::now_utc().date(),
+    )
+    .await
+}
+
+pub async fn pay_off_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    request_id: Option<&str>,
+    installment_plan_id: i32,
+    payload: PayOffInstallmentPlanRequest,
+) -> Result<InstallmentPlanProgress, AppError> {
+    let paid_off_at = payload
+        .paid_off_at
+        .unwrap_or_else(|| OffsetDateTime::now_utc().date());
+
+    let txn = db.begin().await?;
+
+    let (found_installment_plan, _, remaining_installments) =
+        get_progress_by_id(&txn, user, installment_plan_id, paid_off_at).await?;
+    if found_installment_plan.paid_off_at.is_some() {
+        return Err(AppError::Conflict(String::from(
+            "Installment plan is already paid off",
+        )));
+    }
+    let Some(first_remaining_installment) = remaining_installments.first().cloned() else {
+        return Err(AppError::Conflict(String::from(
+            "Installment plan has no remaining installments",
+        )));
+    };
+
+    let remaining_amount: Decimal = remaining_installments
+        .iter()
+        .map(|installment| installment.amount)
+        .sum();
+    for remaining_installment in remaining_installments {
+        transactions_service::delete(&txn, user, request_id, remaining_installment).await?;
+    }
+
+    // a single transaction settles everything that was still scheduled
+    let payoff_transaction = transactions_service::insert(
+        &txn,
+        user,
+        request_id,
+        CreateTransactionRequest {
+            category_id: Some(first_remaining_installment.category_id),
+            budget_id: first_remaining_installment.budget_id,
+            payee_id: first_remaining_installment.payee_id,
+            wallet_id: found_installment_plan.wallet_id,
+            amount: remaining_amount.to_string(),
+            currency: None,
+            description: Some(match found_installment_plan.description.as_deref() {
+                Some(description) => format!("{description} (payoff)"),
+                None => String::from("Installment payoff"),
+            }),
+            flow_direction: TransactionType::Outcome.to_value(),
+            status: None,
+            issued_at: paid_off_at,
+            tag_ids: None,
+            latitude: None,
+            longitude: None,
+            place_name: None,
+        },
+    )
+    .await?;
+    link_installment(&txn, payoff_transaction, &found_installment_plan, None).await?;
+
+    let mut found_installment_plan = found_installment_plan.into_active_model();
+    let now = OffsetDateTime::now_utc();
+    found_installment_plan.paid_off_at = ActiveValue::Set(Some(now));
+    found_installment_plan.updated_at = ActiveValue::Set(now);
+    installment_plans_repository::save(&txn, found_installment_plan).await?;
+
+    let progress = get_progress_by_id(
+        &txn,
+        user,
+        installment_plan_id,
+        OffsetDateTime::now_utc().date(),
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    Ok(progress)
+}
+
+pub async fn delete_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    request_id: Option<&str>,
+    installment_plan_id: i32,
+) -> Result<(), AppError> {
+    let txn = db.begin().await?;
+
+    // taken before the installments go, restoring the plan brings back whatever was deleted after it
+    let now = OffsetDateTime::now_utc();
+    let found_installment_plan = get_by_id(&txn, user, installment_plan_id).await?;
+    let found_installments =
+        transactions_repository::find_all_active_by_installment_plan_ids_order_by_issued_at_asc(
+            &txn,
+            vec![found_installment_plan.id],
+        )
+        .await?;
+    for found_installment in found_installments {
+        transactions_service::delete(&txn, user, request_id, found_installment).await?;
+    }
+
+    let mut found_installment_plan = found_installment_plan.into_active_model();
+    found_installment_plan.deleted_at = ActiveValue::Set(Some(now));
+    installment_plans_repository::save(&txn, found_installment_plan).await?;
+
+    txn.commit().await?;
+
+    Ok(())
+}
+
+// installments deleted on their own before the plan stay in the trash
+fn deleted_with_plan(
+    installment_plan: &installment_plans::Model,
+    installment: &transactions::Model,
+) -> bool {
+    match (installment_plan.deleted_at, installment.deleted_at) {
+        (Some(plan_deleted_at), Some(installment_deleted_at)) => {
+            installment_deleted_at >= plan_deleted_at
+        }
+        _ => false,
+    }
+}
+
+pub async fn find_all_deleted(
+    db: &DatabaseConnection,
+    user: &users::Model,
+) -> Result<Vec<InstallmentPlanProgress>, AppError> {
+    let found_installment_plans =
+        installment_plans_repository::find_all_deleted_by_user_id_order_by_deleted_at_desc(
+            db, user.id,
+        )
+        .await?;
+    let found_installments =
+        transactions_repository::find_all_deleted_by_installment_plan_ids_order_by_issued_at_asc(
+            db,
+            found_installment_plans
+                .iter()
+                .map(|installment_plan| installment_plan.id)
+                .collect(),
+        )
+        .await?;
+
+    let mut installments_by_plan_id: HashMap<i32, Vec<transactions::Model>> = HashMap::new();
+    for found_installment in found_installments {
+        if let Some(installment_plan_id) = found_installment.installment_plan_id {
+            installments_by_plan_id
+                .entry(installment_plan_id)
+                .or_default()
+                .push(found_installment);
+        }
+    }
+
+    let today = OffsetDateTime::now_utc().date();
+    Ok(found_installment_plans
+        .into_iter()
+        .map(|installment_plan| {
+            let installments = installments_by_plan_id
+                .remove(&installment_plan.id)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|installment| deleted_with_plan(&installment_plan, installment))
+                .collect();
+            split_progress(installment_plan, installments, today)
+        })
+        .collect())
+}
+
+pub async fn restore_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    request_id: Option<&str>,
+    installment_plan_id: i32,
+) -> Result<InstallmentPlanProgress, AppError> {
+    let txn = db.begin().await?;
+
+    let found_installment_plan = installment_plans_repository::get_deleted_by_id_and_user_id(
+        &txn,
+        installment_plan_id,
+        user.id,
+    )
+    .await?;
+    let Some(found_installment_plan) = found_installment_plan else {
+        return Err(AppError::NotFound(String::from(
+            "Installment plan not found in trash",
+        )));
+    };
+    let found_installments =
+        transactions_repository::find_all_deleted_by_installment_plan_ids_order_by_issued_at_asc(
+            &txn,
+            vec![found_installment_plan.id],
+        )
+        .await?;
+    for found_installment in found_installments {
+        if deleted_with_plan(&found_installment_plan, &found_installment) {
+            transactions_service::restore(&txn, user, request_id, found_installment).await?;
+        }
+    }
+
+    let mut found_installment_plan = found_installment_plan.into_active_model();
+    found_installment_plan.deleted_at = ActiveValue::Set(None);
+    found_installment_plan.updated_at = ActiveValue::Set(OffsetDateTime::now_utc());
+    installment_plans_repository::save(&txn, found_installment_plan).await?;
+
+    let progress = get_progress_by_id(
+        &txn,
+        user,
+        installment_plan_id,
+        OffsetDateTime::now_utc().date(),
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    Ok(progress)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_amount_gives_the_remainder_to_the_first_installment() {
+        assert_eq!(
+            split_amount(Decimal::from(100), 3, 2).unwrap(),
+            vec![
+                Decimal::new(3334, 2),
+                Decimal::new(3333, 2),
+                Decimal::new(3333, 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn split_amount_keeps_the_total() {
+        let installment_amounts = split_amount(Decimal::new(100001, 2), 7, 2).unwrap();
+
+        assert_eq!(installment_amounts.len(), 7);
+        assert_eq!(
+            installment_amounts.iter().sum::<Decimal>(),
+            Decimal::new(100001, 2)
+        );
+    }
+
+    #[test]
+    fn split_amount_respects_currencies_without_minor_units() {
+        assert_eq!(
+            split_amount(Decimal::from(1000), 3, 0).unwrap(),
+            vec![Decimal::from(334), Decimal::from(333), Decimal::from(333)]
+        );
+    }
+
+    #[test]
+    fn split_amount_rejects_installments_rounding_to_zero() {
+        assert!(split_amount(Decimal::new(2, 2), 3, 2).is_err());
+    }
+}
@@ -0,0 +1,141 @@
+use crate::dto::request::wallets_dto::ValidatedFindWalletBalanceHistoryParams;
+use crate::dto::response::net_worth_dto::{NetWorthPoint, NetWorthWalletTypeTotal};
+use crate::entities::users;
+use crate::errors::AppError;
+use crate::repositories::wallets_repository;
+use crate::services::{exchange_rates_service, wallet_balance_snapshots_service};
+use crate::utils::currency_utils::find_minor_units;
+use crate::utils::wallet_utils;
+use rust_decimal::{Decimal, RoundingStrategy};
+use sea_orm::DatabaseConnection;
+use std::collections::HashMap;
+use time::{Date, OffsetDateTime};
+
+async fn find_cached_rate(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    rates: &mut HashMap<(String, Date), Decimal>,
+    currency: &str,
+    rate_date: Date,
+) -> Result<Decimal, AppError> {
+    let key = (String::from(currency), rate_date);
+    if let Some(rate) = rates.get(&key) {
+        return Ok(*rate);
+    }
+
+    let (rate, _) =
+        exchange_rates_service::find_rate(db, user, currency, &user.reporting_currency, rate_date)
+            .await?;
+    rates.insert(key, rate);
+    Ok(rate)
+}
+
+// only the wallets the user owns count, shared wallets already count for their owner
+pub async fn get_current(
+    db: &DatabaseConnection,
+    user: &users::Model,
+) -> Result<(Date, Vec<NetWorthWalletTypeTotal>), AppError> {
+    let found_wallets =
+        wallets_repository::find_all_active_by_user_id_and_exclude_from_totals_is_false_order_by_name_asc(
+            db, user.id,
+        )
+        .await?;
+
+    let as_of = OffsetDateTime::now_utc().date();
+    let mut rates: HashMap<(String, Date), Decimal> = HashMap::new();
+    let mut wallet_type_totals: Vec<NetWorthWalletTypeTotal> = Vec::new();
+    for found_wallet in found_wallets {
+        let rate = find_cached_rate(db, user, &mut rates, &found_wallet.currency, as_of).await?;
+        let converted_balance = found_wallet.balance * rate;
+        match wallet_type_totals
+            .iter_mut()
+            .find(|wallet_type_total| wallet_type_total.wallet_type == found_wallet.wallet_type)
+        {
+            Some(wallet_type_total) => {
+                wallet_type_total.wallet_count += 1;
+                wallet_type_total.total += converted_balance;
+            }
+            None => wallet_type_totals.push(NetWorthWalletTypeTotal {
+                wallet_type: found_wallet.wallet_type,
+                wallet_count: 1,
+                total: converted_balance,
+            }),
+        }
+    }
+
+    let minor_units = find_minor_units(&user.reporting_currency).unwrap_or_default();
+    for wallet_type_total in &mut wallet_type_totals {
+        wallet_type_total.total = wallet_type_total
+            .total
+            .round_dp_with_strategy(minor_units, RoundingStrategy::MidpointAwayFromZero);
+    }
+    Ok((as_of, wallet_type_totals))
+}
+
+// every point is converted at the rate of its own date, so the history moves with the rates
+pub async fn find_history(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    params: &ValidatedFindWalletBalanceHistoryParams,
+) -> Result<Vec<NetWorthPoint>, AppError> {
+    let found_wallets =
+        wallets_repository::find_all_active_by_user_id_and_exclude_from_totals_is_false_order_by_name_asc(
+            db, user.id,
+        )
+        .await?;
+
+    let today = OffsetDateTime::now_utc().date();
+    let mut rates: HashMap<(String, Date), Decimal> = HashMap::new();
+    let mut points: Vec<NetWorthPoint> = Vec::new();
+    for found_wallet in found_wallets {
+        let wallet_points =
+            wallet_balance_snapshots_service::find_points(db, found_wallet.id, params).await?;
+        for (index, (date, balance)) in wallet_points.into_iter().enumerate() {
+            if points.len() <= index {
+                points.push(NetWorthPoint {
+                    date,
+                    assets: Decimal::ZERO,
+                    liabilities: Decimal::ZERO,
+                    complete: true,
+                });
+            }
+            if balance.is_zero() {
+                continue;
+            }
+
+            // find_rate already falls back to the nearest earlier rate, a point older than every
+            // rate leaves the balance out and is flagged instead of failing the whole series
+            let rate = match find_cached_rate(
+                db,
+                user,
+                &mut rates,
+                &found_wallet.currency,
+                date.min(today),
+            )
+            .await
+            {
+                Err(AppError::NotFound(_)) => {
+                    points[index].complete = false;
+                    continue;
+                }
+                rate => rate?,
+            };
+            if wallet_utils::is_liability(&found_wallet.wallet_type) {
+                points[index].liabilities -= balance * rate;
+            } else {
+                points[index].assets += balance * rate;
+            }
+        }
+    }
+
+    let minor_units = find_minor_units(&user.reporting_currency).unwrap_or_default();
+    for point in &mut points {
+        point.assets = point
+            .assets
+            .round_dp_with_strategy(minor_units, RoundingStrategy::MidpointAwayFromZero);
+        point.liabilities = point
+            .liabilities
+            .round_dp_with_strategy(minor_units, RoundingStrategy::MidpointAwayFromZero);
+    }
+    Ok(points)
+}
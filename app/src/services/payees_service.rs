@@ -0,0 +1,441 @@
+use crate::dto::request::payees_dto::{
+    CreatePayeeRequest, GetPayeeTotalsParams, UpdatePayeeRequest,
+};
+use crate::dto::response::payees_dto::GetPayeeTotalResponse;
+use crate::entities::sea_orm_active_enums::{
+    TransactionRevisionAction, TransactionStatus, TransactionType,
+};
+use crate::entities::{payee_aliases, payees, users};
+use crate::errors::AppError;
+use crate::repositories::{payee_aliases_repository, payees_repository, transactions_repository};
+use crate::services::{exchange_rates_service, transaction_revisions_service};
+use crate::utils::currency_utils::find_minor_units;
+use regex::{Regex, RegexBuilder};
+use rust_decimal::{Decimal, RoundingStrategy};
+use sea_orm::{
+    ActiveValue, ConnectionTrait, DatabaseConnection, IntoActiveModel, TransactionTrait,
+};
+use std::collections::HashMap;
+use time::OffsetDateTime;
+
+pub struct PayeeMatcher {
+    pub payee_id: i32,
+    patterns: Vec<String>,
+    regexes: Vec<Regex>,
+}
+
+impl PayeeMatcher {
+    pub fn matches(&self, description: &str, normalized_description: &str) -> Option<usize> {
+        // the longest matching alias is returned so the most specific payee wins
+        let padded_description = format!(" {normalized_description} ");
+        let pattern_match = self
+            .patterns
+            .iter()
+            .filter(|pattern| padded_description.contains(&format!(" {pattern} ")))
+            .map(String::len);
+        let regex_match = self
+            .regexes
+            .iter()
+            .filter_map(|regex| regex.find(description))
+            .map(|found| found.len());
+        pattern_match.chain(regex_match).max()
+    }
+}
+
+pub fn normalize_description(description: &str) -> String {
+    // "STARBUCKS #1234 JKT" becomes "starbucks jkt", store numbers and punctuation are dropped
+    description
+        .to_lowercase()
+        .split(|character: char| !character.is_alphanumeric())
+        .filter(|token| {
+            token
+                .chars()
+                .next()
+                .is_some_and(|character| !character.is_numeric())
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn build_payee_matcher(
+    payee: &payees::Model,
+    payee_aliases: &[payee_aliases::Model],
+) -> Result<PayeeMatcher, AppError> {
+    let mut patterns = vec![normalize_description(&payee.name)];
+    let mut regexes = Vec::new();
+    for payee_alias in payee_aliases {
+        if payee_alias.is_regex {
+            regexes.push(
+                RegexBuilder::new(&payee_alias.pattern)
+                    .case_insensitive(true)
+                    .build()?,
+            );
+        } else {
+            patterns.push(normalize_description(&payee_alias.pattern));
+        }
+    }
+    patterns.retain(|pattern| !pattern.is_empty());
+
+    Ok(PayeeMatcher {
+        payee_id: payee.id,
+        patterns,
+        regexes,
+    })
+}
+
+async fn save_payee_aliases(
+    connection: &impl ConnectionTrait,
+    payee_id: i32,
+    payload: &CreatePayeeRequest,
+) -> Result<Vec<payee_aliases::Model>, AppError> {
+    payee_aliases_repository::delete_all_by_payee_id(connection, payee_id).await?;
+
+    let mut new_payee_aliases = Vec::new();
+    for payee_alias in payload.aliases.iter().flatten() {
+        let is_regex = payee_alias.is_regex.unwrap_or_default();
+        if is_regex {
+            Regex::new(&payee_alias.pattern)?;
+        } else if normalize_description(&payee_alias.pattern).is_empty() {
+            return Err(AppError::ParseBody(format!(
+                "alias `{}` has nothing to match after normalisation",
+                payee_alias.pattern
+            )));
+        }
+        new_payee_aliases.push(payee_aliases::ActiveModel {
+            payee_id: ActiveValue::Set(payee_id),
+            pattern: ActiveValue::Set(payee_alias.pattern.clone()),
+            is_regex: ActiveValue::Set(is_regex),
+            ..Default::default()
+        });
+    }
+    payee_aliases_repository::insert_many(connection, new_payee_aliases).await?;
+
+    let found_payee_aliases =
+        payee_aliases_repository::find_all_by_payee_ids_order_by_id_asc(connection, vec![payee_id])
+            .await?;
+    Ok(found_payee_aliases)
+}
+
+pub async fn create(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    payload: CreatePayeeRequest,
+) -> Result<(payees::Model, Vec<payee_aliases::Model>), AppError> {
+    let txn = db.begin().await?;
+
+    let new_payee = payees::ActiveModel {
+        user_id: ActiveValue::Set(user.id),
+        name: ActiveValue::Set(payload.name.clone()),
+        ..Default::default()
+    };
+    let new_payee = payees_repository::save(&txn, new_payee).await?;
+    let new_payee_aliases = save_payee_aliases(&txn, new_payee.id, &payload).await?;
+
+    txn.commit().await?;
+
+    Ok((new_payee, new_payee_aliases))
+}
+
+pub async fn find_all(
+    db: &DatabaseConnection,
+    user: &users::Model,
+) -> Result<Vec<(payees::Model, Vec<payee_aliases::Model>)>, AppError> {
+    let found_payees =
+        payees_repository::find_all_active_by_user_id_order_by_name_asc(db, user.id).await?;
+    let mut found_payee_aliases =
+        find_aliases_by_payee_ids(db, found_payees.iter().map(|payee| payee.id).collect()).await?;
+
+    Ok(found_payees
+        .into_iter()
+        .map(|payee| {
+            let payee_aliases = found_payee_aliases.remove(&payee.id).unwrap_or_default();
+            (payee, payee_aliases)
+        })
+        .collect())
+}
+
+pub async fn get_by_id(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    payee_id: i32,
+) -> Result<payees::Model, AppError> {
+    let found_payee =
+        payees_repository::get_active_by_id_and_user_id(connection, payee_id, user.id).await?;
+    let Some(found_payee) = found_payee else {
+        return Err(AppError::NotFound(String::from("Payee not found")));
+    };
+    Ok(found_payee)
+}
+
+pub async fn get_with_aliases_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    payee_id: i32,
+) -> Result<(payees::Model, Vec<payee_aliases::Model>), AppError> {
+    let found_payee = get_by_id(db, user, payee_id).await?;
+    let found_payee_aliases =
+        payee_aliases_repository::find_all_by_payee_ids_order_by_id_asc(db, vec![found_payee.id])
+            .await?;
+    Ok((found_payee, found_payee_aliases))
+}
+
+pub async fn update_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    payee_id: i32,
+    payload: UpdatePayeeRequest,
+) -> Result<(payees::Model, Vec<payee_aliases::Model>), AppError> {
+    let txn = db.begin().await?;
+
+    let payload = CreatePayeeRequest::from(payload);
+    let mut found_payee = get_by_id(&txn, user, payee_id).await?.into_active_model();
+    found_payee.name = ActiveValue::Set(payload.name.clone());
+    found_payee.updated_at = ActiveValue::Set(OffsetDateTime::now_utc());
+
+    let updated_payee = payees_repository::save(&txn, found_payee).await?;
+    let updated_payee_aliases = save_payee_aliases(&txn, updated_payee.id, &payload).await?;
+
+    txn.commit().await?;
+
+    Ok((updated_payee, updated_payee_aliases))
+}
+
+pub async fn delete_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    payee_id: i32,
+) -> Result<(), AppError> {
+    let mut found_payee = get_by_id(db, user, payee_id).await?.into_active_model();
+    found_payee.deleted_at = ActiveValue::Set(Some(OffsetDateTime::now_utc()));
+    payees_repository::save(db, found_payee).await?;
+    Ok(())
+}
+
+pub async fn find_all_deleted(
+    db: &DatabaseConnection,
+    user: &users::Model,
+) -> Result<Vec<(payees::Model, Vec<payee_aliases::Model>)>, AppError> {
+    let found_payees =
+        payees_repository::find_all_deleted_by_user_id_order_by_deleted_at_desc(db, user.id)
+            .await?;
+    let mut found_payee_aliases =
+        find_aliases_by_payee_ids(db, found_payees.iter().map(|payee| payee.id).collect()).await?;
+
+    Ok(found_payees
+        .into_iter()
+        .map(|payee| {
+            let payee_aliases = found_payee_aliases.remove(&payee.id).unwrap_or_default();
+            (payee, payee_aliases)
+        })
+        .collect())
+}
+
+pub async fn restore_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    payee_id: i32,
+) -> Result<(payees::Model, Vec<payee_aliases::Model>), AppError> {
+    let found_payee =
+        payees_repository::get_deleted_by_id_and_user_id(db, payee_id, user.id).await?;
+    let Some(found_payee) = found_payee else {
+        return Err(AppError::NotFound(String::from("Payee not found in trash")));
+    };
+
+    let mut found_payee = found_payee.into_active_model();
+    found_payee.deleted_at = ActiveValue::Set(None);
+    found_payee.updated_at = ActiveValue::Set(OffsetDateTime::now_utc());
+
+    let restored_payee = payees_repository::save(db, found_payee).await?;
+    let found_payee_aliases = payee_aliases_repository::find_all_by_payee_ids_order_by_id_asc(
+        db,
+        vec![restored_payee.id],
+    )
+    .await?;
+    Ok((restored_payee, found_payee_aliases))
+}
+
+async fn find_aliases_by_payee_ids(
+    connection: &impl ConnectionTrait,
+    payee_ids: Vec<i32>,
+) -> Result<HashMap<i32, Vec<payee_aliases::Model>>, AppError> {
+    let found_payee_aliases =
+        payee_aliases_repository::find_all_by_payee_ids_order_by_id_asc(connection, payee_ids)
+            .await?;
+
+    let mut payee_aliases: HashMap<i32, Vec<payee_aliases::Model>> = HashMap::new();
+    for payee_alias in found_payee_aliases {
+        payee_aliases
+            .entry(payee_alias.payee_id)
+            .or_default()
+            .push(payee_alias);
+    }
+    Ok(payee_aliases)
+}
+
+pub async fn find_all_matchers(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+) -> Result<Vec<PayeeMatcher>, AppError> {
+    let found_payees =
+        payees_repository::find_all_active_by_user_id_order_by_name_asc(connection, user.id)
+            .await?;
+    let found_payee_aliases = find_aliases_by_payee_ids(
+        connection,
+        found_payees.iter().map(|payee| payee.id).collect(),
+    )
+    .await?;
+
+    found_payees
+        .iter()
+        .map(|payee| {
+            build_payee_matcher(
+                payee,
+                found_payee_aliases
+                    .get(&payee.id)
+                    .map(Vec::as_slice)
+                    .unwrap_or_default(),
+            )
+        })
+        .collect()
+}
+
+pub fn find_best_match(matchers: &[PayeeMatcher], description: Option<&str>) -> Option<i32> {
+    let description = description?;
+    let normalized_description = normalize_description(description);
+
+    let mut best_match: Option<(usize, i32)> = None;
+    for matcher in matchers {
+        if let Some(matched_length) = matcher.matches(description, &normalized_description)
+            && best_match.is_none_or(|(best_length, _)| matched_length > best_length)
+        {
+            best_match = Some((matched_length, matcher.payee_id));
+        }
+    }
+    best_match.map(|(_, payee_id)| payee_id)
+}
+
+pub async fn apply_all(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    request_id: Option<&str>,
+) -> Result<u64, AppError> {
+    let txn = db.begin().await?;
+
+    let matchers = find_all_matchers(&txn, user).await?;
+    let found_transactions =
+        transactions_repository::find_all_active_by_user_id_and_payee_id_is_null(&txn, user.id)
+            .await?;
+
+    let mut matched_transactions = 0;
+    for transaction in found_transactions {
+        // reconciled transactions are locked, matchers never touch them retroactively
+        if transaction.status == TransactionStatus::Reconciled {
+            continue;
+        }
+
+        let Some(payee_id) = find_best_match(&matchers, transaction.description.as_deref()) else {
+            continue;
+        };
+
+        let previous_transaction = transaction.clone();
+        let mut transaction = transaction.into_active_model();
+        transaction.payee_id = ActiveValue::Set(Some(payee_id));
+        let updated_transaction = transactions_repository::save(&txn, transaction).await?;
+        transaction_revisions_service::record(
+            &txn,
+            user,
+            request_id,
+            TransactionRevisionAction::Updated,
+            Some(&previous_transaction),
+            Some(&updated_transaction),
+        )
+        .await?;
+        matched_transactions += 1;
+    }
+
+    txn.commit().await?;
+
+    Ok(matched_transactions)
+}
+
+pub async fn find_totals(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    params: GetPayeeTotalsParams,
+) -> Result<Vec<GetPayeeTotalResponse>, AppError> {
+    let found_totals = payees_repository::find_totals_by_user_id_and_issued_at_between(
+        db,
+        user.id,
+        params.start_date,
+        params.end_date,
+    )
+    .await?;
+
+    // every currency is converted at the rate of the last day of the period
+    let rate_date = params
+        .end_date
+        .unwrap_or_else(|| OffsetDateTime::now_utc().date())
+        .min(OffsetDateTime::now_utc().date());
+    let mut rates: HashMap<String, Decimal> = HashMap::new();
+
+    // rows come ordered by payee, one row per flow direction and currency
+    let mut payee_totals: Vec<(i32, String, Decimal, Decimal, i64)> = Vec::new();
+    for found_total in found_totals {
+        if payee_totals
+            .last()
+            .is_none_or(|(payee_id, ..)| *payee_id != found_total.payee_id)
+        {
+            payee_totals.push((
+                found_total.payee_id,
+                found_total.name,
+                Decimal::ZERO,
+                Decimal::ZERO,
+                0,
+            ));
+        }
+
+        let (_, _, total_income, total_outcome, transaction_count) =
+            payee_totals.last_mut().unwrap();
+        let rate = match rates.get(&found_total.currency) {
+            Some(rate) => *rate,
+            None => {
+                let (rate, _) = exchange_rates_service::find_rate(
+                    db,
+                    user,
+                    &found_total.currency,
+                    &user.reporting_currency,
+                    rate_date,
+                )
+                .await?;
+                rates.insert(found_total.currency, rate);
+                rate
+            }
+        };
+        match found_total.flow_direction {
+            TransactionType::Income => *total_income += found_total.total_amount * rate,
+            TransactionType::Outcome => *total_outcome += found_total.total_amount * rate,
+        }
+        *transaction_count += found_total.transaction_count;
+    }
+
+    let minor_units = find_minor_units(&user.reporting_currency).unwrap_or_default();
+    Ok(payee_totals
+        .into_iter()
+        .map(
+            |(payee_id, name, total_income, total_outcome, transaction_count)| {
+                GetPayeeTotalResponse {
+                    payee_id,
+                    name,
+                    currency: user.reporting_currency.clone(),
+                    total_income: total_income
+                        .round_dp_with_strategy(minor_units, RoundingStrategy::MidpointAwayFromZero)
+                        .to_string(),
+                    total_outcome: total_outcome
+                        .round_dp_with_strategy(minor_units, RoundingStrategy::MidpointAwayFromZero)
+                        .to_string(),
+                    transaction_count,
+                }
+            },
+        )
+        .collect())
+}
@@ -0,0 +1,303 @@
+use crate::dto::request::reconciliations_dto::CreateReconciliationRequest;
+use crate::entities::sea_orm_active_enums::{
+    TransactionRevisionAction, TransactionStatus, TransactionType, WalletMemberRole,
+};
+use crate::entities::{reconciliations, transactions, users};
+use crate::errors::AppError;
+use crate::repositories::{reconciliations_repository, transactions_repository};
+use crate::services::{transaction_revisions_service, wallets_service};
+use crate::utils::currency_utils::parse_amount;
+use rust_decimal::Decimal;
+use sea_orm::{
+    ActiveValue, ConnectionTrait, DatabaseConnection, IntoActiveModel, TransactionTrait,
+};
+use time::OffsetDateTime;
+
+pub async fn create(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    wallet_id: i32,
+    payload: CreateReconciliationRequest,
+) -> Result<reconciliations::Model, AppError> {
+    let found_wallet =
+        wallets_service::get_by_id(db, user, wallet_id, WalletMemberRole::Owner).await?;
+    if reconciliations_repository::get_active_open_by_wallet_id(db, found_wallet.id)
+        .await?
+        .is_some()
+    {
+        return Err(AppError::Conflict(String::from(
+            "Wallet already has an open reconciliation",
+        )));
+    }
+
+    let new_reconciliation = reconciliations::ActiveModel {
+        user_id: ActiveValue::Set(user.id),
+        wallet_id: ActiveValue::Set(found_wallet.id),
+        statement_balance: ActiveValue::Set(parse_amount(
+            &payload.statement_balance,
+            &found_wallet.currency,
+        )?),
+        statement_date: ActiveValue::Set(payload.statement_date),
+        ..Default::default()
+    };
+    let new_reconciliation = reconciliations_repository::save(db, new_reconciliation).await?;
+    Ok(new_reconciliation)
+}
+
+pub async fn find_all(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    wallet_id: i32,
+) -> Result<Vec<reconciliations::Model>, AppError> {
+    let found_wallet =
+        wallets_service::get_by_id(db, user, wallet_id, WalletMemberRole::Owner).await?;
+    let found_reconciliations =
+        reconciliations_repository::find_all_active_by_wallet_id_and_user_id_order_by_statement_date_desc(
+            db,
+            found_wallet.id,
+            user.id,
+        )
+        .await?;
+    Ok(found_reconciliations)
+}
+
+pub async fn get_by_id(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    wallet_id: i32,
+    reconciliation_id: i32,
+) -> Result<reconciliations::Model, AppError> {
+    let found_reconciliation =
+        reconciliations_repository::get_active_by_id_and_wallet_id_and_user_id(
+            connection,
+            reconciliation_id,
+            wallet_id,
+            user.id,
+        )
+        .await?;
+    let Some(found_reconciliation) = found_reconciliation else {
+        return Err(AppError::NotFound(String::from("Reconciliation not found")));
+    };
+    Ok(found_reconciliation)
+}
+
+fn find_signed_amount(flow_direction: &TransactionType, amount: Decimal) -> Decimal {
+    match flow_direction {
+        TransactionType::Income => amount,
+        TransactionType::Outcome => -amount,
+    }
+}
+
+async fn get_balance_by_statuses(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    reconciliation: &reconciliations::Model,
+    statuses: Vec<TransactionStatus>,
+) -> Result<Decimal, AppError> {
+    let found_wallet = wallets_service::get_by_id(
+        connection,
+        user,
+        reconciliation.wallet_id,
+        WalletMemberRole::Owner,
+    )
+    .await?;
+    let found_amount_sums =
+        transactions_repository::find_amount_sums_by_wallet_id_and_statuses_and_issued_at_lte(
+            connection,
+            reconciliation.wallet_id,
+            statuses,
+            reconciliation.statement_date,
+        )
+        .await?;
+
+    let opening_balance =
+        wallets_service::find_opening_balance_as_of(&found_wallet, reconciliation.statement_date);
+    Ok(opening_balance
+        + found_amount_sums
+            .into_iter()
+            .map(|(flow_direction, total_amount)| find_signed_amount(&flow_direction, total_amount))
+            .sum::<Decimal>())
+}
+
+pub async fn get_summary_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    wallet_id: i32,
+    reconciliation_id: i32,
+) -> Result<(reconciliations::Model, Decimal, Vec<transactions::Model>), AppError> {
+    let found_reconciliation = get_by_id(db, user, wallet_id, reconciliation_id).await?;
+    let cleared_balance = get_balance_by_statuses(
+        db,
+        user,
+        &found_reconciliation,
+        vec![TransactionStatus::Cleared, TransactionStatus::Reconciled],
+    )
+    .await?;
+
+    let found_transactions = if found_reconciliation.completed_at.is_none() {
+        transactions_repository::find_all_active_unreconciled_by_wallet_id_and_issued_at_lte(
+            db,
+            found_reconciliation.wallet_id,
+            found_reconciliation.statement_date,
+        )
+        .await?
+    } else {
+        Vec::new()
+    };
+
+    Ok((found_reconciliation, cleared_balance, found_transactions))
+}
+
+pub async fn complete_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    request_id: Option<&str>,
+    wallet_id: i32,
+    reconciliation_id: i32,
+) -> Result<(reconciliations::Model, Decimal, Vec<transactions::Model>), AppError> {
+    let txn = db.begin().await?;
+
+    let found_reconciliation = get_by_id(&txn, user, wallet_id, reconciliation_id).await?;
+    if found_reconciliation.completed_at.is_some() {
+        return Err(AppError::Conflict(String::from(
+            "Reconciliation is already completed",
+        )));
+    }
+
+    // the cleared transactions are locked first so the balance checked is the one reconciled
+    let found_transactions =
+        transactions_repository::find_all_active_cleared_by_wallet_id_and_issued_at_lte_for_update(
+            &txn,
+            found_reconciliation.wallet_id,
+            found_reconciliation.statement_date,
+        )
+        .await?;
+    let reconciled_balance = get_balance_by_statuses(
+        &txn,
+        user,
+        &found_reconciliation,
+        vec![TransactionStatus::Reconciled],
+    )
+    .await?;
+    let cleared_balance = reconciled_balance
+        + found_transactions
+            .iter()
+            .map(|transaction| find_signed_amount(&transaction.flow_direction, transaction.amount))
+            .sum::<Decimal>();
+    let difference = found_reconciliation.statement_balance - cleared_balance;
+    if !difference.is_zero() {
+        return Err(AppError::Conflict(format!(
+            "Cleared balance differs from the statement balance by {difference}"
+        )));
+    }
+
+    transactions_repository::update_all_cleared_by_wallet_id_and_issued_at_lte_set_reconciled(
+        &txn,
+        found_reconciliation.wallet_id,
+        found_reconciliation.statement_date,
+        found_reconciliation.id,
+    )
+    .await?;
+    for found_transaction in found_transactions {
+        let reconciled_transaction = transactions::Model {
+            status: TransactionStatus::Reconciled,
+            reconciliation_id: Some(found_reconciliation.id),
+            ..found_transaction.clone()
+        };
+        transaction_revisions_service::record(
+            &txn,
+            user,
+            request_id,
+            TransactionRevisionAction::Updated,
+            Some(&found_transaction),
+            Some(&reconciled_transaction),
+        )
+        .await?;
+    }
+
+    let mut found_reconciliation = found_reconciliation.into_active_model();
+    let now = OffsetDateTime::now_utc();
+    found_reconciliation.completed_at = ActiveValue::Set(Some(now));
+    found_reconciliation.updated_at = ActiveValue::Set(now);
+    let completed_reconciliation =
+        reconciliations_repository::save(&txn, found_reconciliation).await?;
+
+    txn.commit().await?;
+
+    Ok((completed_reconciliation, cleared_balance, Vec::new()))
+}
+
+pub async fn delete_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    wallet_id: i32,
+    reconciliation_id: i32,
+) -> Result<(), AppError> {
+    let found_reconciliation = get_by_id(db, user, wallet_id, reconciliation_id).await?;
+    if found_reconciliation.completed_at.is_some() {
+        return Err(AppError::Conflict(String::from(
+            "Completed reconciliations cannot be deleted",
+        )));
+    }
+
+    let mut found_reconciliation = found_reconciliation.into_active_model();
+    found_reconciliation.deleted_at = ActiveValue::Set(Some(OffsetDateTime::now_utc()));
+    reconciliations_repository::save(db, found_reconciliation).await?;
+    Ok(())
+}
+
+pub async fn find_all_deleted(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    wallet_id: i32,
+) -> Result<Vec<reconciliations::Model>, AppError> {
+    let found_wallet =
+        wallets_service::get_by_id(db, user, wallet_id, WalletMemberRole::Owner).await?;
+    let found_reconciliations =
+        reconciliations_repository::find_all_deleted_by_wallet_id_and_user_id_order_by_deleted_at_desc(
+            db,
+            found_wallet.id,
+            user.id,
+        )
+        .await?;
+    Ok(found_reconciliations)
+}
+
+pub async fn restore_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    wallet_id: i32,
+    reconciliation_id: i32,
+) -> Result<reconciliations::Model, AppError> {
+    let found_wallet =
+        wallets_service::get_by_id(db, user, wallet_id, WalletMemberRole::Owner).await?;
+    let found_reconciliation =
+        reconciliations_repository::get_deleted_by_id_and_wallet_id_and_user_id(
+            db,
+            reconciliation_id,
+            found_wallet.id,
+            user.id,
+        )
+        .await?;
+    let Some(found_reconciliation) = found_reconciliation else {
+        return Err(AppError::NotFound(String::from(
+            "Reconciliation not found in trash",
+        )));
+    };
+    // only completed reconciliations cannot be deleted, so every restored one is open again
+    if reconciliations_repository::get_active_open_by_wallet_id(db, found_wallet.id)
+        .await?
+        .is_some()
+    {
+        return Err(AppError::Conflict(String::from(
+            "Wallet already has an open reconciliation",
+        )));
+    }
+
+    let mut found_reconciliation = found_reconciliation.into_active_model();
+    found_reconciliation.deleted_at = ActiveValue::Set(None);
+    found_reconciliation.updated_at = ActiveValue::Set(OffsetDateTime::now_utc());
+    let restored_reconciliation =
+        reconciliations_repository::save(db, found_reconciliation).await?;
+    Ok(restored_reconciliation)
+}
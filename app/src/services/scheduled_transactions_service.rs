@@ -0,0 +1,89 @@
+use crate::entities::sea_orm_active_enums::{TransactionRevisionAction, WalletMemberRole};
+use crate::entities::transactions;
+use crate::errors::AppError;
+use crate::repositories::{transactions_repository, users_repository};
+use crate::services::{
+    budgets_service, transaction_revisions_service, transactions_service, wallets_service,
+};
+use sea_orm::{ActiveValue, DatabaseConnection, IntoActiveModel, TransactionTrait};
+use time::OffsetDateTime;
+
+pub async fn post_all_due(db: &DatabaseConnection) -> Result<u64, AppError> {
+    let found_transactions =
+        transactions_repository::find_all_active_unposted_by_issued_at_lte_order_by_issued_at_asc(
+            db,
+            OffsetDateTime::now_utc().date(),
+        )
+        .await?;
+
+    let mut posted_count = 0;
+    for found_transaction in found_transactions {
+        let transaction_id = found_transaction.id;
+        match post(db, found_transaction).await {
+            Ok(true) => posted_count += 1,
+            Ok(false) => {}
+            Err(err) => {
+                tracing::warn!("Failed to post scheduled transaction {transaction_id}: {err:?}");
+            }
+        }
+    }
+    Ok(posted_count)
+}
+
+async fn post(db: &DatabaseConnection, transaction: transactions::Model) -> Result<bool, AppError> {
+    let Some((found_user, _)) = users_repository::find_by_pk(db, transaction.user_id).await? else {
+        return Ok(false);
+    };
+
+    let txn = db.begin().await?;
+
+    // another worker may have posted or deleted it in the meantime
+    let Some(found_transaction) =
+        transactions_repository::get_active_unposted_by_id_for_update(&txn, transaction.id).await?
+    else {
+        return Ok(false);
+    };
+
+    // wait with posting while the wallet or budget sits in the trash
+    let found_wallet = match wallets_service::get_by_id(
+        &txn,
+        &found_user,
+        found_transaction.wallet_id,
+        WalletMemberRole::Owner,
+    )
+    .await
+    {
+        Err(AppError::NotFound(_)) => return Ok(false),
+        found_wallet => found_wallet?,
+    };
+
+    let now = OffsetDateTime::now_utc();
+    let mut posted_transaction = found_transaction.clone().into_active_model();
+    posted_transaction.posted_at = ActiveValue::Set(Some(now));
+    posted_transaction.updated_at = ActiveValue::Set(now);
+    let posted_transaction = transactions_repository::save(&txn, posted_transaction).await?;
+
+    if let Some((budget_id, budget_amount)) =
+        transactions_service::budget_effect(&posted_transaction)
+    {
+        let found_budget = match budgets_service::get_by_id(&txn, &found_user, budget_id).await {
+            Err(AppError::NotFound(_)) => return Ok(false),
+            found_budget => found_budget?,
+        };
+        budgets_service::update_amount_after_transaction(&txn, found_budget, budget_amount).await?;
+    }
+    wallets_service::update_balance_after_transaction(&txn, found_wallet, &posted_transaction)
+        .await?;
+
+    transaction_revisions_service::record_system(
+        &txn,
+        TransactionRevisionAction::Updated,
+        Some(&found_transaction),
+        Some(&posted_transaction),
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    Ok(true)
+}
@@ -0,0 +1,197 @@
+use crate::dto::request::tags_dto::{CreateTagRequest, GetTagTotalsParams, UpdateTagRequest};
+use crate::dto::response::tags_dto::GetTagTotalResponse;
+use crate::entities::sea_orm_active_enums::TransactionType;
+use crate::entities::{tags, users};
+use crate::errors::AppError;
+use crate::repositories::tags_repository;
+use crate::services::exchange_rates_service;
+use crate::utils::currency_utils::find_minor_units;
+use rust_decimal::{Decimal, RoundingStrategy};
+use sea_orm::{ActiveValue, ConnectionTrait, DatabaseConnection, IntoActiveModel};
+use std::collections::HashMap;
+use time::OffsetDateTime;
+
+pub async fn create(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    payload: CreateTagRequest,
+) -> Result<tags::Model, AppError> {
+    let new_tag = tags::ActiveModel {
+        user_id: ActiveValue::Set(user.id),
+        name: ActiveValue::Set(payload.name),
+        ..Default::default()
+    };
+    let new_tag = tags_repository::save(db, new_tag).await?;
+    Ok(new_tag)
+}
+
+pub async fn find_all(
+    db: &DatabaseConnection,
+    user: &users::Model,
+) -> Result<Vec<tags::Model>, AppError> {
+    let found_tags =
+        tags_repository::find_all_active_by_user_id_order_by_name_asc(db, user.id).await?;
+    Ok(found_tags)
+}
+
+pub async fn get_by_id(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    tag_id: i32,
+) -> Result<tags::Model, AppError> {
+    let found_tag =
+        tags_repository::get_active_by_id_and_user_id(connection, tag_id, user.id).await?;
+    let Some(found_tag) = found_tag else {
+        return Err(AppError::NotFound(String::from("Tag not found")));
+    };
+    Ok(found_tag)
+}
+
+pub async fn get_all_by_ids(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    tag_ids: &[i32],
+) -> Result<Vec<tags::Model>, AppError> {
+    let mut tag_ids = tag_ids.to_vec();
+    tag_ids.sort_unstable();
+    tag_ids.dedup();
+
+    let found_tags =
+        tags_repository::find_all_active_by_ids_and_user_id(connection, tag_ids.clone(), user.id)
+            .await?;
+    if found_tags.len() != tag_ids.len() {
+        return Err(AppError::NotFound(String::from("Tag not found")));
+    }
+    Ok(found_tags)
+}
+
+pub async fn update_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    tag_id: i32,
+    payload: UpdateTagRequest,
+) -> Result<tags::Model, AppError> {
+    let mut found_tag = get_by_id(db, user, tag_id).await?.into_active_model();
+    found_tag.name = ActiveValue::Set(payload.name);
+    found_tag.updated_at = ActiveValue::Set(OffsetDateTime::now_utc());
+
+    let updated_tag = tags_repository::save(db, found_tag).await?;
+    Ok(updated_tag)
+}
+
+pub async fn delete_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    tag_id: i32,
+) -> Result<(), AppError> {
+    let mut found_tag = get_by_id(db, user, tag_id).await?.into_active_model();
+    found_tag.deleted_at = ActiveValue::Set(Some(OffsetDateTime::now_utc()));
+    tags_repository::save(db, found_tag).await?;
+    Ok(())
+}
+
+pub async fn find_all_deleted(
+    db: &DatabaseConnection,
+    user: &users::Model,
+) -> Result<Vec<tags::Model>, AppError> {
+    let found_tags =
+        tags_repository::find_all_deleted_by_user_id_order_by_deleted_at_desc(db, user.id).await?;
+    Ok(found_tags)
+}
+
+pub async fn restore_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    tag_id: i32,
+) -> Result<tags::Model, AppError> {
+    let found_tag = tags_repository::get_deleted_by_id_and_user_id(db, tag_id, user.id).await?;
+    let Some(found_tag) = found_tag else {
+        return Err(AppError::NotFound(String::from("Tag not found in trash")));
+    };
+
+    let mut found_tag = found_tag.into_active_model();
+    found_tag.deleted_at = ActiveValue::Set(None);
+    found_tag.updated_at = ActiveValue::Set(OffsetDateTime::now_utc());
+
+    let restored_tag = tags_repository::save(db, found_tag).await?;
+    Ok(restored_tag)
+}
+
+pub async fn find_totals(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    params: GetTagTotalsParams,
+) -> Result<Vec<GetTagTotalResponse>, AppError> {
+    let found_totals = tags_repository::find_totals_by_user_id_and_issued_at_between(
+        db,
+        user.id,
+        params.start_date,
+        params.end_date,
+    )
+    .await?;
+
+    // every currency is converted at the rate of the last day of the period
+    let rate_date = params
+        .end_date
+        .unwrap_or_else(|| OffsetDateTime::now_utc().date())
+        .min(OffsetDateTime::now_utc().date());
+    let mut rates: HashMap<String, Decimal> = HashMap::new();
+
+    // rows come ordered by tag, one row per flow direction and currency
+    let mut tag_totals: Vec<(i32, String, Decimal, Decimal, i64)> = Vec::new();
+    for found_total in found_totals {
+        if tag_totals
+            .last()
+            .is_none_or(|(tag_id, ..)| *tag_id != found_total.tag_id)
+        {
+            tag_totals.push((
+                found_total.tag_id,
+                found_total.name,
+                Decimal::ZERO,
+                Decimal::ZERO,
+                0,
+            ));
+        }
+
+        let (_, _, total_income, total_outcome, transaction_count) = tag_totals.last_mut().unwrap();
+        let rate = match rates.get(&found_total.currency) {
+            Some(rate) => *rate,
+            None => {
+                let (rate, _) = exchange_rates_service::find_rate(
+                    db,
+                    user,
+                    &found_total.currency,
+                    &user.reporting_currency,
+                    rate_date,
+                )
+                .await?;
+                rates.insert(found_total.currency, rate);
+                rate
+            }
+        };
+        match found_total.flow_direction {
+            TransactionType::Income => *total_income += found_total.total_amount * rate,
+            TransactionType::Outcome => *total_outcome += found_total.total_amount * rate,
+        }
+        *transaction_count += found_total.transaction_count;
+    }
+
+    let minor_units = find_minor_units(&user.reporting_currency).unwrap_or_default();
+    Ok(tag_totals
+        .into_iter()
+        .map(
+            |(tag_id, name, total_income, total_outcome, transaction_count)| GetTagTotalResponse {
+                tag_id,
+                name,
+                currency: user.reporting_currency.clone(),
+                total_income: total_income
+                    .round_dp_with_strategy(minor_units, RoundingStrategy::MidpointAwayFromZero)
+                    .to_string(),
+                total_outcome: total_outcome
+                    .round_dp_with_strategy(minor_units, RoundingStrategy::MidpointAwayFromZero)
+                    .to_string(),
+                transaction_count,
+            },
+        )
+        .collect())
+}
@@ -0,0 +1,235 @@
+use crate::constants::attachment_constants::{
+    ATTACHMENT_RETENTION_DAYS, MAX_ATTACHMENT_FILE_NAME_LENGTH, MAX_ATTACHMENT_SIZE,
+};
+use crate::entities::sea_orm_active_enums::WalletMemberRole;
+use crate::entities::{transaction_attachments, users};
+use crate::enums::attachment_content_type::AttachmentContentType;
+use crate::errors::AppError;
+use crate::repositories::transaction_attachments_repository;
+use crate::services::transactions_service;
+use crate::storage::storage_backend::StorageBackend;
+use sea_orm::{ActiveValue, ConnectionTrait, DatabaseConnection, IntoActiveModel};
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+fn sanitize_file_name(file_name: Option<&str>, content_type: AttachmentContentType) -> String {
+    // keep only the last path segment and drop characters that would break the
+    // Content-Disposition header on download
+    let file_name: String = file_name
+        .and_then(|file_name| file_name.rsplit(['/', '\\']).next())
+        .unwrap_or_default()
+        .chars()
+        .filter(|character| !character.is_control() && *character != '"')
+        .take(MAX_ATTACHMENT_FILE_NAME_LENGTH)
+        .collect();
+    let file_name = file_name.trim();
+
+    if file_name.is_empty() || file_name.chars().all(|character| character == '.') {
+        return format!("attachment.{}", content_type.get_extension());
+    }
+    String::from(file_name)
+}
+
+pub async fn create(
+    db: &DatabaseConnection,
+    storage: &dyn StorageBackend,
+    user: &users::Model,
+    transaction_id: i32,
+    file_name: Option<&str>,
+    content: &[u8],
+) -> Result<transaction_attachments::Model, AppError> {
+    let found_transaction =
+        transactions_service::get_by_id(db, user, transaction_id, WalletMemberRole::Owner).await?;
+
+    if content.is_empty() {
+        return Err(AppError::ParseBody(String::from(
+            "attachment cannot be empty",
+        )));
+    }
+    if content.len() > MAX_ATTACHMENT_SIZE {
+        return Err(AppError::PayloadTooLarge(format!(
+            "attachment cannot be larger than {MAX_ATTACHMENT_SIZE} bytes"
+        )));
+    }
+    let content_type = AttachmentContentType::from_bytes(content)?;
+
+    let storage_key = format!(
+        "users/{}/transactions/{}/{}.{}",
+        user.id,
+        found_transaction.id,
+        Uuid::now_v7(),
+        content_type.get_extension()
+    );
+    storage
+        .put(&storage_key, content, content_type.get_content_type())
+        .await?;
+
+    let new_transaction_attachment = transaction_attachments::ActiveModel {
+        user_id: ActiveValue::Set(user.id),
+        transaction_id: ActiveValue::Set(found_transaction.id),
+        file_name: ActiveValue::Set(sanitize_file_name(file_name, content_type)),
+        content_type: ActiveValue::Set(String::from(content_type.get_content_type())),
+        size: ActiveValue::Set(content.len() as i64),
+        storage_key: ActiveValue::Set(storage_key.clone()),
+        ..Default::default()
+    };
+    match transaction_attachments_repository::save(db, new_transaction_attachment).await {
+        Ok(new_transaction_attachment) => Ok(new_transaction_attachment),
+        Err(err) => {
+            if let Err(storage_err) = storage.delete(&storage_key).await {
+                tracing::warn!(
+                    "Failed to remove orphaned attachment {storage_key}: {storage_err:?}"
+                );
+            }
+            Err(err)
+        }
+    }
+}
+
+pub async fn find_all(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    transaction_id: i32,
+) -> Result<Vec<transaction_attachments::Model>, AppError> {
+    let found_transaction =
+        transactions_service::get_by_id(db, user, transaction_id, WalletMemberRole::Owner).await?;
+    let found_transaction_attachments =
+        transaction_attachments_repository::find_all_active_by_transaction_id_and_user_id_order_by_created_at_asc(
+            db,
+            found_transaction.id,
+            user.id,
+        )
+        .await?;
+    Ok(found_transaction_attachments)
+}
+
+pub async fn get_by_id(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    transaction_id: i32,
+    transaction_attachment_id: i32,
+) -> Result<transaction_attachments::Model, AppError> {
+    let found_transaction_attachment =
+        transaction_attachments_repository::get_active_by_id_and_transaction_id_and_user_id(
+            connection,
+            transaction_attachment_id,
+            transaction_id,
+            user.id,
+        )
+        .await?;
+    let Some(found_transaction_attachment) = found_transaction_attachment else {
+        return Err(AppError::NotFound(String::from(
+            "Transaction attachment not found",
+        )));
+    };
+    Ok(found_transaction_attachment)
+}
+
+pub async fn download_by_id(
+    db: &DatabaseConnection,
+    storage: &dyn StorageBackend,
+    user: &users::Model,
+    transaction_id: i32,
+    transaction_attachment_id: i32,
+) -> Result<(transaction_attachments::Model, Vec<u8>), AppError> {
+    let found_transaction_attachment =
+        get_by_id(db, user, transaction_id, transaction_attachment_id).await?;
+    let content = storage
+        .get(&found_transaction_attachment.storage_key)
+        .await?;
+    Ok((found_transaction_attachment, content))
+}
+
+// the stored file stays until the attachment is purged so it can still be restored
+pub async fn delete_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    transaction_id: i32,
+    transaction_attachment_id: i32,
+) -> Result<(), AppError> {
+    let found_transaction_attachment =
+        get_by_id(db, user, transaction_id, transaction_attachment_id).await?;
+
+    let mut found_transaction_attachment = found_transaction_attachment.into_active_model();
+    found_transaction_attachment.deleted_at = ActiveValue::Set(Some(OffsetDateTime::now_utc()));
+    transaction_attachments_repository::save(db, found_transaction_attachment).await?;
+
+    Ok(())
+}
+
+pub async fn find_all_deleted(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    transaction_id: i32,
+) -> Result<Vec<transaction_attachments::Model>, AppError> {
+    let found_transaction =
+        transactions_service::get_by_id(db, user, transaction_id, WalletMemberRole::Owner).await?;
+    let found_transaction_attachments =
+        transaction_attachments_repository::find_all_deleted_by_transaction_id_and_user_id_order_by_deleted_at_desc(
+            db,
+            found_transaction.id,
+            user.id,
+        )
+        .await?;
+    Ok(found_transaction_attachments)
+}
+
+pub async fn restore_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    transaction_id: i32,
+    transaction_attachment_id: i32,
+) -> Result<transaction_attachments::Model, AppError> {
+    let found_transaction =
+        transactions_service::get_by_id(db, user, transaction_id, WalletMemberRole::Owner).await?;
+    let found_transaction_attachment =
+        transaction_attachments_repository::get_deleted_by_id_and_transaction_id_and_user_id(
+            db,
+            transaction_attachment_id,
+            found_transaction.id,
+            user.id,
+        )
+        .await?;
+    let Some(found_transaction_attachment) = found_transaction_attachment else {
+        return Err(AppError::NotFound(String::from(
+            "Transaction attachment not found in trash",
+        )));
+    };
+
+    let mut found_transaction_attachment = found_transaction_attachment.into_active_model();
+    found_transaction_attachment.deleted_at = ActiveValue::Set(None);
+    found_transaction_attachment.updated_at = ActiveValue::Set(OffsetDateTime::now_utc());
+    let restored_transaction_attachment =
+        transaction_attachments_repository::save(db, found_transaction_attachment).await?;
+    Ok(restored_transaction_attachment)
+}
+
+// removes the stored file before the row, an attachment whose file cannot be removed is kept for
+// the next run instead of leaving the file behind without anything pointing at it
+pub async fn purge_deleted(
+    db: &DatabaseConnection,
+    storage: &dyn StorageBackend,
+) -> Result<usize, AppError> {
+    let deleted_before = OffsetDateTime::now_utc() - Duration::days(ATTACHMENT_RETENTION_DAYS);
+    let found_transaction_attachments =
+        transaction_attachments_repository::find_all_deleted_by_deleted_at_lt(db, deleted_before)
+            .await?;
+
+    let mut purged_attachments = 0;
+    for found_transaction_attachment in found_transaction_attachments {
+        if let Err(err) = storage
+            .delete(&found_transaction_attachment.storage_key)
+            .await
+        {
+            tracing::warn!(
+                "Failed to remove attachment {}: {err:?}",
+                found_transaction_attachment.storage_key
+            );
+            continue;
+        }
+        transaction_attachments_repository::delete_by_id(db, found_transaction_attachment.id)
+            .await?;
+        purged_attachments += 1;
+    }
+    Ok(purged_attachments)
+}
@@ -0,0 +1,279 @@
+use crate::constants::transaction_constants::{
+    MAX_BULK_TRANSACTION_IDS, MAX_BULK_TRANSACTION_OPERATIONS,
+};
+use crate::dto::request::transactions_dto::{
+    BulkTransactionOperationRequest, BulkTransactionsRequest,
+};
+use crate::dto::response::transactions_dto::{
+    BulkTransactionResultResponse, BulkTransactionsResponse,
+};
+use crate::entities::sea_orm_active_enums::{TransactionRevisionAction, WalletMemberRole};
+use crate::entities::{transactions, users};
+use crate::enums::bulk_transaction_action::BulkTransactionAction;
+use crate::errors::AppError;
+use crate::services::{
+    budgets_service, categories_service, transaction_revisions_service, transaction_tags_service,
+    transactions_service,
+};
+use axum::http::StatusCode;
+use sea_orm::{ConnectionTrait, DatabaseConnection, DatabaseTransaction, TransactionTrait};
+
+pub async fn execute(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    request_id: Option<&str>,
+    payload: BulkTransactionsRequest,
+) -> Result<BulkTransactionsResponse, AppError> {
+    if payload.operations.is_empty() || payload.operations.len() > MAX_BULK_TRANSACTION_OPERATIONS {
+        return Err(AppError::ParseBody(format!(
+            "operations must contain between 1 and {MAX_BULK_TRANSACTION_OPERATIONS} items"
+        )));
+    }
+    let total_ids: usize = payload
+        .operations
+        .iter()
+        .map(|operation| operation.ids.as_ref().map_or(0, Vec::len))
+        .sum();
+    if total_ids > MAX_BULK_TRANSACTION_IDS {
+        return Err(AppError::ParseBody(format!(
+            "operations cannot reference more than {MAX_BULK_TRANSACTION_IDS} ids in total"
+        )));
+    }
+
+    let txn = db.begin().await?;
+    let mut results = Vec::new();
+
+    // every item runs inside its own savepoint so that a failing item does not abort the
+    // remaining ones, while the outer transaction is only committed when all of them succeed
+    for (operation_index, operation) in payload.operations.into_iter().enumerate() {
+        let action = match BulkTransactionAction::from_string(&operation.action) {
+            Ok(action) => action,
+            Err(err) => {
+                results.push(to_result(
+                    operation_index,
+                    &operation.action,
+                    None,
+                    Err(err),
+                ));
+                continue;
+            }
+        };
+
+        match action {
+            BulkTransactionAction::Create => {
+                let action_name = operation.action;
+                let result = match operation.transaction {
+                    Some(transaction) => {
+                        let savepoint = txn.begin().await?;
+                        let result =
+                            transactions_service::insert(&savepoint, user, request_id, transaction)
+                                .await
+                                .map(|new_transaction| new_transaction.id);
+                        finish_savepoint(savepoint, &result).await?;
+                        result
+                    }
+                    None => Err(AppError::ParseBody(String::from(
+                        "transaction is required for create operations",
+                    ))),
+                };
+                let transaction_id = result.as_ref().ok().copied();
+                results.push(to_result(
+                    operation_index,
+                    &action_name,
+                    transaction_id,
+                    result.map(|_| StatusCode::CREATED),
+                ));
+            }
+            BulkTransactionAction::Update | BulkTransactionAction::Delete => {
+                let transaction_ids = match validate_operation(action, &operation) {
+                    Ok(transaction_ids) => transaction_ids,
+                    Err(err) => {
+                        results.push(to_result(
+                            operation_index,
+                            &operation.action,
+                            None,
+                            Err(err),
+                        ));
+                        continue;
+                    }
+                };
+
+                for transaction_id in transaction_ids {
+                    let savepoint = txn.begin().await?;
+                    let result = if action == BulkTransactionAction::Update {
+                        update_fields(&savepoint, user, request_id, transaction_id, &operation)
+                            .await
+                            .map(|_| StatusCode::OK)
+                    } else {
+                        delete(&savepoint, user, request_id, transaction_id)
+                            .await
+                            .map(|_| StatusCode::NO_CONTENT)
+                    };
+                    finish_savepoint(savepoint, &result).await?;
+                    results.push(to_result(
+                        operation_index,
+                        &operation.action,
+                        Some(transaction_id),
+                        result,
+                    ));
+                }
+            }
+        }
+    }
+
+    let committed = results.iter().all(|result| result.status < 400);
+    if committed {
+        txn.commit().await?;
+    } else {
+        txn.rollback().await?;
+    }
+
+    Ok(BulkTransactionsResponse { committed, results })
+}
+
+fn validate_operation(
+    action: BulkTransactionAction,
+    operation: &BulkTransactionOperationRequest,
+) -> Result<Vec<i32>, AppError> {
+    let Some(ref transaction_ids) = operation.ids else {
+        return Err(AppError::ParseBody(String::from(
+            "ids is required for update and delete operations",
+        )));
+    };
+    if transaction_ids.is_empty() {
+        return Err(AppError::ParseBody(String::from("ids cannot be empty")));
+    }
+
+    if action == BulkTransactionAction::Update
+        && operation.category_id.is_none()
+        && operation.budget_id.is_none()
+        && operation.wallet_id.is_none()
+        && operation.tag_ids.is_none()
+    {
+        return Err(AppError::ParseBody(String::from(
+            "at least one of category_id, budget_id, wallet_id or tag_ids is required",
+        )));
+    }
+
+    let mut transaction_ids = transaction_ids.clone();
+    transaction_ids.sort_unstable();
+    transaction_ids.dedup();
+    Ok(transaction_ids)
+}
+
+async fn update_fields(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    request_id: Option<&str>,
+    transaction_id: i32,
+    operation: &BulkTransactionOperationRequest,
+) -> Result<transactions::Model, AppError> {
+    let mut found_transaction =
+        transactions_service::get_by_id(connection, user, transaction_id, WalletMemberRole::Owner)
+            .await?;
+    // reconciled ids are rejected one by one and reported in the result of their operation
+    transactions_service::ensure_not_reconciled(&found_transaction)?;
+
+    if let Some(wallet_id) = operation.wallet_id {
+        found_transaction = transactions_service::move_to_wallet(
+            connection,
+            user,
+            request_id,
+            found_transaction,
+            wallet_id,
+        )
+        .await?;
+    }
+
+    if operation.category_id.is_some() || operation.budget_id.is_some() {
+        let category_id = match operation.category_id {
+            Some(category_id) => {
+                categories_service::get_by_id(connection, user, category_id)
+                    .await?
+                    .id
+            }
+            None => found_transaction.category_id,
+        };
+        let budget_id = match operation.budget_id {
+            Some(budget_id) => Some(
+                budgets_service::get_by_id(connection, user, budget_id)
+                    .await?
+                    .id,
+            ),
+            None => found_transaction.budget_id,
+        };
+        found_transaction = transactions_service::reassign(
+            connection,
+            user,
+            request_id,
+            found_transaction,
+            category_id,
+            budget_id,
+        )
+        .await?;
+    }
+
+    if let Some(ref tag_ids) = operation.tag_ids {
+        transaction_tags_service::replace_tags(connection, user, found_transaction.id, tag_ids)
+            .await?;
+        // only the tags changed, the revision compares them with the previous one
+        transaction_revisions_service::record(
+            connection,
+            user,
+            request_id,
+            TransactionRevisionAction::Updated,
+            Some(&found_transaction),
+            Some(&found_transaction),
+        )
+        .await?;
+    }
+
+    Ok(found_transaction)
+}
+
+async fn delete(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    request_id: Option<&str>,
+    transaction_id: i32,
+) -> Result<(), AppError> {
+    let found_transaction =
+        transactions_service::get_by_id(connection, user, transaction_id, WalletMemberRole::Owner)
+            .await?;
+    transactions_service::delete(connection, user, request_id, found_transaction).await?;
+    Ok(())
+}
+
+async fn finish_savepoint<T>(
+    savepoint: DatabaseTransaction,
+    result: &Result<T, AppError>,
+) -> Result<(), AppError> {
+    match result {
+        Ok(_) => savepoint.commit().await?,
+        Err(_) => savepoint.rollback().await?,
+    }
+    Ok(())
+}
+
+fn to_result(
+    operation_index: usize,
+    action: &str,
+    transaction_id: Option<i32>,
+    result: Result<StatusCode, AppError>,
+) -> BulkTransactionResultResponse {
+    let (status, message) = match result {
+        Ok(status) => (status, None),
+        Err(err) => {
+            let (status, response) = err.to_status_and_response();
+            (status, Some(response.message))
+        }
+    };
+
+    BulkTransactionResultResponse {
+        operation_index,
+        action: action.to_lowercase(),
+        transaction_id,
+        status: status.as_u16(),
+        message,
+    }
+}
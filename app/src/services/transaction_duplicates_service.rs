@@ -0,0 +1,189 @@
+use crate::dto::request::transactions_dto::{
+    MergeDuplicateTransactionsRequest, ValidatedFindAllTransactionsParams,
+    ValidatedFindDuplicateTransactionsParams,
+};
+use crate::entities::sea_orm_active_enums::{TransactionRevisionAction, WalletMemberRole};
+use crate::entities::{transactions, users};
+use crate::errors::AppError;
+use crate::repositories::transactions_repository;
+use crate::services::{transaction_revisions_service, transactions_service};
+use rust_decimal::Decimal;
+use sea_orm::{ActiveEnum, ActiveValue, DatabaseConnection, IntoActiveModel, TransactionTrait};
+use std::collections::HashMap;
+use time::OffsetDateTime;
+
+pub async fn find_all(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    params: ValidatedFindDuplicateTransactionsParams,
+) -> Result<Vec<(transactions::Model, transactions::Model, f64)>, AppError> {
+    let found_transactions =
+        transactions_repository::find_all_active_by_user_id_order_by_issued_at_and_created_at_desc(
+            db,
+            user.id,
+            ValidatedFindAllTransactionsParams {
+                wallet_id: params.wallet_id,
+                start_date: params.start_date,
+                end_date: params.end_date,
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    // only transactions sharing wallet, flow direction and amount can ever be duplicates
+    let mut grouped_transactions: HashMap<(i32, String, Decimal), Vec<transactions::Model>> =
+        HashMap::new();
+    for transaction in found_transactions {
+        grouped_transactions
+            .entry((
+                transaction.wallet_id,
+                transaction.flow_direction.to_value(),
+                transaction.amount,
+            ))
+            .or_default()
+            .push(transaction);
+    }
+
+    let mut duplicates = Vec::new();
+    for mut group in grouped_transactions.into_values() {
+        group.sort_by_key(|transaction| {
+            (
+                transaction.issued_at,
+                transaction.created_at,
+                transaction.id,
+            )
+        });
+
+        for (index, original) in group.iter().enumerate() {
+            for duplicate in &group[index + 1..] {
+                if (duplicate.issued_at - original.issued_at).whole_days() > params.window_days {
+                    break;
+                }
+
+                let similarity = description_similarity(
+                    original.description.as_deref(),
+                    duplicate.description.as_deref(),
+                );
+                if similarity >= params.min_similarity {
+                    duplicates.push((original.clone(), duplicate.clone(), similarity));
+                }
+            }
+        }
+    }
+
+    duplicates.sort_by(|(_, left, _), (_, right, _)| {
+        right
+            .issued_at
+            .cmp(&left.issued_at)
+            .then(right.id.cmp(&left.id))
+    });
+
+    Ok(duplicates)
+}
+
+pub async fn merge(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    request_id: Option<&str>,
+    payload: MergeDuplicateTransactionsRequest,
+) -> Result<transactions::Model, AppError> {
+    if payload.keep_id == payload.remove_id {
+        return Err(AppError::ParseBody(String::from(
+            "keep_id and remove_id must be different transactions",
+        )));
+    }
+
+    let txn = db.begin().await?;
+
+    let kept_transaction =
+        transactions_service::get_by_id(&txn, user, payload.keep_id, WalletMemberRole::Owner)
+            .await?;
+    let removed_transaction =
+        transactions_service::get_by_id(&txn, user, payload.remove_id, WalletMemberRole::Owner)
+            .await?;
+
+    if kept_transaction.wallet_id != removed_transaction.wallet_id
+        || kept_transaction.amount != removed_transaction.amount
+        || kept_transaction.flow_direction != removed_transaction.flow_direction
+    {
+        return Err(AppError::ParseBody(String::from(
+            "Transactions must share wallet, amount and flow_direction to be merged",
+        )));
+    }
+
+    let mut merged_transaction = kept_transaction.clone();
+    if kept_transaction.description.is_none() && removed_transaction.description.is_some() {
+        let previous_kept_transaction = kept_transaction.clone();
+        let mut kept_transaction = kept_transaction.into_active_model();
+        kept_transaction.description = ActiveValue::Set(removed_transaction.description.clone());
+        kept_transaction.updated_at = ActiveValue::Set(OffsetDateTime::now_utc());
+        merged_transaction = transactions_repository::save(&txn, kept_transaction).await?;
+        transaction_revisions_service::record(
+            &txn,
+            user,
+            request_id,
+            TransactionRevisionAction::Updated,
+            Some(&previous_kept_transaction),
+            Some(&merged_transaction),
+        )
+        .await?;
+    }
+
+    transactions_service::delete(&txn, user, request_id, removed_transaction).await?;
+
+    txn.commit().await?;
+
+    Ok(merged_transaction)
+}
+
+fn normalize_description(description: &str) -> String {
+    description
+        .to_lowercase()
+        .split(|character: char| !character.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn description_similarity(left: Option<&str>, right: Option<&str>) -> f64 {
+    let left = normalize_description(left.unwrap_or_default());
+    let right = normalize_description(right.unwrap_or_default());
+
+    match (left.is_empty(), right.is_empty()) {
+        (true, true) => 1.0,
+        (true, false) | (false, true) => 0.0,
+        (false, false) => {
+            strsim::normalized_levenshtein(&left, &right).max(strsim::sorensen_dice(&left, &right))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn description_similarity_ignores_case_and_punctuation() {
+        assert_eq!(
+            description_similarity(Some("Coffee, Shop!"), Some("coffee shop")),
+            1.0
+        );
+    }
+
+    #[test]
+    fn description_similarity_treats_missing_descriptions_as_empty() {
+        assert_eq!(description_similarity(None, Some("  ")), 1.0);
+        assert_eq!(description_similarity(None, Some("coffee")), 0.0);
+        assert_eq!(description_similarity(Some("coffee"), None), 0.0);
+    }
+
+    #[test]
+    fn description_similarity_scores_close_descriptions_above_unrelated_ones() {
+        let close = description_similarity(Some("Grocery store 123"), Some("grocery store 124"));
+        let unrelated = description_similarity(Some("Grocery store"), Some("Fuel station"));
+
+        assert!(close > 0.8);
+        assert!(unrelated < 0.5);
+        assert!(close > unrelated);
+    }
+}
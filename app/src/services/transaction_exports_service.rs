@@ -0,0 +1,213 @@
+use crate::dto::request::transactions_dto::ValidatedFindAllTransactionsParams;
+use crate::dto::response::transactions_dto::ExportTransactionResponse;
+use crate::entities::sea_orm_active_enums::WalletMemberRole;
+use crate::entities::users;
+use crate::enums::export_format::ExportFormat;
+use crate::errors::AppError;
+use crate::repositories::transactions_repository;
+use crate::services::wallets_service;
+use crate::utils::currency_utils::find_minor_units;
+use futures_util::TryStreamExt;
+use rust_decimal::prelude::ToPrimitive;
+use rust_xlsxwriter::{Format, Workbook};
+use sea_orm::DatabaseConnection;
+use std::collections::HashMap;
+use std::io::{Error as IoError, Seek, SeekFrom};
+use tokio::io::AsyncReadExt;
+use tokio::sync::mpsc::{self, Sender};
+use tokio_stream::wrappers::ReceiverStream;
+
+const EXPORT_BATCH_SIZE: usize = 500;
+const EXPORT_CHANNEL_CAPACITY: usize = 16;
+const EXPORT_READ_BUFFER_SIZE: usize = 64 * 1024;
+
+pub type ExportStream = ReceiverStream<Result<Vec<u8>, IoError>>;
+
+pub async fn export(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    params: ValidatedFindAllTransactionsParams,
+    format: ExportFormat,
+) -> Result<ExportStream, AppError> {
+    // filtering by a shared wallet exports it from the ledger of its owner
+    let user_id = match params.wallet_id {
+        Some(wallet_id) => {
+            wallets_service::get_by_id(db, user, wallet_id, WalletMemberRole::Viewer)
+                .await?
+                .user_id
+        }
+        None => user.id,
+    };
+
+    let (sender, receiver) = mpsc::channel(EXPORT_CHANNEL_CAPACITY);
+    let db = db.clone();
+
+    tokio::spawn(async move {
+        let result = match format {
+            ExportFormat::Csv => export_csv(&db, user_id, params, &sender).await,
+            ExportFormat::Jsonl => export_jsonl(&db, user_id, params, &sender).await,
+            ExportFormat::Xlsx => export_xlsx(&db, user_id, params, &sender).await,
+        };
+
+        if let Err(err) = result {
+            tracing::error!("Error: {:?}", err);
+            let _ = sender
+                .send(Err(IoError::other("Failed to export transactions")))
+                .await;
+        }
+    });
+
+    Ok(ReceiverStream::new(receiver))
+}
+
+async fn send_chunk(sender: &Sender<Result<Vec<u8>, IoError>>, chunk: Vec<u8>) -> bool {
+    sender.send(Ok(chunk)).await.is_ok()
+}
+
+fn new_csv_writer() -> csv::Writer<Vec<u8>> {
+    csv::WriterBuilder::new()
+        .has_headers(false)
+        .from_writer(Vec::new())
+}
+
+async fn export_csv(
+    db: &DatabaseConnection,
+    user_id: i32,
+    params: ValidatedFindAllTransactionsParams,
+    sender: &Sender<Result<Vec<u8>, IoError>>,
+) -> Result<(), AppError> {
+    let mut found_rows =
+        transactions_repository::stream_all_active_export_rows_by_user_id(db, user_id, params)
+            .await?;
+
+    let mut writer = new_csv_writer();
+    writer.write_record(ExportTransactionResponse::HEADERS)?;
+
+    let mut buffered_rows = 0;
+    while let Some(row) = found_rows.try_next().await? {
+        writer.serialize(ExportTransactionResponse::try_from(row)?)?;
+        buffered_rows += 1;
+
+        if buffered_rows == EXPORT_BATCH_SIZE {
+            let chunk = writer.into_inner().map_err(|err| err.into_error())?;
+            if !send_chunk(sender, chunk).await {
+                return Ok(());
+            }
+            writer = new_csv_writer();
+            buffered_rows = 0;
+        }
+    }
+
+    let chunk = writer.into_inner().map_err(|err| err.into_error())?;
+    send_chunk(sender, chunk).await;
+
+    Ok(())
+}
+
+async fn export_jsonl(
+    db: &DatabaseConnection,
+    user_id: i32,
+    params: ValidatedFindAllTransactionsParams,
+    sender: &Sender<Result<Vec<u8>, IoError>>,
+) -> Result<(), AppError> {
+    let mut found_rows =
+        transactions_repository::stream_all_active_export_rows_by_user_id(db, user_id, params)
+            .await?;
+
+    let mut buffer = Vec::new();
+    let mut buffered_rows = 0;
+    while let Some(row) = found_rows.try_next().await? {
+        serde_json::to_writer(&mut buffer, &ExportTransactionResponse::try_from(row)?)?;
+        buffer.push(b'\n');
+        buffered_rows += 1;
+
+        if buffered_rows == EXPORT_BATCH_SIZE {
+            if !send_chunk(sender, std::mem::take(&mut buffer)).await {
+                return Ok(());
+            }
+            buffered_rows = 0;
+        }
+    }
+
+    if !buffer.is_empty() {
+        send_chunk(sender, buffer).await;
+    }
+
+    Ok(())
+}
+
+async fn export_xlsx(
+    db: &DatabaseConnection,
+    user_id: i32,
+    params: ValidatedFindAllTransactionsParams,
+    sender: &Sender<Result<Vec<u8>, IoError>>,
+) -> Result<(), AppError> {
+    let mut found_rows =
+        transactions_repository::stream_all_active_export_rows_by_user_id(db, user_id, params)
+            .await?;
+
+    // constant memory mode flushes every finished row to a temporary file, so the workbook
+    // never holds the whole export in memory
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet_with_constant_memory();
+    worksheet.set_name("Transactions")?;
+    for (column, header) in ExportTransactionResponse::HEADERS.iter().enumerate() {
+        worksheet.write_string(0, column as u16, *header)?;
+    }
+
+    // amounts keep the scale of their currency, both in the stored value and in how it is shown
+    let mut amount_formats: HashMap<u32, Format> = HashMap::new();
+    let mut row_index = 1;
+    while let Some(row) = found_rows.try_next().await? {
+        let minor_units = find_minor_units(&row.currency).unwrap_or(row.amount.scale());
+        let amount = row
+            .amount
+            .round_dp(minor_units)
+            .to_f64()
+            .unwrap_or_default();
+        let amount_format = amount_formats.entry(minor_units).or_insert_with(|| {
+            Format::new().set_num_format(match minor_units {
+                0 => String::from("0"),
+                minor_units => format!("0.{}", "0".repeat(minor_units as usize)),
+            })
+        });
+        let response = ExportTransactionResponse::try_from(row)?;
+
+        worksheet.write_number(row_index, 0, response.id)?;
+        worksheet.write_string(row_index, 1, response.issued_at)?;
+        worksheet.write_string(row_index, 2, response.flow_direction)?;
+        worksheet.write_number_with_format(row_index, 3, amount, amount_format)?;
+        worksheet.write_string(row_index, 4, response.currency)?;
+        if let Some(description) = response.description {
+            worksheet.write_string(row_index, 5, description)?;
+        }
+        worksheet.write_string(row_index, 6, response.category_name)?;
+        worksheet.write_string(row_index, 7, response.wallet_name)?;
+        if let Some(budget_name) = response.budget_name {
+            worksheet.write_string(row_index, 8, budget_name)?;
+        }
+        row_index += 1;
+    }
+
+    // saving compresses the whole workbook, so it runs off the async workers and into an unnamed
+    // temporary file that is removed as soon as it is closed, whether the export finishes or not
+    let file = tokio::task::spawn_blocking(move || -> Result<std::fs::File, AppError> {
+        let mut file = tempfile::tempfile()?;
+        workbook.save_to_writer(&mut file)?;
+        file.seek(SeekFrom::Start(0))?;
+        Ok(file)
+    })
+    .await
+    .map_err(IoError::other)??;
+    let mut file = tokio::fs::File::from_std(file);
+
+    let mut buffer = vec![0; EXPORT_READ_BUFFER_SIZE];
+    loop {
+        let read_bytes = file.read(&mut buffer).await?;
+        if read_bytes == 0 || !send_chunk(sender, buffer[..read_bytes].to_vec()).await {
+            break;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use crate::dto::request::transactions_dto::{
+    CreateTransactionRefundRequest, CreateTransactionRequest,
+};
+use crate::entities::sea_orm_active_enums::{TransactionType, WalletMemberRole};
+use crate::entities::{transactions, users};
+use crate::errors::AppError;
+use crate::repositories::transactions_repository;
+use crate::services::transactions_service;
+use rust_decimal::Decimal;
+use sea_orm::{ActiveEnum, ConnectionTrait, DatabaseConnection, TransactionTrait};
+use std::str::FromStr;
+use time::OffsetDateTime;
+
+pub async fn find_refunded_amount(
+    connection: &impl ConnectionTrait,
+    transaction_id: i32,
+) -> Result<Decimal, AppError> {
+    let found_refunds =
+        transactions_repository::find_all_active_by_refunded_transaction_id_order_by_issued_at_asc(
+            connection,
+            transaction_id,
+        )
+        .await?;
+    Ok(found_refunds.iter().map(|refund| refund.amount).sum())
+}
+
+pub async fn ensure_refundable(
+    connection: &impl ConnectionTrait,
+    transaction: &transactions::Model,
+    amount: Decimal,
+) -> Result<(), AppError> {
+    if transaction.flow_direction != TransactionType::Outcome
+        || transaction.refunded_transaction_id.is_some()
+        || transaction.transfer_transaction_id.is_some()
+    {
+        return Err(AppError::Conflict(String::from(
+            "Only outcome transactions that are not transfers can be refunded",
+        )));
+    }
+
+    // concurrent refunds of the same transaction wait here until the first one commits
+    transactions_repository::get_active_by_id_for_update(connection, transaction.id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(String::from("Transaction not found")))?;
+
+    let refundable_amount =
+        transaction.amount - find_refunded_amount(connection, transaction.id).await?;
+    if amount > refundable_amount {
+        return Err(AppError::Conflict(format!(
+            "Refund amount exceeds the remaining refundable amount of {refundable_amount}"
+        )));
+    }
+    Ok(())
+}
+
+pub async fn create(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    request_id: Option<&str>,
+    transaction_id: i32,
+    payload: CreateTransactionRefundRequest,
+) -> Result<transactions::Model, AppError> {
+    let amount = Decimal::from_str(&payload.amount)?;
+    if amount <= Decimal::ZERO {
+        return Err(AppError::ParseBody(String::from(
+            "amount must be greater than 0",
+        )));
+    }
+
+    let txn = db.begin().await?;
+
+    let found_transaction =
+        transactions_service::get_by_id(&txn, user, transaction_id, WalletMemberRole::Owner)
+            .await?;
+    ensure_refundable(&txn, &found_transaction, amount).await?;
+
+    // the refund gives back what the original spent from its budget
+    let new_refund = transactions_service::insert_refund(
+        &txn,
+        user,
+        request_id,
+        CreateTransactionRequest {
+            category_id: None,
+            budget_id: None,
+            payee_id: None,
+            wallet_id: payload.wallet_id.unwrap_or(found_transaction.wallet_id),
+            amount: amount.to_string(),
+            currency: Some(found_transaction.currency.clone()),
+            description: payload.description.or_else(|| {
+                found_transaction
+                    .description
+                    .as_ref()
+                    .map(|description| format!("Refund: {description}"))
+            }),
+            flow_direction: TransactionType::Income.to_value(),
+            status: payload.status,
+            issued_at: payload
+                .issued_at
+                .unwrap_or_else(|| OffsetDateTime::now_utc().date()),
+            tag_ids: None,
+            latitude: None,
+            longitude: None,
+            place_name: None,
+        },
+        &found_transaction,
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    Ok(new_refund)
+}
+
+pub async fn find_all(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    transaction_id: i32,
+) -> Result<(transactions::Model, Vec<transactions::Model>), AppError> {
+    let found_transaction =
+        transactions_service::get_by_id(db, user, transaction_id, WalletMemberRole::Viewer).await?;
+    let found_refunds =
+        transactions_repository::find_all_active_by_refunded_transaction_id_order_by_issued_at_asc(
+            db,
+            found_transaction.id,
+        )
+        .await?;
+    Ok((found_transaction, found_refunds))
+}
@@ -0,0 +1,189 @@
+use crate::entities::sea_orm_active_enums::{
+    TransactionRevisionAction, TransactionRevisionSource, WalletMemberRole,
+};
+use crate::entities::{transaction_revisions, transactions, users};
+use crate::errors::AppError;
+use crate::repositories::{transaction_revisions_repository, transactions_repository};
+use crate::services::{transaction_tags_service, wallets_service};
+use sea_orm::{ActiveEnum, ActiveValue, ConnectionTrait, DatabaseConnection};
+use serde::Serialize;
+use serde_json::Value;
+use time::Date;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TransactionSnapshot {
+    category_id: i32,
+    budget_id: Option<i32>,
+    payee_id: Option<i32>,
+    wallet_id: i32,
+    amount: String,
+    currency: String,
+    description: Option<String>,
+    flow_direction: String,
+    status: String,
+    issued_at: Date,
+    reconciliation_id: Option<i32>,
+    posted: bool,
+    latitude: Option<String>,
+    longitude: Option<String>,
+    place_name: Option<String>,
+    tag_ids: Vec<i32>,
+}
+
+fn to_snapshot(transaction: &transactions::Model, tag_ids: Vec<i32>) -> Result<Value, AppError> {
+    let snapshot = TransactionSnapshot {
+        category_id: transaction.category_id,
+        budget_id: transaction.budget_id,
+        payee_id: transaction.payee_id,
+        wallet_id: transaction.wallet_id,
+        amount: transaction.amount.to_string(),
+        currency: transaction.currency.clone(),
+        description: transaction.description.clone(),
+        flow_direction: transaction.flow_direction.to_value(),
+        status: transaction.status.to_value(),
+        issued_at: transaction.issued_at,
+        reconciliation_id: transaction.reconciliation_id,
+        posted: transaction.posted_at.is_some(),
+        latitude: transaction.latitude.map(|latitude| latitude.to_string()),
+        longitude: transaction.longitude.map(|longitude| longitude.to_string()),
+        place_name: transaction.place_name.clone(),
+        tag_ids,
+    };
+    Ok(serde_json::to_value(snapshot)?)
+}
+
+pub async fn record(
+    connection: &impl ConnectionTrait,
+    actor: &users::Model,
+    request_id: Option<&str>,
+    action: TransactionRevisionAction,
+    old_transaction: Option<&transactions::Model>,
+    new_transaction: Option<&transactions::Model>,
+) -> Result<(), AppError> {
+    insert(
+        connection,
+        Some(actor),
+        TransactionRevisionSource::User,
+        request_id,
+        action,
+        old_transaction,
+        new_transaction,
+    )
+    .await
+}
+
+// changes made by background jobs have no actor, the source tells them apart from
+// revisions whose actor was deleted since
+pub async fn record_system(
+    connection: &impl ConnectionTrait,
+    action: TransactionRevisionAction,
+    old_transaction: Option<&transactions::Model>,
+    new_transaction: Option<&transactions::Model>,
+) -> Result<(), AppError> {
+    insert(
+        connection,
+        None,
+        TransactionRevisionSource::System,
+        None,
+        action,
+        old_transaction,
+        new_transaction,
+    )
+    .await
+}
+
+async fn insert(
+    connection: &impl ConnectionTrait,
+    actor: Option<&users::Model>,
+    source: TransactionRevisionSource,
+    request_id: Option<&str>,
+    action: TransactionRevisionAction,
+    old_transaction: Option<&transactions::Model>,
+    new_transaction: Option<&transactions::Model>,
+) -> Result<(), AppError> {
+    let Some(transaction) = new_transaction.or(old_transaction) else {
+        return Ok(());
+    };
+
+    // tags live in their own table and may already be replaced when the revision is written,
+    // so the old tags are taken from the previous revision whenever there is one
+    let mut tag_ids =
+        transaction_tags_service::find_tag_ids_by_transaction_ids(connection, vec![transaction.id])
+            .await?
+            .remove(&transaction.id)
+            .unwrap_or_default();
+    tag_ids.sort_unstable();
+    let old_tag_ids = match old_transaction {
+        Some(_) => transaction_revisions_repository::get_latest_by_transaction_id(
+            connection,
+            transaction.id,
+        )
+        .await?
+        .and_then(|previous_revision| previous_revision.new_values)
+        .and_then(|new_values| serde_json::from_value(new_values["tagIds"].clone()).ok())
+        .unwrap_or_else(|| tag_ids.clone()),
+        None => Vec::new(),
+    };
+
+    let old_values = old_transaction
+        .map(|old_transaction| to_snapshot(old_transaction, old_tag_ids))
+        .transpose()?;
+    let new_values = new_transaction
+        .map(|new_transaction| to_snapshot(new_transaction, tag_ids))
+        .transpose()?;
+    if action == TransactionRevisionAction::Updated && old_values == new_values {
+        return Ok(());
+    }
+
+    let new_transaction_revision = transaction_revisions::ActiveModel {
+        user_id: ActiveValue::Set(transaction.user_id),
+        transaction_id: ActiveValue::Set(transaction.id),
+        actor_id: ActiveValue::Set(actor.map(|actor| actor.id)),
+        action: ActiveValue::Set(action),
+        source: ActiveValue::Set(source),
+        old_values: ActiveValue::Set(old_values),
+        new_values: ActiveValue::Set(new_values),
+        request_id: ActiveValue::Set(request_id.map(String::from)),
+        ..Default::default()
+    };
+    transaction_revisions_repository::save(connection, new_transaction_revision).await?;
+    Ok(())
+}
+
+pub async fn find_all_by_transaction_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    transaction_id: i32,
+) -> Result<Vec<transaction_revisions::Model>, AppError> {
+    // history stays readable while the transaction sits in the trash, members of a shared
+    // wallet read it through their wallet role
+    let Some(found_transaction) = transactions_repository::get_by_id(db, transaction_id).await?
+    else {
+        return Err(AppError::NotFound(String::from("Transaction not found")));
+    };
+    if found_transaction.user_id != user.id {
+        match wallets_service::get_by_id(
+            db,
+            user,
+            found_transaction.wallet_id,
+            WalletMemberRole::Viewer,
+        )
+        .await
+        {
+            Err(AppError::NotFound(_)) => {
+                return Err(AppError::NotFound(String::from("Transaction not found")));
+            }
+            found_wallet => found_wallet?,
+        };
+    }
+
+    let found_transaction_revisions =
+        transaction_revisions_repository::find_all_by_transaction_id_and_user_id_order_by_id_asc(
+            db,
+            found_transaction.id,
+            found_transaction.user_id,
+        )
+        .await?;
+    Ok(found_transaction_revisions)
+}
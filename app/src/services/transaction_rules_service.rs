@@ -0,0 +1,578 @@
+use crate::dto::request::transaction_rules_dto::{
+    CreateTransactionRuleRequest, UpdateTransactionRuleRequest,
+};
+use crate::dto::request::transactions_dto::ValidatedFindAllTransactionsParams;
+use crate::entities::sea_orm_active_enums::{
+    TransactionRevisionAction, TransactionStatus, TransactionType, WalletMemberRole,
+};
+use crate::entities::{transaction_rule_tags, transaction_rules, transactions, users};
+use crate::errors::AppError;
+use crate::repositories::{
+    transaction_rule_tags_repository, transaction_rules_repository, transactions_repository,
+};
+use crate::services::{
+    budgets_service, categories_service, tags_service, transaction_revisions_service,
+    transaction_tags_service, transactions_service, wallets_service,
+};
+use regex::Regex;
+use rust_decimal::Decimal;
+use sea_orm::{
+    ActiveEnum, ActiveValue, ConnectionTrait, DatabaseConnection, IntoActiveModel, TransactionTrait,
+};
+use std::collections::HashMap;
+use std::str::FromStr;
+use time::OffsetDateTime;
+
+pub struct TransactionRuleMatcher {
+    pub category_id: Option<i32>,
+    pub budget_id: Option<i32>,
+    pub tag_ids: Vec<i32>,
+    description_contains: Option<String>,
+    description_regex: Option<Regex>,
+    min_amount: Option<Decimal>,
+    max_amount: Option<Decimal>,
+    wallet_id: Option<i32>,
+    flow_direction: Option<TransactionType>,
+}
+
+impl TransactionRuleMatcher {
+    pub fn matches(
+        &self,
+        wallet_id: i32,
+        amount: Decimal,
+        flow_direction: &TransactionType,
+        description: Option<&str>,
+    ) -> bool {
+        if self
+            .wallet_id
+            .is_some_and(|rule_wallet_id| rule_wallet_id != wallet_id)
+        {
+            return false;
+        }
+        if self
+            .flow_direction
+            .as_ref()
+            .is_some_and(|rule_flow_direction| rule_flow_direction != flow_direction)
+        {
+            return false;
+        }
+        if self
+            .min_amount
+            .is_some_and(|min_amount| amount < min_amount)
+            || self
+                .max_amount
+                .is_some_and(|max_amount| amount > max_amount)
+        {
+            return false;
+        }
+
+        let description = description.unwrap_or_default();
+        if let Some(ref description_contains) = self.description_contains
+            && !description.to_lowercase().contains(description_contains)
+        {
+            return false;
+        }
+        if let Some(ref description_regex) = self.description_regex
+            && !description_regex.is_match(description)
+        {
+            return false;
+        }
+
+        true
+    }
+
+    pub fn matches_transaction(&self, transaction: &transactions::Model) -> bool {
+        self.matches(
+            transaction.wallet_id,
+            transaction.amount,
+            &transaction.flow_direction,
+            transaction.description.as_deref(),
+        )
+    }
+}
+
+impl TryFrom<transaction_rules::Model> for TransactionRuleMatcher {
+    type Error = AppError;
+
+    fn try_from(transaction_rule: transaction_rules::Model) -> Result<Self, Self::Error> {
+        let description_regex = match transaction_rule.description_regex {
+            Some(description_regex) => Some(Regex::new(&description_regex)?),
+            None => None,
+        };
+
+        Ok(TransactionRuleMatcher {
+            category_id: transaction_rule.category_id,
+            budget_id: transaction_rule.budget_id,
+            tag_ids: Vec::new(),
+            description_contains: transaction_rule
+                .description_contains
+                .map(|description_contains| description_contains.to_lowercase()),
+            description_regex,
+            min_amount: transaction_rule.min_amount,
+            max_amount: transaction_rule.max_amount,
+            wallet_id: transaction_rule.wallet_id,
+            flow_direction: transaction_rule.flow_direction,
+        })
+    }
+}
+
+async fn build_transaction_rule(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    payload: &CreateTransactionRuleRequest,
+) -> Result<transaction_rules::ActiveModel, AppError> {
+    if payload.category_id.is_none()
+        && payload.budget_id.is_none()
+        && payload.tag_ids.as_ref().is_none_or(Vec::is_empty)
+    {
+        return Err(AppError::ParseBody(String::from(
+            "A transaction rule must set at least one of category_id, budget_id or tag_ids",
+        )));
+    }
+
+    if let Some(ref description_regex) = payload.description_regex {
+        Regex::new(description_regex)?;
+    }
+
+    let min_amount = match payload.min_amount {
+        Some(ref min_amount) => Some(Decimal::from_str(min_amount)?),
+        None => None,
+    };
+    let max_amount = match payload.max_amount {
+        Some(ref max_amount) => Some(Decimal::from_str(max_amount)?),
+        None => None,
+    };
+    if let (Some(min_amount), Some(max_amount)) = (min_amount, max_amount)
+        && min_amount > max_amount
+    {
+        return Err(AppError::ParseBody(String::from(
+            "min_amount cannot be greater than max_amount",
+        )));
+    }
+
+    let flow_direction = match payload.flow_direction {
+        Some(ref flow_direction) => Some(
+            TransactionType::try_from_value(flow_direction)
+                .map_err(|_| AppError::ParseBody(String::from("invalid flow_direction")))?,
+        ),
+        None => None,
+    };
+
+    if let Some(wallet_id) = payload.wallet_id {
+        wallets_service::get_by_id(connection, user, wallet_id, WalletMemberRole::Owner).await?;
+    }
+    if let Some(category_id) = payload.category_id {
+        categories_service::get_by_id(connection, user, category_id).await?;
+    }
+    if let Some(budget_id) = payload.budget_id {
+        budgets_service::get_by_id(connection, user, budget_id).await?;
+    }
+
+    Ok(transaction_rules::ActiveModel {
+        user_id: ActiveValue::Set(user.id),
+        name: ActiveValue::Set(payload.name.clone()),
+        priority: ActiveValue::Set(payload.priority.unwrap_or_default()),
+        description_contains: ActiveValue::Set(payload.description_contains.clone()),
+        description_regex: ActiveValue::Set(payload.description_regex.clone()),
+        min_amount: ActiveValue::Set(min_amount),
+        max_amount: ActiveValue::Set(max_amount),
+        wallet_id: ActiveValue::Set(payload.wallet_id),
+        flow_direction: ActiveValue::Set(flow_direction),
+        category_id: ActiveValue::Set(payload.category_id),
+        budget_id: ActiveValue::Set(payload.budget_id),
+        ..Default::default()
+    })
+}
+
+async fn save_transaction_rule_tags(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    transaction_rule_id: i32,
+    tag_ids: &[i32],
+) -> Result<Vec<i32>, AppError> {
+    let found_tags = tags_service::get_all_by_ids(connection, user, tag_ids).await?;
+
+    transaction_rule_tags_repository::delete_all_by_transaction_rule_id(
+        connection,
+        transaction_rule_id,
+    )
+    .await?;
+    let new_transaction_rule_tags = found_tags
+        .iter()
+        .map(|tag| transaction_rule_tags::ActiveModel {
+            transaction_rule_id: ActiveValue::Set(transaction_rule_id),
+            tag_id: ActiveValue::Set(tag.id),
+        })
+        .collect();
+    transaction_rule_tags_repository::insert_many(connection, new_transaction_rule_tags).await?;
+
+    Ok(found_tags.into_iter().map(|tag| tag.id).collect())
+}
+
+pub async fn create(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    payload: CreateTransactionRuleRequest,
+) -> Result<(transaction_rules::Model, Vec<i32>), AppError> {
+    let txn = db.begin().await?;
+
+    let new_transaction_rule = build_transaction_rule(&txn, user, &payload).await?;
+    let new_transaction_rule =
+        transaction_rules_repository::save(&txn, new_transaction_rule).await?;
+    let tag_ids = save_transaction_rule_tags(
+        &txn,
+        user,
+        new_transaction_rule.id,
+        &payload.tag_ids.unwrap_or_default(),
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    Ok((new_transaction_rule, tag_ids))
+}
+
+pub async fn find_all(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+) -> Result<Vec<transaction_rules::Model>, AppError> {
+    let found_transaction_rules =
+        transaction_rules_repository::find_all_active_by_user_id_order_by_priority_asc(
+            connection, user.id,
+        )
+        .await?;
+    Ok(found_transaction_rules)
+}
+
+pub async fn get_by_id(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    transaction_rule_id: i32,
+) -> Result<transaction_rules::Model, AppError> {
+    let found_transaction_rule = transaction_rules_repository::get_active_by_id_and_user_id(
+        connection,
+        transaction_rule_id,
+        user.id,
+    )
+    .await?;
+    let Some(found_transaction_rule) = found_transaction_rule else {
+        return Err(AppError::NotFound(String::from(
+            "Transaction rule not found",
+        )));
+    };
+    Ok(found_transaction_rule)
+}
+
+pub async fn update_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    transaction_rule_id: i32,
+    payload: UpdateTransactionRuleRequest,
+) -> Result<(transaction_rules::Model, Vec<i32>), AppError> {
+    let txn = db.begin().await?;
+
+    let found_transaction_rule = get_by_id(&txn, user, transaction_rule_id).await?;
+
+    let payload = CreateTransactionRuleRequest::from(payload);
+    let mut updated_transaction_rule = build_transaction_rule(&txn, user, &payload).await?;
+    updated_transaction_rule.id = ActiveValue::Unchanged(found_transaction_rule.id);
+    updated_transaction_rule.updated_at = ActiveValue::Set(OffsetDateTime::now_utc());
+
+    let updated_transaction_rule =
+        transaction_rules_repository::save(&txn, updated_transaction_rule).await?;
+    let tag_ids = save_transaction_rule_tags(
+        &txn,
+        user,
+        updated_transaction_rule.id,
+        &payload.tag_ids.unwrap_or_default(),
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    Ok((updated_transaction_rule, tag_ids))
+}
+
+pub async fn delete_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    transaction_rule_id: i32,
+) -> Result<(), AppError> {
+    let mut found_transaction_rule = get_by_id(db, user, transaction_rule_id)
+        .await?
+        .into_active_model();
+    found_transaction_rule.deleted_at = ActiveValue::Set(Some(OffsetDateTime::now_utc()));
+    transaction_rules_repository::save(db, found_transaction_rule).await?;
+    Ok(())
+}
+
+pub async fn find_all_deleted(
+    db: &DatabaseConnection,
+    user: &users::Model,
+) -> Result<Vec<transaction_rules::Model>, AppError> {
+    let found_transaction_rules =
+        transaction_rules_repository::find_all_deleted_by_user_id_order_by_deleted_at_desc(
+            db, user.id,
+        )
+        .await?;
+    Ok(found_transaction_rules)
+}
+
+pub async fn restore_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    transaction_rule_id: i32,
+) -> Result<transaction_rules::Model, AppError> {
+    let found_transaction_rule = transaction_rules_repository::get_deleted_by_id_and_user_id(
+        db,
+        transaction_rule_id,
+        user.id,
+    )
+    .await?;
+    let Some(found_transaction_rule) = found_transaction_rule else {
+        return Err(AppError::NotFound(String::from(
+            "Transaction rule not found in trash",
+        )));
+    };
+
+    let mut found_transaction_rule = found_transaction_rule.into_active_model();
+    found_transaction_rule.deleted_at = ActiveValue::Set(None);
+    found_transaction_rule.updated_at = ActiveValue::Set(OffsetDateTime::now_utc());
+
+    let restored_transaction_rule =
+        transaction_rules_repository::save(db, found_transaction_rule).await?;
+    Ok(restored_transaction_rule)
+}
+
+pub async fn find_tag_ids_by_transaction_rule_ids(
+    connection: &impl ConnectionTrait,
+    transaction_rule_ids: Vec<i32>,
+) -> Result<HashMap<i32, Vec<i32>>, AppError> {
+    let found_transaction_rule_tags =
+        transaction_rule_tags_repository::find_all_with_active_tag_by_transaction_rule_ids(
+            connection,
+            transaction_rule_ids,
+        )
+        .await?;
+
+    let mut tag_ids: HashMap<i32, Vec<i32>> = HashMap::new();
+    for transaction_rule_tag in found_transaction_rule_tags {
+        tag_ids
+            .entry(transaction_rule_tag.transaction_rule_id)
+            .or_default()
+            .push(transaction_rule_tag.tag_id);
+    }
+    Ok(tag_ids)
+}
+
+pub async fn find_all_matchers(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+) -> Result<Vec<TransactionRuleMatcher>, AppError> {
+    let found_transaction_rules = find_all(connection, user).await?;
+    let mut found_tag_ids = find_tag_ids_by_transaction_rule_ids(
+        connection,
+        found_transaction_rules
+            .iter()
+            .map(|transaction_rule| transaction_rule.id)
+            .collect(),
+    )
+    .await?;
+
+    found_transaction_rules
+        .into_iter()
+        .map(|transaction_rule| {
+            let tag_ids = found_tag_ids
+                .remove(&transaction_rule.id)
+                .unwrap_or_default();
+            let mut matcher = TransactionRuleMatcher::try_from(transaction_rule)?;
+            matcher.tag_ids = tag_ids;
+            Ok(matcher)
+        })
+        .collect()
+}
+
+pub fn find_first_match<'a>(
+    matchers: &'a [TransactionRuleMatcher],
+    wallet_id: i32,
+    amount: Decimal,
+    flow_direction: &TransactionType,
+    description: Option<&str>,
+) -> Option<&'a TransactionRuleMatcher> {
+    matchers
+        .iter()
+        .find(|matcher| matcher.matches(wallet_id, amount, flow_direction, description))
+}
+
+pub async fn test_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    transaction_rule_id: i32,
+    params: ValidatedFindAllTransactionsParams,
+) -> Result<Vec<transactions::Model>, AppError> {
+    let matcher =
+        TransactionRuleMatcher::try_from(get_by_id(db, user, transaction_rule_id).await?)?;
+
+    let found_transactions =
+        transactions_repository::find_all_active_by_user_id_order_by_issued_at_and_created_at_desc(
+            db, user.id, params,
+        )
+        .await?;
+
+    Ok(found_transactions
+        .into_iter()
+        .filter(|transaction| matcher.matches_transaction(transaction))
+        .collect())
+}
+
+pub async fn apply_all(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    request_id: Option<&str>,
+    params: ValidatedFindAllTransactionsParams,
+) -> Result<(u64, u64), AppError> {
+    let txn = db.begin().await?;
+
+    let matchers = find_all_matchers(&txn, user).await?;
+    let found_transactions =
+        transactions_repository::find_all_active_by_user_id_order_by_issued_at_and_created_at_desc(
+            &txn, user.id, params,
+        )
+        .await?;
+
+    let mut matched_transactions = 0;
+    let mut updated_transactions = 0;
+    for transaction in found_transactions {
+        // reconciled transactions are locked, rules never touch them retroactively
+        if transaction.status == TransactionStatus::Reconciled {
+            continue;
+        }
+
+        let Some(matcher) = matchers
+            .iter()
+            .find(|matcher| matcher.matches_transaction(&transaction))
+        else {
+            continue;
+        };
+        matched_transactions += 1;
+
+        let inserted_transaction_tags =
+            transaction_tags_service::add_tags(&txn, user, transaction.id, &matcher.tag_ids)
+                .await?;
+
+        let category_id = matcher.category_id.unwrap_or(transaction.category_id);
+        let budget_id = matcher.budget_id.or(transaction.budget_id);
+        if category_id == transaction.category_id && budget_id == transaction.budget_id {
+            if inserted_transaction_tags > 0 {
+                transaction_revisions_service::record(
+                    &txn,
+                    user,
+                    request_id,
+                    TransactionRevisionAction::Updated,
+                    Some(&transaction),
+                    Some(&transaction),
+                )
+                .await?;
+                updated_transactions += 1;
+            }
+            continue;
+        }
+
+        transactions_service::reassign(&txn, user, request_id, transaction, category_id, budget_id)
+            .await?;
+        updated_transactions += 1;
+    }
+
+    txn.commit().await?;
+
+    Ok((matched_transactions, updated_transactions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matcher() -> TransactionRuleMatcher {
+        TransactionRuleMatcher {
+            category_id: Some(1),
+            budget_id: None,
+            tag_ids: Vec::new(),
+            description_contains: None,
+            description_regex: None,
+            min_amount: None,
+            max_amount: None,
+            wallet_id: None,
+            flow_direction: None,
+        }
+    }
+
+    #[test]
+    fn matches_everything_without_conditions() {
+        assert!(matcher().matches(1, Decimal::from(10), &TransactionType::Outcome, None));
+    }
+
+    #[test]
+    fn matches_checks_wallet_and_flow_direction() {
+        let matcher = TransactionRuleMatcher {
+            wallet_id: Some(1),
+            flow_direction: Some(TransactionType::Outcome),
+            ..matcher()
+        };
+
+        assert!(matcher.matches(1, Decimal::from(10), &TransactionType::Outcome, None));
+        assert!(!matcher.matches(2, Decimal::from(10), &TransactionType::Outcome, None));
+        assert!(!matcher.matches(1, Decimal::from(10), &TransactionType::Income, None));
+    }
+
+    #[test]
+    fn matches_includes_both_amount_bounds() {
+        let matcher = TransactionRuleMatcher {
+            min_amount: Some(Decimal::from(10)),
+            max_amount: Some(Decimal::from(20)),
+            ..matcher()
+        };
+
+        assert!(matcher.matches(1, Decimal::from(10), &TransactionType::Outcome, None));
+        assert!(matcher.matches(1, Decimal::from(20), &TransactionType::Outcome, None));
+        assert!(!matcher.matches(1, Decimal::new(999, 2), &TransactionType::Outcome, None));
+        assert!(!matcher.matches(1, Decimal::new(2001, 2), &TransactionType::Outcome, None));
+    }
+
+    #[test]
+    fn matches_description_contains_ignoring_case() {
+        let matcher = TransactionRuleMatcher {
+            description_contains: Some(String::from("coffee")),
+            ..matcher()
+        };
+
+        assert!(matcher.matches(
+            1,
+            Decimal::from(10),
+            &TransactionType::Outcome,
+            Some("Morning COFFEE")
+        ));
+        assert!(!matcher.matches(1, Decimal::from(10), &TransactionType::Outcome, Some("Tea")));
+        assert!(!matcher.matches(1, Decimal::from(10), &TransactionType::Outcome, None));
+    }
+
+    #[test]
+    fn matches_description_regex() {
+        let matcher = TransactionRuleMatcher {
+            description_regex: Some(Regex::new(r"^UBER \*TRIP").unwrap()),
+            ..matcher()
+        };
+
+        assert!(matcher.matches(
+            1,
+            Decimal::from(10),
+            &TransactionType::Outcome,
+            Some("UBER *TRIP 1234")
+        ));
+        assert!(!matcher.matches(
+            1,
+            Decimal::from(10),
+            &TransactionType::Outcome,
+            Some("UBER *EATS 1234")
+        ));
+    }
+}
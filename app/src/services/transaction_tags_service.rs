@@ -0,0 +1,59 @@
+use crate::entities::{transaction_tags, users};
+use crate::errors::AppError;
+use crate::repositories::transaction_tags_repository;
+use crate::services::tags_service;
+use sea_orm::{ActiveValue, ConnectionTrait};
+use std::collections::HashMap;
+
+pub async fn add_tags(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    transaction_id: i32,
+    tag_ids: &[i32],
+) -> Result<u64, AppError> {
+    let found_tags = tags_service::get_all_by_ids(connection, user, tag_ids).await?;
+
+    let new_transaction_tags = found_tags
+        .into_iter()
+        .map(|tag| transaction_tags::ActiveModel {
+            transaction_id: ActiveValue::Set(transaction_id),
+            tag_id: ActiveValue::Set(tag.id),
+        })
+        .collect();
+    let inserted_transaction_tags =
+        transaction_tags_repository::insert_many_ignore_existing(connection, new_transaction_tags)
+            .await?;
+    Ok(inserted_transaction_tags)
+}
+
+pub async fn replace_tags(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    transaction_id: i32,
+    tag_ids: &[i32],
+) -> Result<(), AppError> {
+    transaction_tags_repository::delete_all_by_transaction_id(connection, transaction_id).await?;
+    add_tags(connection, user, transaction_id, tag_ids).await?;
+    Ok(())
+}
+
+pub async fn find_tag_ids_by_transaction_ids(
+    connection: &impl ConnectionTrait,
+    transaction_ids: Vec<i32>,
+) -> Result<HashMap<i32, Vec<i32>>, AppError> {
+    let found_transaction_tags =
+        transaction_tags_repository::find_all_with_active_tag_by_transaction_ids(
+            connection,
+            transaction_ids,
+        )
+        .await?;
+
+    let mut tag_ids: HashMap<i32, Vec<i32>> = HashMap::new();
+    for transaction_tag in found_transaction_tags {
+        tag_ids
+            .entry(transaction_tag.transaction_id)
+            .or_default()
+            .push(transaction_tag.tag_id);
+    }
+    Ok(tag_ids)
+}
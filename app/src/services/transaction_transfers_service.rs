@@ -0,0 +1,127 @@
+use crate::dto::request::transactions_dto::CreateTransactionTransferRequest;
+use crate::entities::sea_orm_active_enums::{
+    TransactionRevisionAction, TransactionStatus, TransactionType, WalletMemberRole,
+};
+use crate::entities::{transactions, users, wallets};
+use crate::errors::AppError;
+use crate::repositories::transactions_repository;
+use crate::services::{
+    categories_service, exchange_rates_service, transaction_revisions_service,
+    transactions_service, wallets_service,
+};
+use crate::utils::currency_utils::parse_amount;
+use rust_decimal::Decimal;
+use sea_orm::{
+    ActiveValue, ConnectionTrait, DatabaseConnection, IntoActiveModel, TransactionTrait,
+};
+use time::OffsetDateTime;
+
+pub async fn create(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    request_id: Option<&str>,
+    payload: CreateTransactionTransferRequest,
+) -> Result<(transactions::Model, transactions::Model), AppError> {
+    if payload.from_wallet_id == payload.to_wallet_id {
+        return Err(AppError::ParseBody(String::from(
+            "from_wallet_id and to_wallet_id must be different wallets",
+        )));
+    }
+    let status = match payload.status {
+        Some(ref status) => transactions_service::parse_status(status)?,
+        None => TransactionStatus::Pending,
+    };
+    let issued_at = payload
+        .issued_at
+        .unwrap_or_else(|| OffsetDateTime::now_utc().date());
+
+    let txn = db.begin().await?;
+
+    let from_wallet =
+        wallets_service::get_by_id(&txn, user, payload.from_wallet_id, WalletMemberRole::Owner)
+            .await?;
+    let to_wallet =
+        wallets_service::get_by_id(&txn, user, payload.to_wallet_id, WalletMemberRole::Owner)
+            .await?;
+    wallets_service::ensure_not_archived(&from_wallet)?;
+    wallets_service::ensure_not_archived(&to_wallet)?;
+    let found_category = categories_service::get_by_id(&txn, user, payload.category_id).await?;
+
+    let amount = parse_amount(&payload.amount, &from_wallet.currency)?;
+    let to_amount = match payload.to_amount {
+        Some(ref to_amount) => parse_amount(to_amount, &to_wallet.currency)?,
+        None if from_wallet.currency == to_wallet.currency => amount,
+        None => {
+            let (converted_amount, _, _) = exchange_rates_service::convert(
+                &txn,
+                user,
+                amount,
+                &from_wallet.currency,
+                &to_wallet.currency,
+                issued_at,
+            )
+            .await?;
+            converted_amount
+        }
+    };
+    if amount <= Decimal::ZERO || to_amount <= Decimal::ZERO {
+        return Err(AppError::ParseBody(String::from(
+            "amount must be greater than 0",
+        )));
+    }
+
+    // transfers only move money between wallets, so they skip rules, payees and budgets
+    let new_transaction =
+        |wallet: &wallets::Model, amount, flow_direction| transactions::ActiveModel {
+            user_id: ActiveValue::Set(user.id),
+            created_by_user_id: ActiveValue::Set(Some(user.id)),
+            category_id: ActiveValue::Set(found_category.id),
+            wallet_id: ActiveValue::Set(wallet.id),
+            amount: ActiveValue::Set(amount),
+            currency: ActiveValue::Set(wallet.currency.clone()),
+            description: ActiveValue::Set(payload.description.clone()),
+            flow_direction: ActiveValue::Set(flow_direction),
+            issued_at: ActiveValue::Set(issued_at),
+            status: ActiveValue::Set(status.clone()),
+            posted_at: ActiveValue::Set(transactions_service::posted_at_for(issued_at, None)),
+            ..Default::default()
+        };
+    let from_transaction = new_transaction(&from_wallet, amount, TransactionType::Outcome);
+    let from_transaction =
+        insert_leg(&txn, user, request_id, from_wallet, from_transaction).await?;
+    let mut to_transaction = new_transaction(&to_wallet, to_amount, TransactionType::Income);
+    to_transaction.transfer_transaction_id = ActiveValue::Set(Some(from_transaction.id));
+    let to_transaction = insert_leg(&txn, user, request_id, to_wallet, to_transaction).await?;
+
+    let mut from_transaction = from_transaction.into_active_model();
+    from_transaction.transfer_transaction_id = ActiveValue::Set(Some(to_transaction.id));
+    let from_transaction = transactions_repository::save(&txn, from_transaction).await?;
+
+    txn.commit().await?;
+
+    Ok((from_transaction, to_transaction))
+}
+
+async fn insert_leg(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    request_id: Option<&str>,
+    wallet: wallets::Model,
+    new_transaction: transactions::ActiveModel,
+) -> Result<transactions::Model, AppError> {
+    let new_transaction = transactions_repository::save(connection, new_transaction).await?;
+
+    wallets_service::update_balance_after_transaction(connection, wallet, &new_transaction).await?;
+
+    transaction_revisions_service::record(
+        connection,
+        user,
+        request_id,
+        TransactionRevisionAction::Created,
+        None,
+        Some(&new_transaction),
+    )
+    .await?;
+
+    Ok(new_transaction)
+}
@@ -1,62 +1,197 @@
-use crate::dto::request::transactions_dto::{CreateTransactionRequest, UpdateTransactionRequest};
-use crate::entities::sea_orm_active_enums::TransactionType;
+use crate::constants::transaction_constants::COORDINATE_SCALE;
+use crate::dto::request::transactions_dto::{
+    CreateTransactionRequest, LocationArea, UpdateTransactionRequest,
+    UpdateTransactionStatusRequest, ValidatedFindAllTransactionsParams,
+    ValidatedSearchTransactionsParams,
+};
+use crate::dto::response::transactions_dto::PlaceTotalRow;
+use crate::entities::sea_orm_active_enums::{
+    TransactionRevisionAction, TransactionStatus, TransactionType, WalletMemberRole,
+};
 use crate::entities::{transactions, users};
 use crate::errors::AppError;
-use crate::repositories::transactions_repository;
+use crate::repositories::{transactions_repository, users_repository};
 use crate::services::budgets_service;
 use crate::services::categories_service;
+use crate::services::payees_service;
+use crate::services::transaction_refunds_service;
+use crate::services::transaction_revisions_service;
+use crate::services::transaction_rules_service;
+use crate::services::transaction_tags_service;
 use crate::services::wallets_service;
+use crate::utils::currency_utils::parse_amount;
 use rust_decimal::Decimal;
 use sea_orm::{
     ActiveEnum, ActiveValue, ConnectionTrait, DatabaseConnection, IntoActiveModel,
-    TransactionTrait, TryIntoModel,
+    ItemsAndPagesNumber, TransactionTrait, TryIntoModel,
 };
+use std::collections::HashMap;
 use std::str::FromStr;
-use time::OffsetDateTime;
+use time::{Date, OffsetDateTime};
 
 pub async fn create(
     db: &DatabaseConnection,
     user: &users::Model,
+    request_id: Option<&str>,
     payload: CreateTransactionRequest,
 ) -> Result<transactions::Model, AppError> {
     let txn = db.begin().await?;
-    let found_wallet = wallets_service::get_by_id(&txn, user, payload.wallet_id).await?;
+    let new_transaction = insert(&txn, user, request_id, payload).await?;
+    txn.commit().await?;
+
+    Ok(new_transaction)
+}
+
+pub async fn insert(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    request_id: Option<&str>,
+    payload: CreateTransactionRequest,
+) -> Result<transactions::Model, AppError> {
+    insert_transaction(connection, user, request_id, payload, None).await
+}
+
+// refunds mirror the category, budget and payee of their original, rules and payee matching
+// would otherwise move the money back into a budget the original never spent from
+pub async fn insert_refund(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    request_id: Option<&str>,
+    payload: CreateTransactionRequest,
+    refunded_transaction: &transactions::Model,
+) -> Result<transactions::Model, AppError> {
+    insert_transaction(
+        connection,
+        user,
+        request_id,
+        payload,
+        Some(refunded_transaction),
+    )
+    .await
+}
+
+async fn insert_transaction(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    request_id: Option<&str>,
+    payload: CreateTransactionRequest,
+    refunded_transaction: Option<&transactions::Model>,
+) -> Result<transactions::Model, AppError> {
+    let found_wallet = wallets_service::get_by_id(
+        connection,
+        user,
+        payload.wallet_id,
+        WalletMemberRole::Editor,
+    )
+    .await?;
+    wallets_service::ensure_not_archived(&found_wallet)?;
+    // categories, budgets, payees, rules and tags come from the ledger the wallet belongs to
+    let owner = wallets_service::find_owner(connection, user, &found_wallet).await?;
+    if let Some(ref currency) = payload.currency {
+        wallets_service::ensure_currency(&found_wallet, currency)?;
+    }
+
+    let amount = parse_amount(&payload.amount, &found_wallet.currency)?;
+    let flow_direction = TransactionType::try_from_value(&payload.flow_direction)?;
+    let status = match payload.status {
+        Some(ref status) => parse_status(status)?,
+        None => TransactionStatus::Pending,
+    };
+
+    let mut category_id = payload.category_id;
+    let mut payload_budget_id = payload.budget_id;
+    let mut tag_ids = payload.tag_ids.unwrap_or_default();
+    if let Some(refunded_transaction) = refunded_transaction {
+        category_id = Some(refunded_transaction.category_id);
+        payload_budget_id = refunded_transaction.budget_id;
+    } else {
+        let matchers = transaction_rules_service::find_all_matchers(connection, &owner).await?;
+        if let Some(matcher) = transaction_rules_service::find_first_match(
+            &matchers,
+            found_wallet.id,
+            amount,
+            &flow_direction,
+            payload.description.as_deref(),
+        ) {
+            category_id = category_id.or(matcher.category_id);
+            payload_budget_id = payload_budget_id.or(matcher.budget_id);
+            tag_ids.extend_from_slice(&matcher.tag_ids);
+        }
+    }
+    let Some(category_id) = category_id else {
+        return Err(AppError::ParseBody(String::from(
+            "category_id is required when no transaction rule matches",
+        )));
+    };
 
     let mut budget_id = None;
     let mut found_budget = None;
-    if let Some(payload_budget_id) = payload.budget_id {
-        found_budget = Some(budgets_service::get_by_id(&txn, user, payload_budget_id).await?);
+    if let Some(payload_budget_id) = payload_budget_id {
+        found_budget =
+            Some(budgets_service::get_by_id(connection, &owner, payload_budget_id).await?);
         budget_id = Some(found_budget.as_ref().unwrap().id);
     }
 
-    let found_category = categories_service::get_by_id(&txn, user, payload.category_id).await?;
+    let found_category = categories_service::get_by_id(connection, &owner, category_id).await?;
+
+    let payee_id = match refunded_transaction {
+        Some(refunded_transaction) => refunded_transaction.payee_id,
+        None => {
+            find_payee_id(
+                connection,
+                &owner,
+                payload.payee_id,
+                payload.description.as_deref(),
+            )
+            .await?
+        }
+    };
 
     let new_transaction = transactions::ActiveModel {
-        user_id: ActiveValue::Set(user.id),
+        user_id: ActiveValue::Set(owner.id),
+        created_by_user_id: ActiveValue::Set(Some(user.id)),
         category_id: ActiveValue::Set(found_category.id),
         budget_id: ActiveValue::Set(budget_id),
+        payee_id: ActiveValue::Set(payee_id),
         wallet_id: ActiveValue::Set(found_wallet.id),
-        amount: ActiveValue::Set(Decimal::from_str(&payload.amount)?),
+        amount: ActiveValue::Set(amount),
+        currency: ActiveValue::Set(found_wallet.currency.clone()),
         description: ActiveValue::Set(payload.description),
-        flow_direction: ActiveValue::Set(TransactionType::try_from_value(&payload.flow_direction)?),
+        flow_direction: ActiveValue::Set(flow_direction),
         issued_at: ActiveValue::Set(payload.issued_at),
+        status: ActiveValue::Set(status),
+        posted_at: ActiveValue::Set(posted_at_for(payload.issued_at, None)),
+        latitude: ActiveValue::Set(parse_coordinate(payload.latitude)?),
+        longitude: ActiveValue::Set(parse_coordinate(payload.longitude)?),
+        place_name: ActiveValue::Set(payload.place_name),
+        refunded_transaction_id: ActiveValue::Set(
+            refunded_transaction.map(|refunded_transaction| refunded_transaction.id),
+        ),
         ..Default::default()
     };
-    let new_transaction = transactions_repository::save(&txn, new_transaction).await?;
+    let new_transaction = transactions_repository::save(connection, new_transaction).await?;
 
-    wallets_service::update_balance_after_transaction(&txn, found_wallet, &new_transaction).await?;
+    transaction_tags_service::add_tags(connection, &owner, new_transaction.id, &tag_ids).await?;
+
+    wallets_service::update_balance_after_transaction(connection, found_wallet, &new_transaction)
+        .await?;
 
     if let Some(found_budget) = found_budget
-        && new_transaction.flow_direction == TransactionType::Outcome
+        && let Some((_, budget_amount)) = budget_effect(&new_transaction)
     {
-        budgets_service::update_amount_after_transaction(
-            &txn,
-            found_budget,
-            new_transaction.amount,
-        )
-        .await?;
+        budgets_service::update_amount_after_transaction(connection, found_budget, budget_amount)
+            .await?;
     }
-    txn.commit().await?;
+
+    transaction_revisions_service::record(
+        connection,
+        user,
+        request_id,
+        TransactionRevisionAction::Created,
+        None,
+        Some(&new_transaction),
+    )
+    .await?;
 
     Ok(new_transaction)
 }
@@ -64,41 +199,250 @@ pub async fn create(
 pub async fn find_all(
     db: &DatabaseConnection,
     user: &users::Model,
+    params: ValidatedFindAllTransactionsParams,
 ) -> Result<Vec<transactions::Model>, AppError> {
+    // filtering by a shared wallet lists it from the ledger of its owner
+    let ledger_user_id = match params.wallet_id {
+        Some(wallet_id) => {
+            wallets_service::get_by_id(db, user, wallet_id, WalletMemberRole::Viewer)
+                .await?
+                .user_id
+        }
+        None => user.id,
+    };
     let found_transactions =
         transactions_repository::find_all_active_by_user_id_order_by_issued_at_and_created_at_desc(
-            db, user.id,
+            db,
+            ledger_user_id,
+            params,
         )
         .await?;
     Ok(found_transactions)
 }
 
+pub async fn find_all_nearby(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    params: ValidatedFindAllTransactionsParams,
+    location_area: LocationArea,
+) -> Result<Vec<transactions::Model>, AppError> {
+    let found_transactions = transactions_repository::find_all_active_by_user_id_and_location_area_order_by_issued_at_and_created_at_desc(
+        db,
+        user.id,
+        params,
+        location_area,
+    )
+    .await?;
+    Ok(found_transactions)
+}
+
+pub async fn find_place_totals(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    params: ValidatedFindAllTransactionsParams,
+    location_area: Option<LocationArea>,
+) -> Result<Vec<PlaceTotalRow>, AppError> {
+    let found_totals = transactions_repository::find_place_totals_by_user_id_and_params(
+        db,
+        user.id,
+        params,
+        location_area,
+    )
+    .await?;
+    Ok(found_totals)
+}
+
+pub async fn search(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    params: ValidatedFindAllTransactionsParams,
+    search_params: ValidatedSearchTransactionsParams,
+) -> Result<(Vec<(transactions::Model, f32, String)>, ItemsAndPagesNumber), AppError> {
+    let (found_rows, page_information) =
+        transactions_repository::search_active_by_user_id_and_params_order_by_rank_desc(
+            db,
+            user.id,
+            params,
+            search_params,
+        )
+        .await?;
+
+    let mut found_transactions: HashMap<i32, transactions::Model> =
+        transactions_repository::find_all_by_ids(
+            db,
+            found_rows.iter().map(|found_row| found_row.id).collect(),
+        )
+        .await?
+        .into_iter()
+        .map(|transaction| (transaction.id, transaction))
+        .collect();
+
+    // keep the ranking order of the search rows
+    let found_results = found_rows
+        .into_iter()
+        .filter_map(|found_row| {
+            found_transactions
+                .remove(&found_row.id)
+                .map(|transaction| (transaction, found_row.rank, found_row.highlight))
+        })
+        .collect();
+    Ok((found_results, page_information))
+}
+
+// transactions of a shared wallet are reachable with the role the user has on that wallet
 pub async fn get_by_id(
     connection: &impl ConnectionTrait,
     user: &users::Model,
     transaction_id: i32,
+    required_role: WalletMemberRole,
 ) -> Result<transactions::Model, AppError> {
     let found_transaction =
-        transactions_repository::get_active_by_id_and_user_id(connection, transaction_id, user.id)
-            .await?;
+        transactions_repository::get_active_by_id(connection, transaction_id).await?;
     let Some(found_transaction) = found_transaction else {
         return Err(AppError::NotFound(String::from("Transaction not found")));
     };
+    if found_transaction.user_id != user.id {
+        match wallets_service::get_by_id(
+            connection,
+            user,
+            found_transaction.wallet_id,
+            required_role,
+        )
+        .await
+        {
+            Err(AppError::NotFound(_)) => {
+                return Err(AppError::NotFound(String::from("Transaction not found")));
+            }
+            found_wallet => found_wallet?,
+        };
+    }
     Ok(found_transaction)
 }
 
+// members edit inside the ledger of the wallet owner, the acting user is only recorded
+async fn find_ledger_owner(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    transaction: &transactions::Model,
+) -> Result<users::Model, AppError> {
+    if transaction.user_id == user.id {
+        return Ok(user.clone());
+    }
+    let Some(found_owner) =
+        users_repository::get_active_by_id(connection, transaction.user_id).await?
+    else {
+        return Err(AppError::NotFound(String::from("Transaction not found")));
+    };
+    Ok(found_owner)
+}
+
 pub async fn update_by_id(
     db: &DatabaseConnection,
     user: &users::Model,
+    request_id: Option<&str>,
     transaction_id: i32,
     payload: UpdateTransactionRequest,
 ) -> Result<transactions::Model, AppError> {
     let txn = db.begin().await?;
 
-    let found_transaction = get_by_id(&txn, user, transaction_id).await?;
+    let found_transaction = get_by_id(&txn, user, transaction_id, WalletMemberRole::Editor).await?;
+    ensure_not_reconciled(&found_transaction)?;
+    if found_transaction.refunded_transaction_id.is_some() {
+        return Err(AppError::Conflict(String::from(
+            "Refunds cannot be edited, delete and create them again",
+        )));
+    }
+    ensure_not_transfer(&found_transaction)?;
+    let refunded_amount =
+        transaction_refunds_service::find_refunded_amount(&txn, found_transaction.id).await?;
+    if !refunded_amount.is_zero()
+        && (payload.flow_direction != TransactionType::Outcome.to_value()
+            || Decimal::from_str(&payload.amount)? < refunded_amount)
+    {
+        return Err(AppError::Conflict(format!(
+            "Transaction has {refunded_amount} refunded, it has to stay an outcome of at least that amount"
+        )));
+    }
     revert_transaction(&txn, user, &found_transaction).await?;
 
-    let updated_transaction = apply_transaction(&txn, user, found_transaction, payload).await?;
+    if let Some(ref tag_ids) = payload.tag_ids {
+        let owner = find_ledger_owner(&txn, user, &found_transaction).await?;
+        transaction_tags_service::replace_tags(&txn, &owner, found_transaction.id, tag_ids).await?;
+    }
+
+    let updated_transaction =
+        apply_transaction(&txn, user, found_transaction.clone(), payload).await?;
+
+    transaction_revisions_service::record(
+        &txn,
+        user,
+        request_id,
+        TransactionRevisionAction::Updated,
+        Some(&found_transaction),
+        Some(&updated_transaction),
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    Ok(updated_transaction)
+}
+
+pub fn parse_status(status: &str) -> Result<TransactionStatus, AppError> {
+    let status = TransactionStatus::try_from_value(&String::from(status))
+        .map_err(|_| AppError::ParseBody(String::from("invalid status")))?;
+    if status == TransactionStatus::Reconciled {
+        return Err(AppError::ParseBody(String::from(
+            "transactions can only be reconciled through a wallet reconciliation",
+        )));
+    }
+    Ok(status)
+}
+
+fn ensure_not_transfer(transaction: &transactions::Model) -> Result<(), AppError> {
+    if transaction.transfer_transaction_id.is_some() {
+        return Err(AppError::Conflict(String::from(
+            "Transfers cannot be edited, delete and create them again",
+        )));
+    }
+    Ok(())
+}
+
+pub fn ensure_not_reconciled(transaction: &transactions::Model) -> Result<(), AppError> {
+    if transaction.status == TransactionStatus::Reconciled {
+        return Err(AppError::Conflict(String::from(
+            "Reconciled transactions cannot be changed",
+        )));
+    }
+    Ok(())
+}
+
+pub async fn update_status_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    request_id: Option<&str>,
+    transaction_id: i32,
+    payload: UpdateTransactionStatusRequest,
+) -> Result<transactions::Model, AppError> {
+    let txn = db.begin().await?;
+
+    let found_transaction = get_by_id(&txn, user, transaction_id, WalletMemberRole::Editor).await?;
+    ensure_not_reconciled(&found_transaction)?;
+
+    let mut transaction = found_transaction.clone().into_active_model();
+    transaction.status = ActiveValue::Set(parse_status(&payload.status)?);
+    transaction.updated_at = ActiveValue::Set(OffsetDateTime::now_utc());
+    let updated_transaction = transactions_repository::save(&txn, transaction).await?;
+
+    transaction_revisions_service::record(
+        &txn,
+        user,
+        request_id,
+        TransactionRevisionAction::Updated,
+        Some(&found_transaction),
+        Some(&updated_transaction),
+    )
+    .await?;
 
     txn.commit().await?;
 
@@ -110,18 +454,65 @@ async fn revert_transaction(
     user: &users::Model,
     transaction: &transactions::Model,
 ) -> Result<(), AppError> {
-    wallets_service::revert_transaction(connection, user, transaction).await?;
+    let owner = find_ledger_owner(connection, user, transaction).await?;
+    wallets_service::revert_transaction(connection, &owner, transaction).await?;
 
-    if let Some(budget_id) = transaction.budget_id
-        && transaction.flow_direction == TransactionType::Outcome
-    {
-        budgets_service::revert_transaction(connection, user, budget_id, transaction.amount)
-            .await?;
+    if let Some((budget_id, budget_amount)) = budget_effect(transaction) {
+        budgets_service::revert_transaction(connection, &owner, budget_id, budget_amount).await?;
     }
 
     Ok(())
 }
 
+fn parse_coordinate(coordinate: Option<f64>) -> Result<Option<Decimal>, AppError> {
+    match coordinate {
+        Some(coordinate) => Ok(Some(
+            Decimal::try_from(coordinate)?.round_dp(COORDINATE_SCALE),
+        )),
+        None => Ok(None),
+    }
+}
+
+// scheduled transactions only move wallets and budgets once their date arrives
+pub fn posted_at_for(issued_at: Date, posted_at: Option<OffsetDateTime>) -> Option<OffsetDateTime> {
+    let now = OffsetDateTime::now_utc();
+    if issued_at > now.date() {
+        return None;
+    }
+    Some(posted_at.unwrap_or(now))
+}
+
+// spending counts against the budget, refunds of that spending give it back
+pub fn budget_effect(transaction: &transactions::Model) -> Option<(i32, Decimal)> {
+    transaction.posted_at?;
+    if transaction.transfer_transaction_id.is_some() {
+        return None;
+    }
+    let budget_id = transaction.budget_id?;
+    match transaction.flow_direction {
+        TransactionType::Outcome => Some((budget_id, transaction.amount)),
+        TransactionType::Income if transaction.refunded_transaction_id.is_some() => {
+            Some((budget_id, -transaction.amount))
+        }
+        TransactionType::Income => None,
+    }
+}
+
+async fn find_payee_id(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    payee_id: Option<i32>,
+    description: Option<&str>,
+) -> Result<Option<i32>, AppError> {
+    if let Some(payee_id) = payee_id {
+        let found_payee = payees_service::get_by_id(connection, user, payee_id).await?;
+        return Ok(Some(found_payee.id));
+    }
+
+    let matchers = payees_service::find_all_matchers(connection, user).await?;
+    Ok(payees_service::find_best_match(&matchers, description))
+}
+
 async fn apply_transaction(
     connection: &impl ConnectionTrait,
     user: &users::Model,
@@ -129,59 +520,401 @@ async fn apply_transaction(
     payload: UpdateTransactionRequest,
 ) -> Result<transactions::Model, AppError> {
     let transaction_category_id = transaction.category_id;
+    let transaction_posted_at = transaction.posted_at;
+
+    // moving to another wallet keeps the currency unless a matching one is given explicitly
+    let found_wallet = wallets_service::get_by_id(
+        connection,
+        user,
+        payload.wallet_id,
+        WalletMemberRole::Editor,
+    )
+    .await?;
+    if found_wallet.id != transaction.wallet_id {
+        wallets_service::ensure_not_archived(&found_wallet)?;
+        if found_wallet.user_id != transaction.user_id {
+            return Err(AppError::Conflict(String::from(
+                "Transactions cannot be moved to a wallet of another owner",
+            )));
+        }
+    }
+    let owner = find_ledger_owner(connection, user, &transaction).await?;
+    let user = &owner;
+    wallets_service::ensure_currency(
+        &found_wallet,
+        payload.currency.as_deref().unwrap_or(&transaction.currency),
+    )?;
+    let amount = parse_amount(&payload.amount, &found_wallet.currency)?;
+
+    let payee_id = find_payee_id(
+        connection,
+        user,
+        payload.payee_id,
+        payload.description.as_deref(),
+    )
+    .await?;
 
     let mut transaction = transaction.into_active_model();
-    transaction.amount = ActiveValue::Set(Decimal::from_str(&payload.amount)?);
+    transaction.payee_id = ActiveValue::Set(payee_id);
+    transaction.amount = ActiveValue::Set(amount);
     transaction.description = ActiveValue::Set(payload.description);
     transaction.flow_direction =
         ActiveValue::Set(TransactionType::try_from_value(&payload.flow_direction)?);
     transaction.budget_id = ActiveValue::Set(payload.budget_id);
     transaction.issued_at = ActiveValue::Set(payload.issued_at);
+    let posted_at = posted_at_for(payload.issued_at, transaction_posted_at);
+    transaction.posted_at = ActiveValue::Set(posted_at);
+    transaction.latitude = ActiveValue::Set(parse_coordinate(payload.latitude)?);
+    transaction.longitude = ActiveValue::Set(parse_coordinate(payload.longitude)?);
+    transaction.place_name = ActiveValue::Set(payload.place_name);
     transaction.updated_at = ActiveValue::Set(OffsetDateTime::now_utc());
     if transaction_category_id != payload.category_id {
         let found_category =
             categories_service::get_by_id(connection, user, payload.category_id).await?;
         transaction.category_id = ActiveValue::Set(found_category.id);
     }
-    if let Some(payload_budget_id) = payload.budget_id {
-        let found_budget = budgets_service::get_by_id(connection, user, payload_budget_id).await?;
-        budgets_service::update_amount_after_transaction(
-            connection,
-            found_budget,
-            Decimal::from_str(&payload.amount)?,
-        )
-        .await?;
-    }
+    let found_budget = match payload.budget_id {
+        Some(payload_budget_id) => {
+            Some(budgets_service::get_by_id(connection, user, payload_budget_id).await?)
+        }
+        None => None,
+    };
 
-    let found_wallet = wallets_service::get_by_id(connection, user, payload.wallet_id).await?;
     transaction.wallet_id = ActiveValue::Set(found_wallet.id);
+    let applied_transaction = transaction.clone().try_into_model()?;
     wallets_service::update_balance_after_transaction(
         connection,
         found_wallet,
-        &transaction.clone().try_into_model()?,
+        &applied_transaction,
+    )
+    .await?;
+
+    // same rule as on create, income only moves a budget when it refunds spending
+    if let Some(found_budget) = found_budget
+        && let Some((_, budget_amount)) = budget_effect(&applied_transaction)
+    {
+        budgets_service::update_amount_after_transaction(connection, found_budget, budget_amount)
+            .await?;
+    }
+
+    let updated_transaction = transactions_repository::save(connection, transaction).await?;
+    Ok(updated_transaction)
+}
+
+pub async fn reassign(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    request_id: Option<&str>,
+    transaction: transactions::Model,
+    category_id: i32,
+    budget_id: Option<i32>,
+) -> Result<transactions::Model, AppError> {
+    ensure_not_reconciled(&transaction)?;
+
+    if transaction.budget_id != budget_id {
+        if let Some((previous_budget_id, budget_amount)) = budget_effect(&transaction) {
+            budgets_service::revert_transaction(
+                connection,
+                user,
+                previous_budget_id,
+                budget_amount,
+            )
+            .await?;
+        }
+        let reassigned_transaction = transactions::Model {
+            budget_id,
+            ..transaction.clone()
+        };
+        if let Some((budget_id, budget_amount)) = budget_effect(&reassigned_transaction) {
+            let found_budget = budgets_service::get_by_id(connection, user, budget_id).await?;
+            budgets_service::update_amount_after_transaction(
+                connection,
+                found_budget,
+                budget_amount,
+            )
+            .await?;
+        }
+    }
+
+    let previous_transaction = transaction.clone();
+    let mut transaction = transaction.into_active_model();
+    transaction.category_id = ActiveValue::Set(category_id);
+    transaction.budget_id = ActiveValue::Set(budget_id);
+    transaction.updated_at = ActiveValue::Set(OffsetDateTime::now_utc());
+
+    let updated_transaction = transactions_repository::save(connection, transaction).await?;
+    transaction_revisions_service::record(
+        connection,
+        user,
+        request_id,
+        TransactionRevisionAction::Updated,
+        Some(&previous_transaction),
+        Some(&updated_transaction),
     )
     .await?;
+    Ok(updated_transaction)
+}
+
+pub async fn move_to_wallet(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    request_id: Option<&str>,
+    transaction: transactions::Model,
+    wallet_id: i32,
+) -> Result<transactions::Model, AppError> {
+    ensure_not_reconciled(&transaction)?;
+    ensure_not_transfer(&transaction)?;
+    if transaction.wallet_id == wallet_id {
+        return Ok(transaction);
+    }
+
+    let found_wallet =
+        wallets_service::get_by_id(connection, user, wallet_id, WalletMemberRole::Owner).await?;
+    wallets_service::ensure_not_archived(&found_wallet)?;
+    wallets_service::ensure_currency(&found_wallet, &transaction.currency)?;
+    wallets_service::revert_transaction(connection, user, &transaction).await?;
+
+    let previous_transaction = transaction.clone();
+    let mut transaction = transaction.into_active_model();
+    transaction.wallet_id = ActiveValue::Set(found_wallet.id);
+    transaction.updated_at = ActiveValue::Set(OffsetDateTime::now_utc());
 
     let updated_transaction = transactions_repository::save(connection, transaction).await?;
+    wallets_service::update_balance_after_transaction(
+        connection,
+        found_wallet,
+        &updated_transaction,
+    )
+    .await?;
+    transaction_revisions_service::record(
+        connection,
+        user,
+        request_id,
+        TransactionRevisionAction::Updated,
+        Some(&previous_transaction),
+        Some(&updated_transaction),
+    )
+    .await?;
     Ok(updated_transaction)
 }
 
 pub async fn delete_by_id(
     db: &DatabaseConnection,
     user: &users::Model,
+    request_id: Option<&str>,
     transaction_id: i32,
 ) -> Result<(), AppError> {
     let txn = db.begin().await?;
 
-    let found_transaction = get_by_id(&txn, user, transaction_id).await?;
-    revert_transaction(&txn, user, &found_transaction).await?;
+    let found_transaction = get_by_id(&txn, user, transaction_id, WalletMemberRole::Editor).await?;
+    delete(&txn, user, request_id, found_transaction).await?;
 
-    let mut found_transaction = found_transaction.into_active_model();
-    found_transaction.deleted_at = ActiveValue::Set(Some(OffsetDateTime::now_utc()));
+    txn.commit().await?;
 
-    transactions_repository::save(&txn, found_transaction).await?;
+    Ok(())
+}
 
-    txn.commit().await?;
+pub async fn delete(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    request_id: Option<&str>,
+    transaction: transactions::Model,
+) -> Result<(), AppError> {
+    ensure_not_reconciled(&transaction)?;
+    if !transaction_refunds_service::find_refunded_amount(connection, transaction.id)
+        .await?
+        .is_zero()
+    {
+        return Err(AppError::Conflict(String::from(
+            "Delete the refunds of this transaction first",
+        )));
+    }
+
+    // both sides of a transfer are deleted together
+    let transfer_transaction = match transaction.transfer_transaction_id {
+        Some(transfer_transaction_id) => {
+            match get_by_id(
+                connection,
+                user,
+                transfer_transaction_id,
+                WalletMemberRole::Editor,
+            )
+            .await
+            {
+                Err(AppError::NotFound(_)) => None,
+                transfer_transaction => Some(transfer_transaction?),
+            }
+        }
+        None => None,
+    };
+    if let Some(ref transfer_transaction) = transfer_transaction {
+        ensure_not_reconciled(transfer_transaction)?;
+    }
+
+    soft_delete(connection, user, request_id, transaction).await?;
+    if let Some(transfer_transaction) = transfer_transaction {
+        soft_delete(connection, user, request_id, transfer_transaction).await?;
+    }
 
     Ok(())
 }
+
+async fn soft_delete(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    request_id: Option<&str>,
+    transaction: transactions::Model,
+) -> Result<(), AppError> {
+    revert_transaction(connection, user, &transaction).await?;
+
+    let previous_transaction = transaction.clone();
+    let mut transaction = transaction.into_active_model();
+    transaction.deleted_at = ActiveValue::Set(Some(OffsetDateTime::now_utc()));
+
+    transactions_repository::save(connection, transaction).await?;
+    transaction_revisions_service::record(
+        connection,
+        user,
+        request_id,
+        TransactionRevisionAction::Deleted,
+        Some(&previous_transaction),
+        None,
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn find_all_deleted(
+    db: &DatabaseConnection,
+    user: &users::Model,
+) -> Result<Vec<transactions::Model>, AppError> {
+    let found_transactions =
+        transactions_repository::find_all_deleted_by_user_id_order_by_deleted_at_desc(db, user.id)
+            .await?;
+    Ok(found_transactions)
+}
+
+pub async fn restore_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    request_id: Option<&str>,
+    transaction_id: i32,
+) -> Result<transactions::Model, AppError> {
+    let txn = db.begin().await?;
+
+    let found_transaction =
+        transactions_repository::get_deleted_by_id_and_user_id(&txn, transaction_id, user.id)
+            .await?;
+    let Some(found_transaction) = found_transaction else {
+        return Err(AppError::NotFound(String::from(
+            "Transaction not found in trash",
+        )));
+    };
+    let transfer_transaction = match found_transaction.transfer_transaction_id {
+        Some(transfer_transaction_id) => {
+            transactions_repository::get_deleted_by_id_and_user_id(
+                &txn,
+                transfer_transaction_id,
+                user.id,
+            )
+            .await?
+        }
+        None => None,
+    };
+
+    let restored_transaction = restore(&txn, user, request_id, found_transaction).await?;
+    if let Some(transfer_transaction) = transfer_transaction {
+        restore(&txn, user, request_id, transfer_transaction).await?;
+    }
+
+    txn.commit().await?;
+
+    Ok(restored_transaction)
+}
+
+pub async fn restore(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    request_id: Option<&str>,
+    found_transaction: transactions::Model,
+) -> Result<transactions::Model, AppError> {
+    // the wallet and budget have to be alive again before their amounts can be re-applied
+    let found_wallet = match wallets_service::get_by_id(
+        connection,
+        user,
+        found_transaction.wallet_id,
+        WalletMemberRole::Owner,
+    )
+    .await
+    {
+        Err(AppError::NotFound(_)) => {
+            return Err(AppError::Conflict(String::from(
+                "Restore the wallet of this transaction first",
+            )));
+        }
+        found_wallet => found_wallet?,
+    };
+    let mut found_budget = None;
+    if let Some((budget_id, budget_amount)) = budget_effect(&found_transaction) {
+        found_budget = match budgets_service::get_by_id(connection, user, budget_id).await {
+            Err(AppError::NotFound(_)) => {
+                return Err(AppError::Conflict(String::from(
+                    "Restore the budget of this transaction first",
+                )));
+            }
+            found_budget => Some((found_budget?, budget_amount)),
+        };
+    }
+    if let Some(refunded_transaction_id) = found_transaction.refunded_transaction_id {
+        let refunded_transaction = match get_by_id(
+            connection,
+            user,
+            refunded_transaction_id,
+            WalletMemberRole::Owner,
+        )
+        .await
+        {
+            Err(AppError::NotFound(_)) => {
+                return Err(AppError::Conflict(String::from(
+                    "Restore the refunded transaction of this refund first",
+                )));
+            }
+            refunded_transaction => refunded_transaction?,
+        };
+        transaction_refunds_service::ensure_refundable(
+            connection,
+            &refunded_transaction,
+            found_transaction.amount,
+        )
+        .await?;
+    }
+
+    let mut found_transaction = found_transaction.into_active_model();
+    found_transaction.deleted_at = ActiveValue::Set(None);
+    found_transaction.updated_at = ActiveValue::Set(OffsetDateTime::now_utc());
+    let restored_transaction = transactions_repository::save(connection, found_transaction).await?;
+
+    wallets_service::update_balance_after_transaction(
+        connection,
+        found_wallet,
+        &restored_transaction,
+    )
+    .await?;
+    if let Some((found_budget, budget_amount)) = found_budget {
+        budgets_service::update_amount_after_transaction(connection, found_budget, budget_amount)
+            .await?;
+    }
+
+    transaction_revisions_service::record(
+        connection,
+        user,
+        request_id,
+        TransactionRevisionAction::Restored,
+        None,
+        Some(&restored_transaction),
+    )
+    .await?;
+
+    Ok(restored_transaction)
+}
@@ -79,6 +79,9 @@ pub async fn update_using_model(
     let mut user_model = user_model.into_active_model();
     user_model.username = ActiveValue::Set(String::from(&payload.username));
     user_model.email = ActiveValue::Set(String::from(&payload.email));
+    if let Some(ref reporting_currency) = payload.reporting_currency {
+        user_model.reporting_currency = ActiveValue::Set(String::from(reporting_currency));
+    }
     let updated_user = user_model.save(db).await?.try_into_model()?;
 
     Ok(updated_user)
@@ -0,0 +1,213 @@
+use crate::dto::request::wallets_dto::ValidatedFindWalletBalanceHistoryParams;
+use crate::entities::sea_orm_active_enums::WalletMemberRole;
+use crate::entities::{users, wallet_balance_snapshots, wallets};
+use crate::errors::AppError;
+use crate::repositories::wallet_balance_snapshots_repository;
+use crate::services::wallets_service;
+use rust_decimal::Decimal;
+use sea_orm::{ActiveValue, ConnectionTrait, DatabaseConnection};
+use time::{Date, Duration};
+
+// moves the closing balance of the day and of every later day, so backdated changes ripple forward
+pub async fn apply_change(
+    connection: &impl ConnectionTrait,
+    wallet_id: i32,
+    snapshot_date: Date,
+    amount: Decimal,
+) -> Result<(), AppError> {
+    if amount.is_zero() {
+        return Ok(());
+    }
+
+    let previous_balance =
+        wallet_balance_snapshots_repository::get_latest_by_wallet_id_and_snapshot_date_lt(
+            connection,
+            wallet_id,
+            snapshot_date,
+        )
+        .await?
+        .map(|previous_snapshot| previous_snapshot.balance)
+        .unwrap_or_default();
+    wallet_balance_snapshots_repository::insert_if_absent(
+        connection,
+        wallet_balance_snapshots::ActiveModel {
+            wallet_id: ActiveValue::Set(wallet_id),
+            snapshot_date: ActiveValue::Set(snapshot_date),
+            balance: ActiveValue::Set(previous_balance),
+            ..Default::default()
+        },
+    )
+    .await?;
+    wallet_balance_snapshots_repository::update_all_by_wallet_id_and_snapshot_date_gte_add_balance(
+        connection,
+        wallet_id,
+        snapshot_date,
+        amount,
+    )
+    .await?;
+    Ok(())
+}
+
+pub async fn find_history(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    wallet_id: i32,
+    params: &ValidatedFindWalletBalanceHistoryParams,
+) -> Result<(wallets::Model, Vec<(Date, Decimal)>), AppError> {
+    let found_wallet =
+        wallets_service::get_by_id(db, user, wallet_id, WalletMemberRole::Viewer).await?;
+    let points = find_points(db, found_wallet.id, params).await?;
+    Ok((found_wallet, points))
+}
+
+// one closing balance per period of the range, zero before the wallet had any
+pub async fn find_points(
+    connection: &impl ConnectionTrait,
+    wallet_id: i32,
+    params: &ValidatedFindWalletBalanceHistoryParams,
+) -> Result<Vec<(Date, Decimal)>, AppError> {
+    let base_date = params.start_date - Duration::days(1);
+    let base_balance =
+        wallet_balance_snapshots_repository::get_latest_by_wallet_id_and_snapshot_date_lte(
+            connection, wallet_id, base_date,
+        )
+        .await?
+        .map(|base_snapshot| base_snapshot.balance)
+        .unwrap_or_default();
+    let found_snapshots = wallet_balance_snapshots_repository::find_all_by_wallet_id_and_snapshot_date_gt_and_lte_order_by_snapshot_date_asc(
+        connection,
+        wallet_id,
+        base_date,
+        params.end_date,
+    )
+    .await?;
+
+    build_points(
+        base_balance,
+        found_snapshots
+            .into_iter()
+            .map(|found_snapshot| (found_snapshot.snapshot_date, found_snapshot.balance)),
+        params,
+    )
+}
+
+// the snapshots are the closing balances in the range, in date order, days without a snapshot
+// kept the closing balance of the day before
+fn build_points(
+    base_balance: Decimal,
+    snapshots: impl IntoIterator<Item = (Date, Decimal)>,
+    params: &ValidatedFindWalletBalanceHistoryParams,
+) -> Result<Vec<(Date, Decimal)>, AppError> {
+    let mut balance = base_balance;
+    let mut snapshots = snapshots.into_iter().peekable();
+    let mut points = Vec::new();
+    let mut period_start = params.start_date;
+    while period_start <= params.end_date {
+        let period_end = params
+            .granularity
+            .find_period_end(period_start)?
+            .min(params.end_date);
+        while let Some((_, snapshot_balance)) =
+            snapshots.next_if(|(snapshot_date, _)| *snapshot_date <= period_end)
+        {
+            balance = snapshot_balance;
+        }
+        points.push((period_end, balance));
+        period_start = period_end + Duration::days(1);
+    }
+    Ok(points)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::balance_history_granularity::BalanceHistoryGranularity;
+    use time::macros::date;
+
+    fn params(
+        granularity: BalanceHistoryGranularity,
+        start_date: Date,
+        end_date: Date,
+    ) -> ValidatedFindWalletBalanceHistoryParams {
+        ValidatedFindWalletBalanceHistoryParams {
+            granularity,
+            start_date,
+            end_date,
+        }
+    }
+
+    #[test]
+    fn build_points_carries_the_balance_over_days_without_a_snapshot() {
+        let points = build_points(
+            Decimal::from(100),
+            [(date!(2026 - 01 - 02), Decimal::from(80))],
+            &params(
+                BalanceHistoryGranularity::Day,
+                date!(2026 - 01 - 01),
+                date!(2026 - 01 - 04),
+            ),
+        )
+        .unwrap();
+
+        assert_eq!(
+            points,
+            vec![
+                (date!(2026 - 01 - 01), Decimal::from(100)),
+                (date!(2026 - 01 - 02), Decimal::from(80)),
+                (date!(2026 - 01 - 03), Decimal::from(80)),
+                (date!(2026 - 01 - 04), Decimal::from(80)),
+            ]
+        );
+    }
+
+    #[test]
+    fn build_points_keeps_the_last_balance_of_every_week() {
+        // 2026-01-05 is a monday
+        let points = build_points(
+            Decimal::ZERO,
+            [
+                (date!(2026 - 01 - 06), Decimal::from(10)),
+                (date!(2026 - 01 - 09), Decimal::from(30)),
+                (date!(2026 - 01 - 13), Decimal::from(20)),
+            ],
+            &params(
+                BalanceHistoryGranularity::Week,
+                date!(2026 - 01 - 05),
+                date!(2026 - 01 - 21),
+            ),
+        )
+        .unwrap();
+
+        assert_eq!(
+            points,
+            vec![
+                (date!(2026 - 01 - 11), Decimal::from(30)),
+                (date!(2026 - 01 - 18), Decimal::from(20)),
+                (date!(2026 - 01 - 21), Decimal::from(20)),
+            ]
+        );
+    }
+
+    #[test]
+    fn build_points_ends_months_on_their_last_day_and_cuts_the_last_one_short() {
+        let points = build_points(
+            Decimal::ZERO,
+            [(date!(2028 - 02 - 29), Decimal::from(50))],
+            &params(
+                BalanceHistoryGranularity::Month,
+                date!(2028 - 01 - 15),
+                date!(2028 - 03 - 10),
+            ),
+        )
+        .unwrap();
+
+        assert_eq!(
+            points,
+            vec![
+                (date!(2028 - 01 - 31), Decimal::ZERO),
+                (date!(2028 - 02 - 29), Decimal::from(50)),
+                (date!(2028 - 03 - 10), Decimal::from(50)),
+            ]
+        );
+    }
+}
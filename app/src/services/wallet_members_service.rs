@@ -0,0 +1,257 @@
+use crate::dto::request::wallet_members_dto::{
+    CreateWalletMemberRequest, UpdateWalletMemberRequest,
+};
+use crate::entities::sea_orm_active_enums::WalletMemberRole;
+use crate::entities::{users, wallet_members, wallets};
+use crate::errors::AppError;
+use crate::repositories::{users_repository, wallet_members_repository, wallets_repository};
+use crate::services::wallets_service;
+use sea_orm::{ActiveEnum, ActiveValue, ConnectionTrait, DatabaseConnection, IntoActiveModel};
+use std::collections::HashMap;
+use time::OffsetDateTime;
+
+pub async fn create(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    wallet_id: i32,
+    payload: CreateWalletMemberRequest,
+) -> Result<(wallet_members::Model, users::Model), AppError> {
+    let found_wallet =
+        wallets_service::get_by_id(db, user, wallet_id, WalletMemberRole::Owner).await?;
+    let role = parse_role(&payload.role)?;
+    // a username and an email could each point at a different user
+    let found_invitee = match (payload.username.as_deref(), payload.email.as_deref()) {
+        (Some(username), None) => users_repository::get_active_by_username(db, username).await?,
+        (None, Some(email)) => users_repository::get_active_by_email(db, email).await?,
+        _ => {
+            return Err(AppError::ParseBody(String::from(
+                "exactly one of username or email is required",
+            )));
+        }
+    };
+    let Some(found_invitee) = found_invitee else {
+        return Err(AppError::NotFound(String::from("User not found")));
+    };
+    if wallet_members_repository::get_by_wallet_id_and_user_id(
+        db,
+        found_wallet.id,
+        found_invitee.id,
+    )
+    .await?
+    .is_some()
+    {
+        return Err(AppError::Conflict(format!(
+            "{} is already a member of this wallet or invited to it",
+            found_invitee.username
+        )));
+    }
+
+    let new_wallet_member = wallet_members::ActiveModel {
+        wallet_id: ActiveValue::Set(found_wallet.id),
+        user_id: ActiveValue::Set(found_invitee.id),
+        role: ActiveValue::Set(role),
+        invited_by_user_id: ActiveValue::Set(Some(user.id)),
+        ..Default::default()
+    };
+    let new_wallet_member = wallet_members_repository::save(db, new_wallet_member).await?;
+    Ok((new_wallet_member, found_invitee))
+}
+
+pub async fn find_all(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    wallet_id: i32,
+) -> Result<Vec<(wallet_members::Model, users::Model)>, AppError> {
+    let found_wallet =
+        wallets_service::get_by_id(db, user, wallet_id, WalletMemberRole::Viewer).await?;
+    let found_wallet_members =
+        wallet_members_repository::find_all_by_wallet_id_order_by_created_at_asc(
+            db,
+            found_wallet.id,
+        )
+        .await?;
+    with_users(db, found_wallet_members).await
+}
+
+pub async fn update_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    wallet_id: i32,
+    wallet_member_id: i32,
+    payload: UpdateWalletMemberRequest,
+) -> Result<(wallet_members::Model, users::Model), AppError> {
+    let found_wallet =
+        wallets_service::get_by_id(db, user, wallet_id, WalletMemberRole::Owner).await?;
+    let role = parse_role(&payload.role)?;
+    let found_wallet_member = get_by_id(db, &found_wallet, wallet_member_id).await?;
+    if found_wallet_member.role == WalletMemberRole::Owner {
+        return Err(AppError::Conflict(String::from(
+            "The role of the wallet owner cannot be changed",
+        )));
+    }
+
+    let mut found_wallet_member = found_wallet_member.into_active_model();
+    found_wallet_member.role = ActiveValue::Set(role);
+    found_wallet_member.updated_at = ActiveValue::Set(OffsetDateTime::now_utc());
+    let updated_wallet_member = wallet_members_repository::save(db, found_wallet_member).await?;
+
+    let mut found_wallet_members = with_users(db, vec![updated_wallet_member]).await?;
+    found_wallet_members
+        .pop()
+        .ok_or_else(|| AppError::NotFound(String::from("User not found")))
+}
+
+// owners remove anyone but themselves, the other members can only leave
+pub async fn delete_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    wallet_id: i32,
+    wallet_member_id: i32,
+) -> Result<(), AppError> {
+    let found_wallet =
+        wallets_service::get_by_id(db, user, wallet_id, WalletMemberRole::Viewer).await?;
+    let found_wallet_member = get_by_id(db, &found_wallet, wallet_member_id).await?;
+    if found_wallet_member.role == WalletMemberRole::Owner {
+        return Err(AppError::Conflict(String::from(
+            "The wallet owner cannot leave the wallet, delete the wallet instead",
+        )));
+    }
+    if found_wallet.user_id != user.id && found_wallet_member.user_id != user.id {
+        return Err(AppError::Forbidden(String::from(
+            "Only the wallet owner can remove other members",
+        )));
+    }
+
+    wallet_members_repository::delete(db, found_wallet_member).await?;
+    Ok(())
+}
+
+pub async fn find_all_invitations(
+    db: &DatabaseConnection,
+    user: &users::Model,
+) -> Result<Vec<(wallet_members::Model, wallets::Model)>, AppError> {
+    let found_wallet_members =
+        wallet_members_repository::find_all_pending_by_user_id_order_by_created_at_desc(
+            db, user.id,
+        )
+        .await?;
+    let mut found_wallets: HashMap<i32, wallets::Model> =
+        wallets_repository::find_all_active_by_ids(
+            db,
+            found_wallet_members
+                .iter()
+                .map(|wallet_member| wallet_member.wallet_id)
+                .collect(),
+        )
+        .await?
+        .into_iter()
+        .map(|wallet| (wallet.id, wallet))
+        .collect();
+
+    // invitations to deleted wallets stay hidden until the wallet is restored
+    let found_invitations = found_wallet_members
+        .into_iter()
+        .filter_map(|wallet_member| {
+            let found_wallet = found_wallets.remove(&wallet_member.wallet_id)?;
+            Some((wallet_member, found_wallet))
+        })
+        .collect();
+    Ok(found_invitations)
+}
+
+pub async fn accept_invitation_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    wallet_member_id: i32,
+) -> Result<(wallet_members::Model, users::Model), AppError> {
+    let found_wallet_member = get_invitation_by_id(db, user, wallet_member_id).await?;
+
+    let now = OffsetDateTime::now_utc();
+    let mut found_wallet_member = found_wallet_member.into_active_model();
+    found_wallet_member.accepted_at = ActiveValue::Set(Some(now));
+    found_wallet_member.updated_at = ActiveValue::Set(now);
+    let accepted_wallet_member = wallet_members_repository::save(db, found_wallet_member).await?;
+    Ok((accepted_wallet_member, user.clone()))
+}
+
+pub async fn decline_invitation_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    wallet_member_id: i32,
+) -> Result<(), AppError> {
+    let found_wallet_member = get_invitation_by_id(db, user, wallet_member_id).await?;
+    wallet_members_repository::delete(db, found_wallet_member).await?;
+    Ok(())
+}
+
+fn parse_role(role: &str) -> Result<WalletMemberRole, AppError> {
+    match WalletMemberRole::try_from_value(&String::from(role)) {
+        Ok(WalletMemberRole::Owner) | Err(_) => Err(AppError::ParseBody(String::from(
+            "role must be EDITOR or VIEWER",
+        ))),
+        Ok(role) => Ok(role),
+    }
+}
+
+async fn get_by_id(
+    connection: &impl ConnectionTrait,
+    wallet: &wallets::Model,
+    wallet_member_id: i32,
+) -> Result<wallet_members::Model, AppError> {
+    let Some(found_wallet_member) =
+        wallet_members_repository::get_by_id_and_wallet_id(connection, wallet_member_id, wallet.id)
+            .await?
+    else {
+        return Err(AppError::NotFound(String::from("Wallet member not found")));
+    };
+    Ok(found_wallet_member)
+}
+
+async fn get_invitation_by_id(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    wallet_member_id: i32,
+) -> Result<wallet_members::Model, AppError> {
+    let found_wallet_member = wallet_members_repository::get_pending_by_id_and_user_id(
+        connection,
+        wallet_member_id,
+        user.id,
+    )
+    .await?;
+    let Some(found_wallet_member) = found_wallet_member else {
+        return Err(AppError::NotFound(String::from("Invitation not found")));
+    };
+    if wallets_repository::get_active_by_id(connection, found_wallet_member.wallet_id)
+        .await?
+        .is_none()
+    {
+        return Err(AppError::NotFound(String::from("Invitation not found")));
+    }
+    Ok(found_wallet_member)
+}
+
+async fn with_users(
+    connection: &impl ConnectionTrait,
+    wallet_members: Vec<wallet_members::Model>,
+) -> Result<Vec<(wallet_members::Model, users::Model)>, AppError> {
+    let found_users: HashMap<i32, users::Model> = users_repository::find_all_by_ids(
+        connection,
+        wallet_members
+            .iter()
+            .map(|wallet_member| wallet_member.user_id)
+            .collect(),
+    )
+    .await?
+    .into_iter()
+    .map(|user| (user.id, user))
+    .collect();
+
+    let found_wallet_members = wallet_members
+        .into_iter()
+        .filter_map(|wallet_member| {
+            let found_user = found_users.get(&wallet_member.user_id)?.clone();
+            Some((wallet_member, found_user))
+        })
+        .collect();
+    Ok(found_wallet_members)
+}
@@ -1,25 +1,87 @@
+use crate::constants::currency_constants::DEFAULT_CURRENCY;
 use crate::dto::request::wallets_dto::{
     CreateWalletRequest, FindAllWalletsParams, UpdateWalletRequest,
 };
-use crate::entities::sea_orm_active_enums::TransactionType;
-use crate::entities::{transactions, users, wallets};
+use crate::entities::sea_orm_active_enums::{TransactionType, WalletMemberRole, WalletType};
+use crate::entities::{transactions, users, wallet_members, wallets};
 use crate::errors::AppError;
-use crate::repositories::wallets_repository;
-use sea_orm::{ActiveValue, ConnectionTrait, DatabaseConnection, IntoActiveModel};
-use time::OffsetDateTime;
+use crate::repositories::{
+    transactions_repository, users_repository, wallet_members_repository, wallets_repository,
+};
+use crate::services::wallet_balance_snapshots_service;
+use crate::utils::{currency_utils, wallet_utils};
+use rust_decimal::Decimal;
+use sea_orm::{
+    ActiveEnum, ActiveValue, ConnectionTrait, DatabaseConnection, IntoActiveModel, TransactionTrait,
+};
+use std::collections::HashMap;
+use std::str::FromStr;
+use time::{Date, OffsetDateTime};
 
 pub async fn create(
     db: &DatabaseConnection,
     user: &users::Model,
     payload: CreateWalletRequest,
 ) -> Result<wallets::Model, AppError> {
-    let new_wallet = wallets::ActiveModel {
+    let currency = payload
+        .currency
+        .unwrap_or_else(|| String::from(DEFAULT_CURRENCY));
+    let wallet_type = match payload.wallet_type {
+        Some(wallet_type) => parse_wallet_type(&wallet_type)?,
+        None => WalletType::Cash,
+    };
+    let opening_balance = match payload.opening_balance {
+        Some(ref opening_balance) => currency_utils::parse_amount(opening_balance, &currency)?,
+        None => Decimal::ZERO,
+    };
+    let today = OffsetDateTime::now_utc().date();
+    let opening_date = payload.opening_date.unwrap_or(today);
+    if opening_date > today {
+        return Err(AppError::ParseBody(String::from(
+            "opening_date cannot be in the future",
+        )));
+    }
+
+    let mut new_wallet = wallets::ActiveModel {
         user_id: ActiveValue::Set(user.id),
         name: ActiveValue::Set(payload.name),
         description: ActiveValue::Set(payload.description),
+        balance: ActiveValue::Set(opening_balance),
+        opening_balance: ActiveValue::Set(opening_balance),
+        opening_date: ActiveValue::Set(Some(opening_date)),
+        exclude_from_totals: ActiveValue::Set(payload.exclude_from_totals.unwrap_or(false)),
+        ..Default::default()
+    };
+    apply_wallet_details(
+        &mut new_wallet,
+        wallet_type,
+        &currency,
+        payload.credit_limit,
+        payload.statement_closing_day,
+        payload.payment_due_day,
+        payload.interest_rate,
+    )?;
+    new_wallet.currency = ActiveValue::Set(currency);
+
+    let txn = db.begin().await?;
+    let new_wallet = wallets_repository::save(&txn, new_wallet).await?;
+    let new_wallet_member = wallet_members::ActiveModel {
+        wallet_id: ActiveValue::Set(new_wallet.id),
+        user_id: ActiveValue::Set(user.id),
+        role: ActiveValue::Set(WalletMemberRole::Owner),
+        accepted_at: ActiveValue::Set(Some(new_wallet.created_at)),
         ..Default::default()
     };
-    let new_wallet = wallets_repository::save(db, new_wallet).await?;
+    wallet_members_repository::save(&txn, new_wallet_member).await?;
+    wallet_balance_snapshots_service::apply_change(
+        &txn,
+        new_wallet.id,
+        opening_date,
+        opening_balance,
+    )
+    .await?;
+    txn.commit().await?;
+
     Ok(new_wallet)
 }
 
@@ -34,28 +96,203 @@ pub async fn find_all(
     Ok(found_wallets)
 }
 
+// members of a shared wallet reach it with the role they were invited with,
+// everybody else does not get to know it exists
 pub async fn get_by_id(
     connection: &impl ConnectionTrait,
     user: &users::Model,
     wallet_id: i32,
+    required_role: WalletMemberRole,
 ) -> Result<wallets::Model, AppError> {
-    let Some(found_wallet) =
-        wallets_repository::find_active_by_id_and_user_id(connection, wallet_id, user.id).await?
+    let Some(found_wallet) = wallets_repository::get_active_by_id(connection, wallet_id).await?
     else {
         return Err(AppError::NotFound(String::from("Wallet not found")));
     };
+    if found_wallet.user_id == user.id {
+        return Ok(found_wallet);
+    }
+
+    let found_wallet_member =
+        wallet_members_repository::get_by_wallet_id_and_user_id(connection, wallet_id, user.id)
+            .await?;
+    let Some(found_wallet_member) =
+        found_wallet_member.filter(|wallet_member| wallet_member.accepted_at.is_some())
+    else {
+        return Err(AppError::NotFound(String::from("Wallet not found")));
+    };
+    if !wallet_utils::has_role(&found_wallet_member.role, &required_role) {
+        return Err(AppError::Forbidden(format!(
+            "Wallet {} needs the {} role for this",
+            found_wallet.name,
+            required_role.to_value()
+        )));
+    }
     Ok(found_wallet)
 }
 
+// transactions of a shared wallet belong to the ledger of its owner
+pub async fn find_owner(
+    connection: &impl ConnectionTrait,
+    user: &users::Model,
+    wallet: &wallets::Model,
+) -> Result<users::Model, AppError> {
+    if wallet.user_id == user.id {
+        return Ok(user.clone());
+    }
+    let Some(found_owner) = users_repository::get_active_by_id(connection, wallet.user_id).await?
+    else {
+        return Err(AppError::NotFound(String::from("Wallet owner not found")));
+    };
+    Ok(found_owner)
+}
+
+pub fn ensure_currency(wallet: &wallets::Model, currency: &str) -> Result<(), AppError> {
+    if wallet.currency != currency {
+        return Err(AppError::Conflict(format!(
+            "Currency {currency} conflicts with the {} currency of the wallet",
+            wallet.currency
+        )));
+    }
+    Ok(())
+}
+
+// archived wallets keep their history but take no new transactions
+pub fn ensure_not_archived(wallet: &wallets::Model) -> Result<(), AppError> {
+    if wallet.archived_at.is_some() {
+        return Err(AppError::Conflict(format!(
+            "Wallet {} is archived, unarchive it first",
+            wallet.name
+        )));
+    }
+    Ok(())
+}
+
+fn parse_wallet_type(wallet_type: &str) -> Result<WalletType, AppError> {
+    WalletType::try_from_value(&String::from(wallet_type))
+        .map_err(|_| AppError::ParseBody(String::from("invalid wallet_type")))
+}
+
+// details that do not belong to the wallet type are rejected instead of being silently dropped
+fn apply_wallet_details(
+    wallet: &mut wallets::ActiveModel,
+    wallet_type: WalletType,
+    currency: &str,
+    credit_limit: Option<String>,
+    statement_closing_day: Option<i16>,
+    payment_due_day: Option<i16>,
+    interest_rate: Option<String>,
+) -> Result<(), AppError> {
+    if wallet_type == WalletType::CreditCard {
+        if statement_closing_day.is_none() || payment_due_day.is_none() {
+            return Err(AppError::ParseBody(String::from(
+                "credit cards require statement_closing_day and payment_due_day",
+            )));
+        }
+    } else if credit_limit.is_some() || statement_closing_day.is_some() || payment_due_day.is_some()
+    {
+        return Err(AppError::ParseBody(String::from(
+            "credit_limit, statement_closing_day and payment_due_day are only for credit cards",
+        )));
+    }
+    let credit_limit = match credit_limit {
+        Some(credit_limit) => {
+            let credit_limit = currency_utils::parse_amount(&credit_limit, currency)?;
+            if credit_limit.is_sign_negative() {
+                return Err(AppError::ParseBody(String::from(
+                    "credit_limit cannot be negative",
+                )));
+            }
+            Some(credit_limit)
+        }
+        None => None,
+    };
+
+    if !matches!(wallet_type, WalletType::Savings | WalletType::Loan) && interest_rate.is_some() {
+        return Err(AppError::ParseBody(String::from(
+            "interest_rate is only for savings and loans",
+        )));
+    }
+    let interest_rate = match interest_rate {
+        Some(interest_rate) => {
+            let interest_rate = Decimal::from_str(&interest_rate)?;
+            if interest_rate < Decimal::ZERO
+                || interest_rate > Decimal::ONE_HUNDRED
+                || interest_rate.normalize().scale() > 4
+            {
+                return Err(AppError::ParseBody(String::from(
+                    "interest_rate must be between 0 and 100 with at most 4 decimal places",
+                )));
+            }
+            Some(interest_rate)
+        }
+        None => None,
+    };
+
+    wallet.wallet_type = ActiveValue::Set(wallet_type);
+    wallet.credit_limit = ActiveValue::Set(credit_limit);
+    wallet.statement_closing_day = ActiveValue::Set(statement_closing_day);
+    wallet.payment_due_day = ActiveValue::Set(payment_due_day);
+    wallet.interest_rate = ActiveValue::Set(interest_rate);
+    Ok(())
+}
+
+// an update only sends what changes, the stored details are kept as long as they still fit the
+// wallet type and dropped when the type no longer has them
+fn fill_missing_wallet_details(
+    wallet: &wallets::Model,
+    wallet_type: &WalletType,
+    mut payload: UpdateWalletRequest,
+) -> UpdateWalletRequest {
+    if *wallet_type == WalletType::CreditCard {
+        payload.credit_limit = payload.credit_limit.or_else(|| {
+            wallet
+                .credit_limit
+                .map(|credit_limit| credit_limit.to_string())
+        });
+        payload.statement_closing_day = payload
+            .statement_closing_day
+            .or(wallet.statement_closing_day);
+        payload.payment_due_day = payload.payment_due_day.or(wallet.payment_due_day);
+    }
+    if matches!(wallet_type, WalletType::Savings | WalletType::Loan) {
+        payload.interest_rate = payload.interest_rate.or_else(|| {
+            wallet
+                .interest_rate
+                .map(|interest_rate| interest_rate.to_string())
+        });
+    }
+    payload
+}
+
 pub async fn update_by_id(
     db: &DatabaseConnection,
     user: &users::Model,
     wallet_id: i32,
     payload: UpdateWalletRequest,
 ) -> Result<wallets::Model, AppError> {
-    let mut found_wallet = get_by_id(db, user, wallet_id).await?.into_active_model();
+    let found_wallet = get_by_id(db, user, wallet_id, WalletMemberRole::Owner).await?;
+    let wallet_type = match payload.wallet_type.as_deref() {
+        Some(wallet_type) => parse_wallet_type(wallet_type)?,
+        None => found_wallet.wallet_type.clone(),
+    };
+    let currency = found_wallet.currency.clone();
+    let payload = fill_missing_wallet_details(&found_wallet, &wallet_type, payload);
+
+    let mut found_wallet = found_wallet.into_active_model();
     found_wallet.name = ActiveValue::Set(payload.name);
     found_wallet.description = ActiveValue::Set(payload.description);
+    if let Some(exclude_from_totals) = payload.exclude_from_totals {
+        found_wallet.exclude_from_totals = ActiveValue::Set(exclude_from_totals);
+    }
+    apply_wallet_details(
+        &mut found_wallet,
+        wallet_type,
+        &currency,
+        payload.credit_limit,
+        payload.statement_closing_day,
+        payload.payment_due_day,
+        payload.interest_rate,
+    )?;
     found_wallet.updated_at = ActiveValue::Set(OffsetDateTime::now_utc());
 
     let updated_wallet = wallets_repository::save(db, found_wallet).await?;
@@ -67,32 +304,170 @@ pub async fn delete_by_id(
     user: &users::Model,
     wallet_id: i32,
 ) -> Result<(), AppError> {
-    let mut found_wallet = get_by_id(db, user, wallet_id).await?.into_active_model();
+    let mut found_wallet = get_by_id(db, user, wallet_id, WalletMemberRole::Owner)
+        .await?
+        .into_active_model();
     found_wallet.deleted_at = ActiveValue::Set(Some(OffsetDateTime::now_utc()));
     wallets_repository::save(db, found_wallet).await?;
     Ok(())
 }
 
+pub async fn archive_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    wallet_id: i32,
+) -> Result<wallets::Model, AppError> {
+    let found_wallet = get_by_id(db, user, wallet_id, WalletMemberRole::Owner).await?;
+    if found_wallet.archived_at.is_some() {
+        return Ok(found_wallet);
+    }
+
+    let now = OffsetDateTime::now_utc();
+    let mut found_wallet = found_wallet.into_active_model();
+    found_wallet.archived_at = ActiveValue::Set(Some(now));
+    found_wallet.updated_at = ActiveValue::Set(now);
+
+    let archived_wallet = wallets_repository::save(db, found_wallet).await?;
+    Ok(archived_wallet)
+}
+
+pub async fn unarchive_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    wallet_id: i32,
+) -> Result<wallets::Model, AppError> {
+    let found_wallet = get_by_id(db, user, wallet_id, WalletMemberRole::Owner).await?;
+    if found_wallet.archived_at.is_none() {
+        return Ok(found_wallet);
+    }
+
+    let mut found_wallet = found_wallet.into_active_model();
+    found_wallet.archived_at = ActiveValue::Set(None);
+    found_wallet.updated_at = ActiveValue::Set(OffsetDateTime::now_utc());
+
+    let unarchived_wallet = wallets_repository::save(db, found_wallet).await?;
+    Ok(unarchived_wallet)
+}
+
+pub async fn find_all_deleted(
+    db: &DatabaseConnection,
+    user: &users::Model,
+) -> Result<Vec<wallets::Model>, AppError> {
+    let found_wallets =
+        wallets_repository::find_all_deleted_by_user_id_order_by_deleted_at_desc(db, user.id)
+            .await?;
+    Ok(found_wallets)
+}
+
+pub async fn restore_by_id(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    wallet_id: i32,
+) -> Result<wallets::Model, AppError> {
+    let found_wallet =
+        wallets_repository::get_deleted_by_id_and_user_id(db, wallet_id, user.id).await?;
+    let Some(found_wallet) = found_wallet else {
+        return Err(AppError::NotFound(String::from(
+            "Wallet not found in trash",
+        )));
+    };
+
+    let mut found_wallet = found_wallet.into_active_model();
+    found_wallet.deleted_at = ActiveValue::Set(None);
+    found_wallet.updated_at = ActiveValue::Set(OffsetDateTime::now_utc());
+
+    let restored_wallet = wallets_repository::save(db, found_wallet).await?;
+    Ok(restored_wallet)
+}
+
+// the opening balance only exists from the day it was counted
+pub fn find_opening_balance_as_of(wallet: &wallets::Model, date: Date) -> Decimal {
+    match wallet.opening_date {
+        Some(opening_date) if opening_date > date => Decimal::ZERO,
+        _ => wallet.opening_balance,
+    }
+}
+
+// scheduled transactions count too when the date lies in the future
+pub async fn get_balance_as_of(
+    db: &DatabaseConnection,
+    user: &users::Model,
+    wallet_id: i32,
+    as_of: Date,
+) -> Result<(wallets::Model, Decimal), AppError> {
+    let found_wallet = get_by_id(db, user, wallet_id, WalletMemberRole::Viewer).await?;
+    let found_amount_sums =
+        transactions_repository::find_amount_sums_by_wallet_id_and_issued_at_lte(
+            db,
+            found_wallet.id,
+            as_of,
+        )
+        .await?;
+
+    let balance = find_opening_balance_as_of(&found_wallet, as_of)
+        + found_amount_sums
+            .into_iter()
+            .map(|(flow_direction, total_amount)| match flow_direction {
+                TransactionType::Income => total_amount,
+                TransactionType::Outcome => -total_amount,
+            })
+            .sum::<Decimal>();
+    Ok((found_wallet, balance))
+}
+
+// the balance once every scheduled transaction of the wallet has been posted
+pub async fn find_projected_balances(
+    connection: &impl ConnectionTrait,
+    wallets: &[wallets::Model],
+) -> Result<HashMap<i32, Decimal>, AppError> {
+    let mut projected_balances: HashMap<i32, Decimal> = wallets
+        .iter()
+        .map(|wallet| (wallet.id, wallet.balance))
+        .collect();
+
+    let found_amount_sums = transactions_repository::find_unposted_amount_sums_by_wallet_ids(
+        connection,
+        wallets.iter().map(|wallet| wallet.id).collect(),
+    )
+    .await?;
+    for (wallet_id, flow_direction, total_amount) in found_amount_sums {
+        if let Some(projected_balance) = projected_balances.get_mut(&wallet_id) {
+            match flow_direction {
+                TransactionType::Income => *projected_balance += total_amount,
+                TransactionType::Outcome => *projected_balance -= total_amount,
+            }
+        }
+    }
+    Ok(projected_balances)
+}
+
 pub async fn update_balance_after_transaction(
     connection: &impl ConnectionTrait,
     wallet: wallets::Model,
     transaction: &transactions::Model,
 ) -> Result<wallets::Model, AppError> {
+    if transaction.posted_at.is_none() {
+        return Ok(wallet);
+    }
     let wallet_balance = wallet.balance;
 
     let mut wallet = wallet.into_active_model();
     wallet.updated_at = ActiveValue::Set(OffsetDateTime::now_utc());
 
-    match transaction.flow_direction {
-        TransactionType::Income => {
-            wallet.balance = ActiveValue::Set(wallet_balance + transaction.amount);
-        }
-        TransactionType::Outcome => {
-            wallet.balance = ActiveValue::Set(wallet_balance - transaction.amount);
-        }
-    }
+    let amount = match transaction.flow_direction {
+        TransactionType::Income => transaction.amount,
+        TransactionType::Outcome => -transaction.amount,
+    };
+    wallet.balance = ActiveValue::Set(wallet_balance + amount);
 
     let updated_wallet = wallets_repository::save(connection, wallet).await?;
+    wallet_balance_snapshots_service::apply_change(
+        connection,
+        updated_wallet.id,
+        transaction.issued_at,
+        amount,
+    )
+    .await?;
     Ok(updated_wallet)
 }
 
@@ -101,20 +476,159 @@ pub async fn revert_transaction(
     user: &users::Model,
     transaction: &transactions::Model,
 ) -> Result<wallets::Model, AppError> {
-    let found_wallet = get_by_id(connection, user, transaction.wallet_id).await?;
+    let found_wallet = get_by_id(
+        connection,
+        user,
+        transaction.wallet_id,
+        WalletMemberRole::Owner,
+    )
+    .await?;
+    if transaction.posted_at.is_none() {
+        return Ok(found_wallet);
+    }
     let wallet_balance = found_wallet.balance;
 
     let mut found_wallet = found_wallet.into_active_model();
     found_wallet.updated_at = ActiveValue::Set(OffsetDateTime::now_utc());
-    match transaction.flow_direction {
-        TransactionType::Income => {
-            found_wallet.balance = ActiveValue::Set(wallet_balance - transaction.amount);
+    let amount = match transaction.flow_direction {
+        TransactionType::Income => -transaction.amount,
+        TransactionType::Outcome => transaction.amount,
+    };
+    found_wallet.balance = ActiveValue::Set(wallet_balance + amount);
+
+    let updated_wallet = wallets_repository::save(connection, found_wallet).await?;
+    wallet_balance_snapshots_service::apply_change(
+        connection,
+        updated_wallet.id,
+        transaction.issued_at,
+        amount,
+    )
+    .await?;
+    Ok(updated_wallet)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    fn credit_card() -> wallets::Model {
+        wallets::Model {
+            id: 1,
+            user_id: 1,
+            name: String::from("Card"),
+            balance: Decimal::ZERO,
+            currency: String::from("USD"),
+            description: None,
+            created_at: datetime!(2026 - 01 - 01 0:00 UTC),
+            updated_at: datetime!(2026 - 01 - 01 0:00 UTC),
+            deleted_at: None,
+            wallet_type: WalletType::CreditCard,
+            credit_limit: Some(Decimal::new(500000, 2)),
+            statement_closing_day: Some(25),
+            payment_due_day: Some(10),
+            interest_rate: None,
+            opening_balance: Decimal::ZERO,
+            opening_date: None,
+            archived_at: None,
+            exclude_from_totals: false,
         }
-        TransactionType::Outcome => {
-            found_wallet.balance = ActiveValue::Set(wallet_balance + transaction.amount);
+    }
+
+    fn rename(name: &str) -> UpdateWalletRequest {
+        UpdateWalletRequest {
+            name: String::from(name),
+            description: None,
+            exclude_from_totals: None,
+            wallet_type: None,
+            credit_limit: None,
+            statement_closing_day: None,
+            payment_due_day: None,
+            interest_rate: None,
         }
     }
 
-    let updated_wallet = wallets_repository::save(connection, found_wallet).await?;
-    Ok(updated_wallet)
+    fn apply_update(
+        wallet: wallets::Model,
+        wallet_type: WalletType,
+        payload: UpdateWalletRequest,
+    ) -> Result<wallets::ActiveModel, AppError> {
+        let payload = fill_missing_wallet_details(&wallet, &wallet_type, payload);
+        let currency = wallet.currency.clone();
+        let mut wallet = wallet.into_active_model();
+        apply_wallet_details(
+            &mut wallet,
+            wallet_type,
+            &currency,
+            payload.credit_limit,
+            payload.statement_closing_day,
+            payload.payment_due_day,
+            payload.interest_rate,
+        )?;
+        Ok(wallet)
+    }
+
+    #[test]
+    fn partial_update_keeps_the_credit_card_details() {
+        let wallet =
+            apply_update(credit_card(), WalletType::CreditCard, rename("Travel card")).unwrap();
+
+        assert_eq!(
+            wallet.credit_limit,
+            ActiveValue::Set(Some(Decimal::new(500000, 2)))
+        );
+        assert_eq!(wallet.statement_closing_day, ActiveValue::Set(Some(25)));
+        assert_eq!(wallet.payment_due_day, ActiveValue::Set(Some(10)));
+    }
+
+    #[test]
+    fn partial_update_replaces_only_the_sent_details() {
+        let wallet = apply_update(
+            credit_card(),
+            WalletType::CreditCard,
+            UpdateWalletRequest {
+                payment_due_day: Some(15),
+                ..rename("Card")
+            },
+        )
+        .unwrap();
+
+        assert_eq!(wallet.statement_closing_day, ActiveValue::Set(Some(25)));
+        assert_eq!(wallet.payment_due_day, ActiveValue::Set(Some(15)));
+    }
+
+    #[test]
+    fn changing_the_type_drops_details_it_does_not_have() {
+        let wallet = apply_update(credit_card(), WalletType::Cash, rename("Cash")).unwrap();
+
+        assert_eq!(wallet.credit_limit, ActiveValue::Set(None));
+        assert_eq!(wallet.statement_closing_day, ActiveValue::Set(None));
+        assert_eq!(wallet.payment_due_day, ActiveValue::Set(None));
+    }
+
+    #[test]
+    fn sent_details_that_do_not_fit_the_type_are_rejected() {
+        assert!(
+            apply_update(
+                credit_card(),
+                WalletType::CreditCard,
+                UpdateWalletRequest {
+                    interest_rate: Some(String::from("5")),
+                    ..rename("Card")
+                },
+            )
+            .is_err()
+        );
+        assert!(
+            apply_update(
+                credit_card(),
+                WalletType::Cash,
+                UpdateWalletRequest {
+                    payment_due_day: Some(15),
+                    ..rename("Cash")
+                },
+            )
+            .is_err()
+        );
+    }
 }
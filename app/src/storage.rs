@@ -0,0 +1,3 @@
+pub mod local_storage_backend;
+pub mod s3_storage_backend;
+pub mod storage_backend;
@@ -0,0 +1,137 @@
+use crate::errors::AppError;
+use crate::storage::storage_backend::StorageBackend;
+use async_trait::async_trait;
+use std::io::{Error as IoError, ErrorKind, Write};
+use std::path::{Component, Path, PathBuf};
+use tempfile::NamedTempFile;
+use tokio::fs;
+
+pub struct LocalStorageBackend {
+    root: PathBuf,
+}
+
+impl LocalStorageBackend {
+    pub fn new(root: impl Into<PathBuf>) -> LocalStorageBackend {
+        LocalStorageBackend { root: root.into() }
+    }
+
+    fn resolve(&self, key: &str) -> Result<PathBuf, AppError> {
+        let key = Path::new(key);
+        if key.as_os_str().is_empty()
+            || !key
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)))
+        {
+            return Err(AppError::Storage(String::from("invalid storage key")));
+        }
+        Ok(self.root.join(key))
+    }
+}
+
+#[async_trait]
+impl StorageBackend for LocalStorageBackend {
+    async fn put(&self, key: &str, content: &[u8], _content_type: &str) -> Result<(), AppError> {
+        let path = self.resolve(key)?;
+        let parent = path.parent().unwrap_or(&self.root).to_path_buf();
+        fs::create_dir_all(&parent).await?;
+
+        // write to a uniquely named sibling file first so readers never observe a partial upload
+        // and concurrent uploads of the same key never share a temporary file
+        let content = content.to_vec();
+        tokio::task::spawn_blocking(move || -> Result<(), AppError> {
+            let mut temp_file = NamedTempFile::new_in(parent)?;
+            temp_file.write_all(&content)?;
+            temp_file.persist(path).map_err(|err| err.error)?;
+            Ok(())
+        })
+        .await
+        .map_err(IoError::other)??;
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>, AppError> {
+        match fs::read(self.resolve(key)?).await {
+            Ok(content) => Ok(content),
+            Err(err) if err.kind() == ErrorKind::NotFound => Err(AppError::NotFound(String::from(
+                "Attachment file not found",
+            ))),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), AppError> {
+        match fs::remove_file(self.resolve(key)?).await {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn put_then_get_returns_the_same_content() {
+        let root = tempfile::tempdir().unwrap();
+        let storage = LocalStorageBackend::new(root.path());
+
+        storage
+            .put("1/receipt.pdf", b"first", "application/pdf")
+            .await
+            .unwrap();
+        assert_eq!(storage.get("1/receipt.pdf").await.unwrap(), b"first");
+
+        storage
+            .put("1/receipt.pdf", b"second", "application/pdf")
+            .await
+            .unwrap();
+        assert_eq!(storage.get("1/receipt.pdf").await.unwrap(), b"second");
+        assert_eq!(
+            std::fs::read_dir(root.path().join("1")).unwrap().count(),
+            1,
+            "no temporary files should be left behind"
+        );
+    }
+
+    #[tokio::test]
+    async fn delete_removes_the_file_and_ignores_missing_ones() {
+        let root = tempfile::tempdir().unwrap();
+        let storage = LocalStorageBackend::new(root.path());
+
+        storage
+            .put("1/receipt.pdf", b"content", "application/pdf")
+            .await
+            .unwrap();
+        storage.delete("1/receipt.pdf").await.unwrap();
+
+        assert!(matches!(
+            storage.get("1/receipt.pdf").await,
+            Err(AppError::NotFound(_))
+        ));
+        storage.delete("1/receipt.pdf").await.unwrap();
+    }
+
+    #[test]
+    fn resolve_rejects_keys_outside_of_the_root() {
+        let storage = LocalStorageBackend::new("/var/lib/old-money");
+
+        for key in [
+            "",
+            "../secret",
+            "1/../../secret",
+            "/etc/passwd",
+            "./1/receipt.pdf",
+        ] {
+            assert!(
+                matches!(storage.resolve(key), Err(AppError::Storage(_))),
+                "{key} should be rejected"
+            );
+        }
+        assert_eq!(
+            storage.resolve("1/receipt.pdf").unwrap(),
+            PathBuf::from("/var/lib/old-money/1/receipt.pdf")
+        );
+    }
+}
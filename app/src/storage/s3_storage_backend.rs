@@ -0,0 +1,98 @@
+use crate::errors::AppError;
+use crate::storage::storage_backend::StorageBackend;
+use async_trait::async_trait;
+use s3::creds::Credentials;
+use s3::error::S3Error;
+use s3::{Bucket, Region};
+
+pub struct S3StorageBackend {
+    bucket: Box<Bucket>,
+}
+
+impl S3StorageBackend {
+    pub fn new(
+        bucket: &str,
+        region: String,
+        endpoint: String,
+        access_key: &str,
+        secret_key: &str,
+    ) -> Result<S3StorageBackend, AppError> {
+        let credentials = Credentials::new(Some(access_key), Some(secret_key), None, None, None)
+            .map_err(S3Error::from)?;
+        // path-style addressing keeps MinIO and other S3-compatible servers working
+        // without wildcard DNS for bucket subdomains
+        let bucket = Bucket::new(bucket, Region::Custom { region, endpoint }, credentials)?
+            .with_path_style();
+        Ok(S3StorageBackend { bucket })
+    }
+}
+
+#[async_trait]
+impl StorageBackend for S3StorageBackend {
+    async fn put(&self, key: &str, content: &[u8], content_type: &str) -> Result<(), AppError> {
+        self.bucket
+            .put_object_with_content_type(key, content, content_type)
+            .await?;
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>, AppError> {
+        match self.bucket.get_object(key).await {
+            Ok(response) => Ok(response.to_vec()),
+            Err(S3Error::HttpFailWithBody(404, _)) => Err(AppError::NotFound(String::from(
+                "Attachment file not found",
+            ))),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), AppError> {
+        match self.bucket.delete_object(key).await {
+            Ok(_) | Err(S3Error::HttpFailWithBody(404, _)) => Ok(()),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use s3::BucketConfiguration;
+
+    // needs a live S3-compatible server, so it only runs when asked for, e.g. against MinIO:
+    // docker container run -d -p 9000:9000 minio/minio server /data
+    // S3_TEST_ENDPOINT=http://localhost:9000 cargo test -- --ignored
+    #[tokio::test]
+    #[ignore]
+    async fn put_get_and_delete_against_s3_compatible_server() {
+        let env_or = |name: &str, default: &str| std::env::var(name).unwrap_or(default.into());
+        let endpoint = env_or("S3_TEST_ENDPOINT", "http://localhost:9000");
+        let bucket = env_or("S3_TEST_BUCKET", "old-money-test");
+        let region = env_or("S3_TEST_REGION", "us-east-1");
+        let access_key = env_or("S3_TEST_ACCESS_KEY", "minioadmin");
+        let secret_key = env_or("S3_TEST_SECRET_KEY", "minioadmin");
+        let storage =
+            S3StorageBackend::new(&bucket, region, endpoint, &access_key, &secret_key).unwrap();
+        if !storage.bucket.exists().await.unwrap() {
+            Bucket::create_with_path_style(
+                &bucket,
+                storage.bucket.region(),
+                storage.bucket.credentials().await.unwrap(),
+                BucketConfiguration::default(),
+            )
+            .await
+            .unwrap();
+        }
+        let key = format!("tests/{}.txt", uuid::Uuid::now_v7());
+
+        storage.put(&key, b"content", "text/plain").await.unwrap();
+        assert_eq!(storage.get(&key).await.unwrap(), b"content");
+
+        storage.delete(&key).await.unwrap();
+        assert!(matches!(
+            storage.get(&key).await,
+            Err(AppError::NotFound(_))
+        ));
+        storage.delete(&key).await.unwrap();
+    }
+}
@@ -0,0 +1,45 @@
+use crate::constants::environment_constants::{
+    S3_ACCESS_KEY, S3_BUCKET, S3_ENDPOINT, S3_REGION, S3_SECRET_KEY, STORAGE_BACKEND,
+    STORAGE_LOCAL_PATH,
+};
+use crate::errors::AppError;
+use crate::storage::local_storage_backend::LocalStorageBackend;
+use crate::storage::s3_storage_backend::S3StorageBackend;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait StorageBackend: Send + Sync {
+    async fn put(&self, key: &str, content: &[u8], content_type: &str) -> Result<(), AppError>;
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>, AppError>;
+
+    async fn delete(&self, key: &str) -> Result<(), AppError>;
+}
+
+pub fn from_env() -> Result<Arc<dyn StorageBackend>, AppError> {
+    let storage_backend = std::env::var(STORAGE_BACKEND).unwrap_or(String::from("local"));
+    match storage_backend.to_lowercase().as_str() {
+        "local" => {
+            let root = std::env::var(STORAGE_LOCAL_PATH).unwrap_or(String::from("./storage"));
+            Ok(Arc::new(LocalStorageBackend::new(root)))
+        }
+        "s3" => {
+            let bucket = std::env::var(S3_BUCKET)?;
+            let region = std::env::var(S3_REGION)?;
+            let endpoint = std::env::var(S3_ENDPOINT)?;
+            let access_key = std::env::var(S3_ACCESS_KEY)?;
+            let secret_key = std::env::var(S3_SECRET_KEY)?;
+            Ok(Arc::new(S3StorageBackend::new(
+                &bucket,
+                region,
+                endpoint,
+                &access_key,
+                &secret_key,
+            )?))
+        }
+        _ => Err(AppError::Storage(String::from(
+            "STORAGE_BACKEND must be one of local or s3",
+        ))),
+    }
+}
@@ -1,2 +1,5 @@
+pub mod currency_utils;
+pub mod date_utils;
 pub mod jwt_utils;
 pub mod validation;
+pub mod wallet_utils;
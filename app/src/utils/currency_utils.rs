@@ -0,0 +1,67 @@
+use crate::constants::currency_constants::ISO_4217_CURRENCIES;
+use crate::errors::AppError;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+pub fn find_minor_units(currency: &str) -> Option<u32> {
+    ISO_4217_CURRENCIES
+        .binary_search_by_key(&currency, |(code, _)| code)
+        .ok()
+        .map(|index| ISO_4217_CURRENCIES[index].1)
+}
+
+// rejects amounts more precise than the currency can hold instead of silently rounding them
+pub fn parse_amount(amount: &str, currency: &str) -> Result<Decimal, AppError> {
+    let Some(minor_units) = find_minor_units(currency) else {
+        return Err(AppError::ParseBody(format!("unknown currency {currency}")));
+    };
+    let amount = Decimal::from_str(amount)?;
+    if amount.normalize().scale() > minor_units {
+        return Err(AppError::ParseBody(format!(
+            "amount cannot have more than {minor_units} decimal places in {currency}"
+        )));
+    }
+    Ok(amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_amount_accepts_the_scale_of_the_currency() {
+        assert_eq!(parse_amount("10.25", "USD").unwrap(), Decimal::new(1025, 2));
+        assert_eq!(parse_amount("1.125", "KWD").unwrap(), Decimal::new(1125, 3));
+        assert_eq!(parse_amount("500", "JPY").unwrap(), Decimal::from(500));
+    }
+
+    #[test]
+    fn parse_amount_ignores_trailing_zeros() {
+        assert_eq!(
+            parse_amount("10.2500", "USD").unwrap(),
+            Decimal::new(1025, 2)
+        );
+        assert_eq!(parse_amount("500.0", "JPY").unwrap(), Decimal::from(500));
+    }
+
+    #[test]
+    fn parse_amount_rejects_more_decimal_places_than_the_currency() {
+        assert!(parse_amount("10.255", "USD").is_err());
+        assert!(parse_amount("500.5", "JPY").is_err());
+    }
+
+    #[test]
+    fn parse_amount_keeps_the_sign_of_negative_amounts() {
+        assert_eq!(
+            parse_amount("-10.25", "USD").unwrap(),
+            Decimal::new(-1025, 2)
+        );
+        assert!(parse_amount("-10.255", "USD").is_err());
+    }
+
+    #[test]
+    fn parse_amount_rejects_unknown_currencies_and_malformed_amounts() {
+        assert!(parse_amount("10", "XYZ").is_err());
+        assert!(parse_amount("ten", "USD").is_err());
+    }
+}
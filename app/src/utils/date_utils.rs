@@ -0,0 +1,83 @@
+use crate::errors::AppError;
+use time::{Date, Month};
+
+// lands on the last day of the month when the original day does not exist in it
+pub fn add_months(date: Date, months: i32) -> Result<Date, AppError> {
+    let total_months = date.year() * 12 + i32::from(u8::from(date.month())) - 1 + months;
+    let year = total_months.div_euclid(12);
+    let month = Month::try_from(u8::try_from(total_months.rem_euclid(12) + 1).unwrap_or(1))?;
+    let day = date.day().min(month.length(year));
+    Ok(Date::from_calendar_date(year, month, day)?)
+}
+
+// same month as the given date, on the last day of it when the day does not exist in it
+pub fn with_day_clamped(date: Date, day: u8) -> Result<Date, AppError> {
+    let day = day.min(date.month().length(date.year()));
+    Ok(Date::from_calendar_date(date.year(), date.month(), day)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::date;
+
+    #[test]
+    fn add_months_clamps_to_the_end_of_shorter_months() {
+        assert_eq!(
+            add_months(date!(2026 - 01 - 31), 1).unwrap(),
+            date!(2026 - 02 - 28)
+        );
+        assert_eq!(
+            add_months(date!(2026 - 03 - 31), 1).unwrap(),
+            date!(2026 - 04 - 30)
+        );
+    }
+
+    #[test]
+    fn add_months_handles_leap_years() {
+        assert_eq!(
+            add_months(date!(2028 - 01 - 31), 1).unwrap(),
+            date!(2028 - 02 - 29)
+        );
+        assert_eq!(
+            add_months(date!(2028 - 02 - 29), 12).unwrap(),
+            date!(2029 - 02 - 28)
+        );
+    }
+
+    #[test]
+    fn add_months_crosses_years_in_both_directions() {
+        assert_eq!(
+            add_months(date!(2026 - 11 - 15), 3).unwrap(),
+            date!(2027 - 02 - 15)
+        );
+        assert_eq!(
+            add_months(date!(2026 - 01 - 15), -1).unwrap(),
+            date!(2025 - 12 - 15)
+        );
+        assert_eq!(
+            add_months(date!(2026 - 03 - 31), -13).unwrap(),
+            date!(2025 - 02 - 28)
+        );
+    }
+
+    #[test]
+    fn with_day_clamped_stays_in_the_same_month() {
+        assert_eq!(
+            with_day_clamped(date!(2026 - 02 - 10), 31).unwrap(),
+            date!(2026 - 02 - 28)
+        );
+        assert_eq!(
+            with_day_clamped(date!(2028 - 02 - 10), 31).unwrap(),
+            date!(2028 - 02 - 29)
+        );
+        assert_eq!(
+            with_day_clamped(date!(2026 - 01 - 10), 31).unwrap(),
+            date!(2026 - 01 - 31)
+        );
+        assert_eq!(
+            with_day_clamped(date!(2026 - 04 - 30), 15).unwrap(),
+            date!(2026 - 04 - 15)
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::utils::currency_utils::find_minor_units;
 use validator::ValidationError;
 
 pub fn validate_password(password: &str) -> Result<(), ValidationError> {
@@ -47,3 +48,24 @@ pub fn validate_password(password: &str) -> Result<(), ValidationError> {
 
     Ok(())
 }
+
+pub fn validate_coordinates(
+    latitude: Option<f64>,
+    longitude: Option<f64>,
+) -> Result<(), ValidationError> {
+    if latitude.is_some() != longitude.is_some() {
+        return Err(ValidationError::new(
+            "latitude and longitude must be given together",
+        ));
+    }
+    Ok(())
+}
+
+pub fn validate_currency(currency: &str) -> Result<(), ValidationError> {
+    if find_minor_units(currency).is_none() {
+        return Err(ValidationError::new(
+            "currency must be an uppercase ISO 4217 code",
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use crate::entities::sea_orm_active_enums::{WalletMemberRole, WalletType};
+
+// liability wallets keep a negative balance while money is owed on them
+pub fn is_liability(wallet_type: &WalletType) -> bool {
+    matches!(wallet_type, WalletType::CreditCard | WalletType::Loan)
+}
+
+// owners can do everything editors can, editors everything viewers can
+pub fn has_role(role: &WalletMemberRole, required_role: &WalletMemberRole) -> bool {
+    let rank = |role: &WalletMemberRole| match role {
+        WalletMemberRole::Viewer => 0,
+        WalletMemberRole::Editor => 1,
+        WalletMemberRole::Owner => 2,
+    };
+    rank(role) >= rank(required_role)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn has_role_grants_every_lower_role() {
+        assert!(has_role(&WalletMemberRole::Owner, &WalletMemberRole::Owner));
+        assert!(has_role(
+            &WalletMemberRole::Owner,
+            &WalletMemberRole::Editor
+        ));
+        assert!(has_role(
+            &WalletMemberRole::Owner,
+            &WalletMemberRole::Viewer
+        ));
+        assert!(has_role(
+            &WalletMemberRole::Editor,
+            &WalletMemberRole::Editor
+        ));
+        assert!(has_role(
+            &WalletMemberRole::Editor,
+            &WalletMemberRole::Viewer
+        ));
+        assert!(has_role(
+            &WalletMemberRole::Viewer,
+            &WalletMemberRole::Viewer
+        ));
+    }
+
+    #[test]
+    fn has_role_denies_every_higher_role() {
+        assert!(!has_role(
+            &WalletMemberRole::Editor,
+            &WalletMemberRole::Owner
+        ));
+        assert!(!has_role(
+            &WalletMemberRole::Viewer,
+            &WalletMemberRole::Owner
+        ));
+        assert!(!has_role(
+            &WalletMemberRole::Viewer,
+            &WalletMemberRole::Editor
+        ));
+    }
+}
@@ -9,6 +9,26 @@ mod m20250726_160856_create_budget_configs_table;
 mod m20250728_130953_create_wallets_table;
 mod m20250731_120654_create_budgets_table;
 mod m20250806_131727_create_transactions_table;
+mod m20261019_090000_create_transaction_rules_table;
+mod m20261019_100000_create_tags_tables;
+mod m20261019_110000_create_transaction_attachments_table;
+mod m20261019_120000_create_payees_tables;
+mod m20261019_130000_create_reconciliations_table;
+mod m20261019_140000_create_transaction_revisions_table;
+mod m20261019_150000_add_search_vector_to_transactions_table;
+mod m20261019_160000_create_installment_plans_table;
+mod m20261019_170000_add_refunded_transaction_id_to_transactions_table;
+mod m20261019_180000_add_posted_at_to_transactions_table;
+mod m20261019_190000_add_location_to_transactions_table;
+mod m20261019_200000_add_currency_to_wallets_and_transactions_table;
+mod m20261019_210000_create_exchange_rates_table;
+mod m20261019_220000_add_wallet_type_to_wallets_table;
+mod m20261019_230000_add_transfer_transaction_id_to_transactions_table;
+mod m20261019_240000_add_opening_balance_to_wallets_table;
+mod m20261019_250000_create_wallet_balance_snapshots_table;
+mod m20261019_260000_add_archived_at_and_exclude_from_totals_to_wallets_table;
+mod m20261019_270000_create_wallet_members_table;
+mod m20261019_280000_add_source_to_transaction_revisions_table;
 
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
@@ -21,6 +41,28 @@ impl MigratorTrait for Migrator {
             Box::new(m20250728_130953_create_wallets_table::Migration),
             Box::new(m20250731_120654_create_budgets_table::Migration),
             Box::new(m20250806_131727_create_transactions_table::Migration),
+            Box::new(m20261019_090000_create_transaction_rules_table::Migration),
+            Box::new(m20261019_100000_create_tags_tables::Migration),
+            Box::new(m20261019_110000_create_transaction_attachments_table::Migration),
+            Box::new(m20261019_120000_create_payees_tables::Migration),
+            Box::new(m20261019_130000_create_reconciliations_table::Migration),
+            Box::new(m20261019_140000_create_transaction_revisions_table::Migration),
+            Box::new(m20261019_150000_add_search_vector_to_transactions_table::Migration),
+            Box::new(m20261019_160000_create_installment_plans_table::Migration),
+            Box::new(m20261019_170000_add_refunded_transaction_id_to_transactions_table::Migration),
+            Box::new(m20261019_180000_add_posted_at_to_transactions_table::Migration),
+            Box::new(m20261019_190000_add_location_to_transactions_table::Migration),
+            Box::new(m20261019_200000_add_currency_to_wallets_and_transactions_table::Migration),
+            Box::new(m20261019_210000_create_exchange_rates_table::Migration),
+            Box::new(m20261019_220000_add_wallet_type_to_wallets_table::Migration),
+            Box::new(m20261019_230000_add_transfer_transaction_id_to_transactions_table::Migration),
+            Box::new(m20261019_240000_add_opening_balance_to_wallets_table::Migration),
+            Box::new(m20261019_250000_create_wallet_balance_snapshots_table::Migration),
+            Box::new(
+                m20261019_260000_add_archived_at_and_exclude_from_totals_to_wallets_table::Migration,
+            ),
+            Box::new(m20261019_270000_create_wallet_members_table::Migration),
+            Box::new(m20261019_280000_add_source_to_transaction_revisions_table::Migration),
         ]
     }
 }
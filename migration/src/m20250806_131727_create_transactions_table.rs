@@ -101,10 +101,10 @@ impl MigrationTrait for Migration {
 }
 
 #[derive(DeriveIden)]
-struct TransactionType;
+pub struct TransactionType;
 
 #[derive(DeriveIden, EnumIter)]
-enum TransactionTypeVariants {
+pub enum TransactionTypeVariants {
     #[sea_orm(iden = "INCOME")]
     Income,
 
@@ -113,7 +113,7 @@ enum TransactionTypeVariants {
 }
 
 #[derive(DeriveIden)]
-enum Transactions {
+pub enum Transactions {
     Table,
     Id,
     UserId,
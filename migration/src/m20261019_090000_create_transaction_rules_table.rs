@@ -0,0 +1,107 @@
+use crate::m20250701_134445_create_m2m_user_roles_tables::Users;
+use crate::m20250712_000001_create_categories_table::Categories;
+use crate::m20250728_130953_create_wallets_table::Wallets;
+use crate::m20250731_120654_create_budgets_table::Budgets;
+use crate::m20250806_131727_create_transactions_table::{TransactionType, TransactionTypeVariants};
+use crate::sea_orm::Iterable;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TransactionRules::Table)
+                    .if_not_exists()
+                    .col(pk_auto(TransactionRules::Id))
+                    .col(integer(TransactionRules::UserId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_transaction-rules_users_user-id")
+                            .from(TransactionRules::Table, TransactionRules::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(string(TransactionRules::Name))
+                    .col(integer(TransactionRules::Priority).default(0))
+                    .col(string_null(TransactionRules::DescriptionContains))
+                    .col(string_null(TransactionRules::DescriptionRegex))
+                    .col(decimal_null(TransactionRules::MinAmount))
+                    .col(decimal_null(TransactionRules::MaxAmount))
+                    .col(integer_null(TransactionRules::WalletId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_transaction-rules_wallets_wallet-id")
+                            .from(TransactionRules::Table, TransactionRules::WalletId)
+                            .to(Wallets::Table, Wallets::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(enumeration_null(
+                        TransactionRules::FlowDirection,
+                        TransactionType,
+                        TransactionTypeVariants::iter(),
+                    ))
+                    .col(integer_null(TransactionRules::CategoryId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_transaction-rules_categories_category-id")
+                            .from(TransactionRules::Table, TransactionRules::CategoryId)
+                            .to(Categories::Table, Categories::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(integer_null(TransactionRules::BudgetId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_transaction-rules_budgets_budget-id")
+                            .from(TransactionRules::Table, TransactionRules::BudgetId)
+                            .to(Budgets::Table, Budgets::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(
+                        timestamp_with_time_zone(TransactionRules::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        timestamp_with_time_zone(TransactionRules::UpdatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(timestamp_with_time_zone_null(TransactionRules::DeletedAt))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TransactionRules::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum TransactionRules {
+    Table,
+    Id,
+    UserId,
+    Name,
+    Priority,
+    DescriptionContains,
+    DescriptionRegex,
+    MinAmount,
+    MaxAmount,
+    WalletId,
+    FlowDirection,
+    CategoryId,
+    BudgetId,
+    CreatedAt,
+    UpdatedAt,
+    DeletedAt,
+}
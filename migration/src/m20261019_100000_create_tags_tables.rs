@@ -0,0 +1,159 @@
+use crate::m20250701_134445_create_m2m_user_roles_tables::Users;
+use crate::m20250806_131727_create_transactions_table::Transactions;
+use crate::m20261019_090000_create_transaction_rules_table::TransactionRules;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // create `Tags` table
+        manager
+            .create_table(
+                Table::create()
+                    .table(Tags::Table)
+                    .if_not_exists()
+                    .col(pk_auto(Tags::Id))
+                    .col(integer(Tags::UserId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_tags_users_user-id")
+                            .from(Tags::Table, Tags::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(string(Tags::Name))
+                    .col(
+                        timestamp_with_time_zone(Tags::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        timestamp_with_time_zone(Tags::UpdatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(timestamp_with_time_zone_null(Tags::DeletedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        // create `TransactionTags` table
+        manager
+            .create_table(
+                Table::create()
+                    .table(TransactionTags::Table)
+                    .if_not_exists()
+                    .col(integer(TransactionTags::TransactionId))
+                    .col(integer(TransactionTags::TagId))
+                    .primary_key(
+                        Index::create()
+                            .name("pk-transaction_tags")
+                            .col(TransactionTags::TransactionId)
+                            .col(TransactionTags::TagId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_transaction-tags_transactions_transaction-id")
+                            .from(TransactionTags::Table, TransactionTags::TransactionId)
+                            .to(Transactions::Table, Transactions::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_transaction-tags_tags_tag-id")
+                            .from(TransactionTags::Table, TransactionTags::TagId)
+                            .to(Tags::Table, Tags::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // create `TransactionRuleTags` table
+        manager
+            .create_table(
+                Table::create()
+                    .table(TransactionRuleTags::Table)
+                    .if_not_exists()
+                    .col(integer(TransactionRuleTags::TransactionRuleId))
+                    .col(integer(TransactionRuleTags::TagId))
+                    .primary_key(
+                        Index::create()
+                            .name("pk-transaction_rule_tags")
+                            .col(TransactionRuleTags::TransactionRuleId)
+                            .col(TransactionRuleTags::TagId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_transaction-rule-tags_transaction-rules_transaction-rule-id")
+                            .from(
+                                TransactionRuleTags::Table,
+                                TransactionRuleTags::TransactionRuleId,
+                            )
+                            .to(TransactionRules::Table, TransactionRules::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_transaction-rule-tags_tags_tag-id")
+                            .from(TransactionRuleTags::Table, TransactionRuleTags::TagId)
+                            .to(Tags::Table, Tags::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // drop `TransactionRuleTags` table
+        manager
+            .drop_table(Table::drop().table(TransactionRuleTags::Table).to_owned())
+            .await?;
+
+        // drop `TransactionTags` table
+        manager
+            .drop_table(Table::drop().table(TransactionTags::Table).to_owned())
+            .await?;
+
+        // drop `Tags` table
+        manager
+            .drop_table(Table::drop().table(Tags::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Tags {
+    Table,
+    Id,
+    UserId,
+    Name,
+    CreatedAt,
+    UpdatedAt,
+    DeletedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum TransactionTags {
+    Table,
+    TransactionId,
+    TagId,
+}
+
+#[derive(DeriveIden)]
+enum TransactionRuleTags {
+    Table,
+    TransactionRuleId,
+    TagId,
+}
@@ -0,0 +1,91 @@
+use crate::m20250701_134445_create_m2m_user_roles_tables::Users;
+use crate::m20250806_131727_create_transactions_table::Transactions;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // create `TransactionAttachments` table
+        manager
+            .create_table(
+                Table::create()
+                    .table(TransactionAttachments::Table)
+                    .if_not_exists()
+                    .col(pk_auto(TransactionAttachments::Id))
+                    .col(integer(TransactionAttachments::UserId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_transaction-attachments_users_user-id")
+                            .from(
+                                TransactionAttachments::Table,
+                                TransactionAttachments::UserId,
+                            )
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(integer(TransactionAttachments::TransactionId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_transaction-attachments_transactions_transaction-id")
+                            .from(
+                                TransactionAttachments::Table,
+                                TransactionAttachments::TransactionId,
+                            )
+                            .to(Transactions::Table, Transactions::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(string(TransactionAttachments::FileName))
+                    .col(string(TransactionAttachments::ContentType))
+                    .col(big_integer(TransactionAttachments::Size))
+                    .col(string_uniq(TransactionAttachments::StorageKey))
+                    .col(
+                        timestamp_with_time_zone(TransactionAttachments::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        timestamp_with_time_zone(TransactionAttachments::UpdatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(timestamp_with_time_zone_null(
+                        TransactionAttachments::DeletedAt,
+                    ))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // drop `TransactionAttachments` table
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(TransactionAttachments::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum TransactionAttachments {
+    Table,
+    Id,
+    UserId,
+    TransactionId,
+    FileName,
+    ContentType,
+    Size,
+    StorageKey,
+    CreatedAt,
+    UpdatedAt,
+    DeletedAt,
+}
@@ -0,0 +1,135 @@
+use crate::m20250701_134445_create_m2m_user_roles_tables::Users;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // create `Payees` table
+        manager
+            .create_table(
+                Table::create()
+                    .table(Payees::Table)
+                    .if_not_exists()
+                    .col(pk_auto(Payees::Id))
+                    .col(integer(Payees::UserId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_payees_users_user-id")
+                            .from(Payees::Table, Payees::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(string(Payees::Name))
+                    .col(
+                        timestamp_with_time_zone(Payees::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        timestamp_with_time_zone(Payees::UpdatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(timestamp_with_time_zone_null(Payees::DeletedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        // create `PayeeAliases` table
+        manager
+            .create_table(
+                Table::create()
+                    .table(PayeeAliases::Table)
+                    .if_not_exists()
+                    .col(pk_auto(PayeeAliases::Id))
+                    .col(integer(PayeeAliases::PayeeId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_payee-aliases_payees_payee-id")
+                            .from(PayeeAliases::Table, PayeeAliases::PayeeId)
+                            .to(Payees::Table, Payees::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(string(PayeeAliases::Pattern))
+                    .col(boolean(PayeeAliases::IsRegex).default(false))
+                    .to_owned(),
+            )
+            .await?;
+
+        // add `payee_id` to `Transactions` table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transactions::Table)
+                    .add_column(integer_null(Transactions::PayeeId))
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_transactions_payees_payee-id")
+                            .from_tbl(Transactions::Table)
+                            .from_col(Transactions::PayeeId)
+                            .to_tbl(Payees::Table)
+                            .to_col(Payees::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // drop `payee_id` from `Transactions` table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transactions::Table)
+                    .drop_foreign_key(Alias::new("fk_transactions_payees_payee-id"))
+                    .drop_column(Transactions::PayeeId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // drop `PayeeAliases` table
+        manager
+            .drop_table(Table::drop().table(PayeeAliases::Table).to_owned())
+            .await?;
+
+        // drop `Payees` table
+        manager
+            .drop_table(Table::drop().table(Payees::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Payees {
+    Table,
+    Id,
+    UserId,
+    Name,
+    CreatedAt,
+    UpdatedAt,
+    DeletedAt,
+}
+
+#[derive(DeriveIden)]
+enum PayeeAliases {
+    Table,
+    Id,
+    PayeeId,
+    Pattern,
+    IsRegex,
+}
+
+#[derive(DeriveIden)]
+enum Transactions {
+    Table,
+    PayeeId,
+}
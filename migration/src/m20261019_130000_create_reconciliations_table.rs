@@ -0,0 +1,156 @@
+use crate::extension::postgres::Type;
+use crate::m20250701_134445_create_m2m_user_roles_tables::Users;
+use crate::m20250728_130953_create_wallets_table::Wallets;
+use crate::sea_orm::{EnumIter, Iterable};
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // create TransactionStatus enum
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(TransactionStatus)
+                    .values(TransactionStatusVariants::iter())
+                    .to_owned(),
+            )
+            .await?;
+
+        // create `Reconciliations` table
+        manager
+            .create_table(
+                Table::create()
+                    .table(Reconciliations::Table)
+                    .if_not_exists()
+                    .col(pk_auto(Reconciliations::Id))
+                    .col(integer(Reconciliations::UserId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_reconciliations_users_user-id")
+                            .from(Reconciliations::Table, Reconciliations::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(integer(Reconciliations::WalletId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_reconciliations_wallets_wallet-id")
+                            .from(Reconciliations::Table, Reconciliations::WalletId)
+                            .to(Wallets::Table, Wallets::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(decimal(Reconciliations::StatementBalance))
+                    .col(date(Reconciliations::StatementDate))
+                    .col(timestamp_with_time_zone_null(Reconciliations::CompletedAt))
+                    .col(
+                        timestamp_with_time_zone(Reconciliations::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        timestamp_with_time_zone(Reconciliations::UpdatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(timestamp_with_time_zone_null(Reconciliations::DeletedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        // add `status` and `reconciliation_id` to `Transactions` table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transactions::Table)
+                    .add_column(
+                        enumeration(
+                            Transactions::Status,
+                            TransactionStatus,
+                            TransactionStatusVariants::iter(),
+                        )
+                        .default(Expr::val("PENDING").as_enum(TransactionStatus)),
+                    )
+                    .add_column(integer_null(Transactions::ReconciliationId))
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_transactions_reconciliations_reconciliation-id")
+                            .from_tbl(Transactions::Table)
+                            .from_col(Transactions::ReconciliationId)
+                            .to_tbl(Reconciliations::Table)
+                            .to_col(Reconciliations::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // drop `status` and `reconciliation_id` from `Transactions` table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transactions::Table)
+                    .drop_foreign_key(Alias::new(
+                        "fk_transactions_reconciliations_reconciliation-id",
+                    ))
+                    .drop_column(Transactions::ReconciliationId)
+                    .drop_column(Transactions::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        // drop `Reconciliations` table
+        manager
+            .drop_table(Table::drop().table(Reconciliations::Table).to_owned())
+            .await?;
+
+        // drop enum type
+        manager
+            .drop_type(Type::drop().name(TransactionStatus).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub struct TransactionStatus;
+
+#[derive(DeriveIden, EnumIter)]
+pub enum TransactionStatusVariants {
+    #[sea_orm(iden = "PENDING")]
+    Pending,
+
+    #[sea_orm(iden = "CLEARED")]
+    Cleared,
+
+    #[sea_orm(iden = "RECONCILED")]
+    Reconciled,
+}
+
+#[derive(DeriveIden)]
+enum Reconciliations {
+    Table,
+    Id,
+    UserId,
+    WalletId,
+    StatementBalance,
+    StatementDate,
+    CompletedAt,
+    CreatedAt,
+    UpdatedAt,
+    DeletedAt,
+}
+
+#[derive(DeriveIden)]
+enum Transactions {
+    Table,
+    Status,
+    ReconciliationId,
+}
@@ -0,0 +1,132 @@
+use crate::extension::postgres::Type;
+use crate::m20250701_134445_create_m2m_user_roles_tables::Users;
+use crate::m20250806_131727_create_transactions_table::Transactions;
+use crate::sea_orm::{EnumIter, Iterable};
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // create TransactionRevisionAction enum
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(TransactionRevisionAction)
+                    .values(TransactionRevisionActionVariants::iter())
+                    .to_owned(),
+            )
+            .await?;
+
+        // create `TransactionRevisions` table
+        manager
+            .create_table(
+                Table::create()
+                    .table(TransactionRevisions::Table)
+                    .if_not_exists()
+                    .col(pk_auto(TransactionRevisions::Id))
+                    .col(integer(TransactionRevisions::UserId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_transaction-revisions_users_user-id")
+                            .from(TransactionRevisions::Table, TransactionRevisions::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(integer(TransactionRevisions::TransactionId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_transaction-revisions_transactions_transaction-id")
+                            .from(
+                                TransactionRevisions::Table,
+                                TransactionRevisions::TransactionId,
+                            )
+                            .to(Transactions::Table, Transactions::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(integer_null(TransactionRevisions::ActorId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_transaction-revisions_users_actor-id")
+                            .from(TransactionRevisions::Table, TransactionRevisions::ActorId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(enumeration(
+                        TransactionRevisions::Action,
+                        TransactionRevisionAction,
+                        TransactionRevisionActionVariants::iter(),
+                    ))
+                    .col(json_binary_null(TransactionRevisions::OldValues))
+                    .col(json_binary_null(TransactionRevisions::NewValues))
+                    .col(string_null(TransactionRevisions::RequestId))
+                    .col(
+                        timestamp_with_time_zone(TransactionRevisions::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_transaction-revisions_transaction-id")
+                    .table(TransactionRevisions::Table)
+                    .col(TransactionRevisions::TransactionId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // drop `TransactionRevisions` table
+        manager
+            .drop_table(Table::drop().table(TransactionRevisions::Table).to_owned())
+            .await?;
+
+        // drop enum type
+        manager
+            .drop_type(Type::drop().name(TransactionRevisionAction).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub struct TransactionRevisionAction;
+
+#[derive(DeriveIden, EnumIter)]
+pub enum TransactionRevisionActionVariants {
+    #[sea_orm(iden = "CREATED")]
+    Created,
+
+    #[sea_orm(iden = "UPDATED")]
+    Updated,
+
+    #[sea_orm(iden = "DELETED")]
+    Deleted,
+
+    #[sea_orm(iden = "RESTORED")]
+    Restored,
+}
+
+#[derive(DeriveIden)]
+enum TransactionRevisions {
+    Table,
+    Id,
+    UserId,
+    TransactionId,
+    ActorId,
+    Action,
+    OldValues,
+    NewValues,
+    RequestId,
+    CreatedAt,
+}
@@ -0,0 +1,150 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        // add `search_vector` to `Transactions` table
+        db.execute_unprepared(
+            "ALTER TABLE transactions ADD COLUMN search_vector tsvector NOT NULL DEFAULT ''::tsvector",
+        )
+        .await?;
+
+        // description weighs more than the category and payee names
+        db.execute_unprepared(
+            r#"
+            CREATE FUNCTION transactions_search_vector(
+                transaction_description TEXT,
+                transaction_category_id INTEGER,
+                transaction_payee_id INTEGER
+            ) RETURNS tsvector AS $$
+                SELECT
+                    setweight(to_tsvector('simple', coalesce(transaction_description, '')), 'A')
+                    || setweight(to_tsvector('simple', coalesce(
+                        (SELECT name FROM categories WHERE id = transaction_category_id), ''
+                    )), 'B')
+                    || setweight(to_tsvector('simple', coalesce(
+                        (SELECT name FROM payees WHERE id = transaction_payee_id), ''
+                    )), 'B')
+            $$ LANGUAGE SQL STABLE
+            "#,
+        )
+        .await?;
+
+        db.execute_unprepared(
+            r#"
+            CREATE FUNCTION transactions_search_vector_refresh() RETURNS TRIGGER AS $$
+            BEGIN
+                NEW.search_vector := transactions_search_vector(
+                    NEW.description, NEW.category_id, NEW.payee_id
+                );
+                RETURN NEW;
+            END
+            $$ LANGUAGE plpgsql
+            "#,
+        )
+        .await?;
+        db.execute_unprepared(
+            r#"
+            CREATE TRIGGER transactions_search_vector_refresh
+            BEFORE INSERT OR UPDATE OF description, category_id, payee_id ON transactions
+            FOR EACH ROW EXECUTE FUNCTION transactions_search_vector_refresh()
+            "#,
+        )
+        .await?;
+
+        // renaming a category or payee has to refresh every transaction pointing at it
+        db.execute_unprepared(
+            r#"
+            CREATE FUNCTION categories_search_vector_refresh() RETURNS TRIGGER AS $$
+            BEGIN
+                UPDATE transactions
+                SET search_vector = transactions_search_vector(description, category_id, payee_id)
+                WHERE category_id = NEW.id;
+                RETURN NULL;
+            END
+            $$ LANGUAGE plpgsql
+            "#,
+        )
+        .await?;
+        db.execute_unprepared(
+            r#"
+            CREATE TRIGGER categories_search_vector_refresh
+            AFTER UPDATE OF name ON categories
+            FOR EACH ROW WHEN (OLD.name IS DISTINCT FROM NEW.name)
+            EXECUTE FUNCTION categories_search_vector_refresh()
+            "#,
+        )
+        .await?;
+        db.execute_unprepared(
+            r#"
+            CREATE FUNCTION payees_search_vector_refresh() RETURNS TRIGGER AS $$
+            BEGIN
+                UPDATE transactions
+                SET search_vector = transactions_search_vector(description, category_id, payee_id)
+                WHERE payee_id = NEW.id;
+                RETURN NULL;
+            END
+            $$ LANGUAGE plpgsql
+            "#,
+        )
+        .await?;
+        db.execute_unprepared(
+            r#"
+            CREATE TRIGGER payees_search_vector_refresh
+            AFTER UPDATE OF name ON payees
+            FOR EACH ROW WHEN (OLD.name IS DISTINCT FROM NEW.name)
+            EXECUTE FUNCTION payees_search_vector_refresh()
+            "#,
+        )
+        .await?;
+
+        // backfill existing transactions
+        db.execute_unprepared(
+            "UPDATE transactions SET search_vector = transactions_search_vector(description, category_id, payee_id)",
+        )
+        .await?;
+
+        db.execute_unprepared(
+            "CREATE INDEX \"idx_transactions_search-vector\" ON transactions USING GIN (search_vector)",
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared("DROP TRIGGER IF EXISTS payees_search_vector_refresh ON payees")
+            .await?;
+        db.execute_unprepared(
+            "DROP TRIGGER IF EXISTS categories_search_vector_refresh ON categories",
+        )
+        .await?;
+        db.execute_unprepared(
+            "DROP TRIGGER IF EXISTS transactions_search_vector_refresh ON transactions",
+        )
+        .await?;
+        db.execute_unprepared("DROP FUNCTION IF EXISTS payees_search_vector_refresh()")
+            .await?;
+        db.execute_unprepared("DROP FUNCTION IF EXISTS categories_search_vector_refresh()")
+            .await?;
+        db.execute_unprepared("DROP FUNCTION IF EXISTS transactions_search_vector_refresh()")
+            .await?;
+        db.execute_unprepared(
+            "DROP FUNCTION IF EXISTS transactions_search_vector(TEXT, INTEGER, INTEGER)",
+        )
+        .await?;
+
+        // drop `search_vector` from `Transactions` table, the index goes with it
+        db.execute_unprepared("ALTER TABLE transactions DROP COLUMN search_vector")
+            .await?;
+
+        Ok(())
+    }
+}
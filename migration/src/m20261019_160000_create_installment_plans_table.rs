@@ -0,0 +1,164 @@
+use crate::m20250701_134445_create_m2m_user_roles_tables::Users;
+use crate::m20250712_000001_create_categories_table::Categories;
+use crate::m20250728_130953_create_wallets_table::Wallets;
+use crate::m20250731_120654_create_budgets_table::Budgets;
+use crate::m20261019_120000_create_payees_tables::Payees;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // create `InstallmentPlans` table
+        manager
+            .create_table(
+                Table::create()
+                    .table(InstallmentPlans::Table)
+                    .if_not_exists()
+                    .col(pk_auto(InstallmentPlans::Id))
+                    .col(integer(InstallmentPlans::UserId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_installment-plans_users_user-id")
+                            .from(InstallmentPlans::Table, InstallmentPlans::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(integer(InstallmentPlans::WalletId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_installment-plans_wallets_wallet-id")
+                            .from(InstallmentPlans::Table, InstallmentPlans::WalletId)
+                            .to(Wallets::Table, Wallets::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(integer_null(InstallmentPlans::BudgetId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_installment-plans_budgets_budget-id")
+                            .from(InstallmentPlans::Table, InstallmentPlans::BudgetId)
+                            .to(Budgets::Table, Budgets::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(integer_null(InstallmentPlans::CategoryId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_installment-plans_categories_category-id")
+                            .from(InstallmentPlans::Table, InstallmentPlans::CategoryId)
+                            .to(Categories::Table, Categories::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(integer_null(InstallmentPlans::PayeeId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_installment-plans_payees_payee-id")
+                            .from(InstallmentPlans::Table, InstallmentPlans::PayeeId)
+                            .to(Payees::Table, Payees::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(string_null(InstallmentPlans::Description))
+                    .col(decimal(InstallmentPlans::TotalAmount))
+                    .col(integer(InstallmentPlans::InstallmentCount))
+                    .col(date(InstallmentPlans::FirstIssuedAt))
+                    .col(timestamp_with_time_zone_null(InstallmentPlans::PaidOffAt))
+                    .col(
+                        timestamp_with_time_zone(InstallmentPlans::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        timestamp_with_time_zone(InstallmentPlans::UpdatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(timestamp_with_time_zone_null(InstallmentPlans::DeletedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        // add `installment_plan_id` and `installment_number` to `Transactions` table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transactions::Table)
+                    .add_column(integer_null(Transactions::InstallmentPlanId))
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_transactions_installment-plans_installment-plan-id")
+                            .from_tbl(Transactions::Table)
+                            .from_col(Transactions::InstallmentPlanId)
+                            .to_tbl(InstallmentPlans::Table)
+                            .to_col(InstallmentPlans::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .add_column(integer_null(Transactions::InstallmentNumber))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_transactions_installment-plan-id")
+                    .table(Transactions::Table)
+                    .col(Transactions::InstallmentPlanId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // drop `installment_plan_id` and `installment_number` from `Transactions` table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transactions::Table)
+                    .drop_foreign_key(Alias::new(
+                        "fk_transactions_installment-plans_installment-plan-id",
+                    ))
+                    .drop_column(Transactions::InstallmentNumber)
+                    .drop_column(Transactions::InstallmentPlanId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // drop `InstallmentPlans` table
+        manager
+            .drop_table(Table::drop().table(InstallmentPlans::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum InstallmentPlans {
+    Table,
+    Id,
+    UserId,
+    WalletId,
+    BudgetId,
+    CategoryId,
+    PayeeId,
+    Description,
+    TotalAmount,
+    InstallmentCount,
+    FirstIssuedAt,
+    PaidOffAt,
+    CreatedAt,
+    UpdatedAt,
+    DeletedAt,
+}
+
+#[derive(DeriveIden)]
+enum Transactions {
+    Table,
+    InstallmentPlanId,
+    InstallmentNumber,
+}
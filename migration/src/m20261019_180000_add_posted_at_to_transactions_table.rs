@@ -0,0 +1,62 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // add `posted_at` to `Transactions` table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transactions::Table)
+                    .add_column(timestamp_with_time_zone_null(Transactions::PostedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        // every existing transaction already moved its wallet and budget
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(Transactions::Table)
+                    .value(Transactions::PostedAt, Expr::col(Transactions::CreatedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_transactions_posted-at_issued-at")
+                    .table(Transactions::Table)
+                    .col(Transactions::PostedAt)
+                    .col(Transactions::IssuedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // drop `posted_at` from `Transactions` table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transactions::Table)
+                    .drop_column(Transactions::PostedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Transactions {
+    Table,
+    PostedAt,
+    IssuedAt,
+    CreatedAt,
+}
@@ -0,0 +1,56 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // add `latitude`, `longitude` and `place_name` to `Transactions` table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transactions::Table)
+                    .add_column(decimal_len_null(Transactions::Latitude, 9, 6))
+                    .add_column(decimal_len_null(Transactions::Longitude, 9, 6))
+                    .add_column(string_null(Transactions::PlaceName))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_transactions_latitude_longitude")
+                    .table(Transactions::Table)
+                    .col(Transactions::Latitude)
+                    .col(Transactions::Longitude)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // drop `latitude`, `longitude` and `place_name` from `Transactions` table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transactions::Table)
+                    .drop_column(Transactions::PlaceName)
+                    .drop_column(Transactions::Longitude)
+                    .drop_column(Transactions::Latitude)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Transactions {
+    Table,
+    Latitude,
+    Longitude,
+    PlaceName,
+}
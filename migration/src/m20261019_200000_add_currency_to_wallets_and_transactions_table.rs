@@ -0,0 +1,65 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // add `currency` to `Wallets` table, existing wallets were all kept in rupiah
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Wallets::Table)
+                    .add_column(string_len(Wallets::Currency, 3).default("IDR"))
+                    .to_owned(),
+            )
+            .await?;
+
+        // add `currency` to `Transactions` table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transactions::Table)
+                    .add_column(string_len(Transactions::Currency, 3).default("IDR"))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // drop `currency` from `Transactions` table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transactions::Table)
+                    .drop_column(Transactions::Currency)
+                    .to_owned(),
+            )
+            .await?;
+
+        // drop `currency` from `Wallets` table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Wallets::Table)
+                    .drop_column(Wallets::Currency)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Wallets {
+    Table,
+    Currency,
+}
+
+#[derive(DeriveIden)]
+enum Transactions {
+    Table,
+    Currency,
+}
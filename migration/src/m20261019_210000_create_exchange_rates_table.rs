@@ -0,0 +1,105 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // create `ExchangeRates` table, rates without a user are shared by everyone
+        manager
+            .create_table(
+                Table::create()
+                    .table(ExchangeRates::Table)
+                    .if_not_exists()
+                    .col(pk_auto(ExchangeRates::Id))
+                    .col(integer_null(ExchangeRates::UserId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_exchange-rates_users_user-id")
+                            .from(ExchangeRates::Table, ExchangeRates::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(string_len(ExchangeRates::BaseCurrency, 3))
+                    .col(string_len(ExchangeRates::QuoteCurrency, 3))
+                    .col(date(ExchangeRates::RateDate))
+                    .col(decimal_len(ExchangeRates::Rate, 28, 12))
+                    .col(
+                        timestamp_with_time_zone(ExchangeRates::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        timestamp_with_time_zone(ExchangeRates::UpdatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_exchange-rates_user-id_base-currency_quote-currency_rate-date")
+                    .table(ExchangeRates::Table)
+                    .col(ExchangeRates::UserId)
+                    .col(ExchangeRates::BaseCurrency)
+                    .col(ExchangeRates::QuoteCurrency)
+                    .col(ExchangeRates::RateDate)
+                    .unique()
+                    .nulls_not_distinct()
+                    .to_owned(),
+            )
+            .await?;
+
+        // add `reporting_currency` to `Users` table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column(string_len(Users::ReportingCurrency, 3).default("IDR"))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // drop `reporting_currency` from `Users` table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(Users::ReportingCurrency)
+                    .to_owned(),
+            )
+            .await?;
+
+        // drop `ExchangeRates` table
+        manager
+            .drop_table(Table::drop().table(ExchangeRates::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ExchangeRates {
+    Table,
+    Id,
+    UserId,
+    BaseCurrency,
+    QuoteCurrency,
+    RateDate,
+    Rate,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+    ReportingCurrency,
+}
@@ -0,0 +1,95 @@
+use crate::extension::postgres::Type;
+use crate::sea_orm::{EnumIter, Iterable};
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // create WalletType enum
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(WalletType)
+                    .values(WalletTypeVariants::iter())
+                    .to_owned(),
+            )
+            .await?;
+
+        // add `wallet_type` and the type specific details to `Wallets` table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Wallets::Table)
+                    .add_column(
+                        enumeration(Wallets::WalletType, WalletType, WalletTypeVariants::iter())
+                            .default(Expr::val("CASH").as_enum(WalletType)),
+                    )
+                    .add_column(decimal_null(Wallets::CreditLimit))
+                    .add_column(small_integer_null(Wallets::StatementClosingDay))
+                    .add_column(small_integer_null(Wallets::PaymentDueDay))
+                    .add_column(decimal_len_null(Wallets::InterestRate, 7, 4))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // drop `wallet_type` and the type specific details from `Wallets` table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Wallets::Table)
+                    .drop_column(Wallets::InterestRate)
+                    .drop_column(Wallets::PaymentDueDay)
+                    .drop_column(Wallets::StatementClosingDay)
+                    .drop_column(Wallets::CreditLimit)
+                    .drop_column(Wallets::WalletType)
+                    .to_owned(),
+            )
+            .await?;
+
+        // drop enum type
+        manager
+            .drop_type(Type::drop().name(WalletType).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub struct WalletType;
+
+#[derive(DeriveIden, EnumIter)]
+pub enum WalletTypeVariants {
+    #[sea_orm(iden = "CASH")]
+    Cash,
+
+    #[sea_orm(iden = "BANK")]
+    Bank,
+
+    #[sea_orm(iden = "CREDIT_CARD")]
+    CreditCard,
+
+    #[sea_orm(iden = "SAVINGS")]
+    Savings,
+
+    #[sea_orm(iden = "LOAN")]
+    Loan,
+
+    #[sea_orm(iden = "INVESTMENT")]
+    Investment,
+}
+
+#[derive(DeriveIden)]
+enum Wallets {
+    Table,
+    WalletType,
+    CreditLimit,
+    StatementClosingDay,
+    PaymentDueDay,
+    InterestRate,
+}
@@ -0,0 +1,63 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // add `transfer_transaction_id` to `Transactions` table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transactions::Table)
+                    .add_column(integer_null(Transactions::TransferTransactionId))
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_transactions_transactions_transfer-transaction-id")
+                            .from_tbl(Transactions::Table)
+                            .from_col(Transactions::TransferTransactionId)
+                            .to_tbl(Transactions::Table)
+                            .to_col(Transactions::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_transactions_transfer-transaction-id")
+                    .table(Transactions::Table)
+                    .col(Transactions::TransferTransactionId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // drop `transfer_transaction_id` from `Transactions` table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transactions::Table)
+                    .drop_foreign_key(Alias::new(
+                        "fk_transactions_transactions_transfer-transaction-id",
+                    ))
+                    .drop_column(Transactions::TransferTransactionId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Transactions {
+    Table,
+    Id,
+    TransferTransactionId,
+}
@@ -0,0 +1,40 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // add `opening_balance` and `opening_date` to `Wallets` table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Wallets::Table)
+                    .add_column(decimal(Wallets::OpeningBalance).default(0))
+                    .add_column(date_null(Wallets::OpeningDate))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // drop `opening_balance` and `opening_date` from `Wallets` table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Wallets::Table)
+                    .drop_column(Wallets::OpeningDate)
+                    .drop_column(Wallets::OpeningBalance)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Wallets {
+    Table,
+    OpeningBalance,
+    OpeningDate,
+}
@@ -0,0 +1,111 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // create `WalletBalanceSnapshots` table, one closing balance per wallet per day that changed it
+        manager
+            .create_table(
+                Table::create()
+                    .table(WalletBalanceSnapshots::Table)
+                    .if_not_exists()
+                    .col(pk_auto(WalletBalanceSnapshots::Id))
+                    .col(integer(WalletBalanceSnapshots::WalletId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_wallet-balance-snapshots_wallets_wallet-id")
+                            .from(
+                                WalletBalanceSnapshots::Table,
+                                WalletBalanceSnapshots::WalletId,
+                            )
+                            .to(Wallets::Table, Wallets::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(date(WalletBalanceSnapshots::SnapshotDate))
+                    .col(decimal(WalletBalanceSnapshots::Balance))
+                    .col(
+                        timestamp_with_time_zone(WalletBalanceSnapshots::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        timestamp_with_time_zone(WalletBalanceSnapshots::UpdatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_wallet-balance-snapshots_wallet-id_snapshot-date")
+                    .table(WalletBalanceSnapshots::Table)
+                    .col(WalletBalanceSnapshots::WalletId)
+                    .col(WalletBalanceSnapshots::SnapshotDate)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // backfill from the opening balances and the posted transactions
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                INSERT INTO wallet_balance_snapshots (wallet_id, snapshot_date, balance)
+                SELECT
+                    wallet_id,
+                    change_date,
+                    SUM(SUM(amount)) OVER (PARTITION BY wallet_id ORDER BY change_date)
+                FROM (
+                    SELECT
+                        wallet_id,
+                        issued_at AS change_date,
+                        CASE WHEN flow_direction = 'INCOME' THEN amount ELSE -amount END AS amount
+                    FROM transactions
+                    WHERE deleted_at IS NULL AND posted_at IS NOT NULL
+                    UNION ALL
+                    SELECT id, opening_date, opening_balance
+                    FROM wallets
+                    WHERE opening_date IS NOT NULL
+                ) AS changes
+                GROUP BY wallet_id, change_date
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // drop `WalletBalanceSnapshots` table
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(WalletBalanceSnapshots::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum WalletBalanceSnapshots {
+    Table,
+    Id,
+    WalletId,
+    SnapshotDate,
+    Balance,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Wallets {
+    Table,
+    Id,
+}
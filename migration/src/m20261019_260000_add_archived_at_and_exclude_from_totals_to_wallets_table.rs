@@ -0,0 +1,40 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // add `archived_at` and `exclude_from_totals` to `Wallets` table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Wallets::Table)
+                    .add_column(timestamp_with_time_zone_null(Wallets::ArchivedAt))
+                    .add_column(boolean(Wallets::ExcludeFromTotals).default(false))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // drop `archived_at` and `exclude_from_totals` from `Wallets` table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Wallets::Table)
+                    .drop_column(Wallets::ExcludeFromTotals)
+                    .drop_column(Wallets::ArchivedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Wallets {
+    Table,
+    ArchivedAt,
+    ExcludeFromTotals,
+}
@@ -0,0 +1,203 @@
+use crate::extension::postgres::Type;
+use crate::sea_orm::{EnumIter, Iterable};
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // create WalletMemberRole enum
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(WalletMemberRole)
+                    .values(WalletMemberRoleVariants::iter())
+                    .to_owned(),
+            )
+            .await?;
+
+        // create `WalletMembers` table, invitations stay pending until `accepted_at` is set
+        manager
+            .create_table(
+                Table::create()
+                    .table(WalletMembers::Table)
+                    .if_not_exists()
+                    .col(pk_auto(WalletMembers::Id))
+                    .col(integer(WalletMembers::WalletId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_wallet-members_wallets_wallet-id")
+                            .from(WalletMembers::Table, WalletMembers::WalletId)
+                            .to(Wallets::Table, Wallets::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(integer(WalletMembers::UserId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_wallet-members_users_user-id")
+                            .from(WalletMembers::Table, WalletMembers::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(enumeration(
+                        WalletMembers::Role,
+                        WalletMemberRole,
+                        WalletMemberRoleVariants::iter(),
+                    ))
+                    .col(integer_null(WalletMembers::InvitedByUserId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_wallet-members_users_invited-by-user-id")
+                            .from(WalletMembers::Table, WalletMembers::InvitedByUserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(timestamp_with_time_zone_null(WalletMembers::AcceptedAt))
+                    .col(
+                        timestamp_with_time_zone(WalletMembers::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        timestamp_with_time_zone(WalletMembers::UpdatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_wallet-members_wallet-id_user-id")
+                    .table(WalletMembers::Table)
+                    .col(WalletMembers::WalletId)
+                    .col(WalletMembers::UserId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_wallet-members_user-id")
+                    .table(WalletMembers::Table)
+                    .col(WalletMembers::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // every existing wallet gets its owner as an accepted member
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                INSERT INTO wallet_members (wallet_id, user_id, role, accepted_at)
+                SELECT id, user_id, 'OWNER', created_at
+                FROM wallets
+                "#,
+            )
+            .await?;
+
+        // add `created_by_user_id` to `Transactions` table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transactions::Table)
+                    .add_column(integer_null(Transactions::CreatedByUserId))
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_transactions_users_created-by-user-id")
+                            .from_tbl(Transactions::Table)
+                            .from_col(Transactions::CreatedByUserId)
+                            .to_tbl(Users::Table)
+                            .to_col(Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared("UPDATE transactions SET created_by_user_id = user_id")
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // drop `created_by_user_id` from `Transactions` table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transactions::Table)
+                    .drop_foreign_key(Alias::new("fk_transactions_users_created-by-user-id"))
+                    .drop_column(Transactions::CreatedByUserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // drop `WalletMembers` table
+        manager
+            .drop_table(Table::drop().table(WalletMembers::Table).to_owned())
+            .await?;
+
+        // drop enum type
+        manager
+            .drop_type(Type::drop().name(WalletMemberRole).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub struct WalletMemberRole;
+
+#[derive(DeriveIden, EnumIter)]
+pub enum WalletMemberRoleVariants {
+    #[sea_orm(iden = "OWNER")]
+    Owner,
+
+    #[sea_orm(iden = "EDITOR")]
+    Editor,
+
+    #[sea_orm(iden = "VIEWER")]
+    Viewer,
+}
+
+#[derive(DeriveIden)]
+enum WalletMembers {
+    Table,
+    Id,
+    WalletId,
+    UserId,
+    Role,
+    InvitedByUserId,
+    AcceptedAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Wallets {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Transactions {
+    Table,
+    CreatedByUserId,
+}
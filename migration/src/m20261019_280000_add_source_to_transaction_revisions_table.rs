@@ -0,0 +1,75 @@
+use crate::extension::postgres::Type;
+use crate::sea_orm::{EnumIter, Iterable};
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // create TransactionRevisionSource enum
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(TransactionRevisionSource)
+                    .values(TransactionRevisionSourceVariants::iter())
+                    .to_owned(),
+            )
+            .await?;
+
+        // add `source` to `TransactionRevisions` table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TransactionRevisions::Table)
+                    .add_column(
+                        enumeration(
+                            TransactionRevisions::Source,
+                            TransactionRevisionSource,
+                            TransactionRevisionSourceVariants::iter(),
+                        )
+                        .default(Expr::val("USER").as_enum(TransactionRevisionSource)),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // drop `source` from `TransactionRevisions` table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TransactionRevisions::Table)
+                    .drop_column(TransactionRevisions::Source)
+                    .to_owned(),
+            )
+            .await?;
+
+        // drop enum type
+        manager
+            .drop_type(Type::drop().name(TransactionRevisionSource).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub struct TransactionRevisionSource;
+
+#[derive(DeriveIden, EnumIter)]
+pub enum TransactionRevisionSourceVariants {
+    #[sea_orm(iden = "USER")]
+    User,
+
+    #[sea_orm(iden = "SYSTEM")]
+    System,
+}
+
+#[derive(DeriveIden)]
+enum TransactionRevisions {
+    Table,
+    Source,
+}